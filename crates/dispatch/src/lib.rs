@@ -4,6 +4,7 @@
 //! tracked by the jit issue tracker.
 
 use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
@@ -15,10 +16,20 @@ pub struct Config {
     /// How often to poll for ready issues (seconds)
     pub poll_interval_secs: u64,
 
+    /// How long an assignment may go without a heartbeat before it's
+    /// considered stalled, released, and returned to `Ready` for another
+    /// agent to pick up.
+    #[serde(default = "default_stall_timeout_secs")]
+    pub stall_timeout_secs: u64,
+
     /// List of available agents
     pub agents: Vec<AgentConfig>,
 }
 
+fn default_stall_timeout_secs() -> u64 {
+    300
+}
+
 impl Config {
     /// Load configuration from TOML file
     pub fn from_file(path: &Path) -> Result<Self> {
@@ -49,10 +60,19 @@ pub struct AgentConfig {
     pub command: String,
 }
 
+/// A single in-flight assignment: which issue an agent is holding, when it
+/// was claimed, and when that agent was last heard from.
+#[derive(Debug, Clone)]
+struct Assignment {
+    issue_id: String,
+    claimed_at: DateTime<Utc>,
+    last_seen: DateTime<Utc>,
+}
+
 /// Tracks agent capacity and assignments
 pub struct AgentTracker {
     agents: Vec<AgentConfig>,
-    assignments: HashMap<String, Vec<String>>, // agent_id -> issue_ids
+    assignments: HashMap<String, Vec<Assignment>>, // agent_id -> assignments
 }
 
 impl AgentTracker {
@@ -79,8 +99,13 @@ impl AgentTracker {
             .collect()
     }
 
-    /// Assign work to an agent
-    pub fn assign_work(&mut self, agent_id: &str, issue_id: &str) -> Result<()> {
+    /// Number of agents with available capacity.
+    pub fn idle_count(&self) -> usize {
+        self.available_agents().len()
+    }
+
+    /// Assign work to an agent, claimed (and first heartbeat) at `now`.
+    pub fn assign_work_at(&mut self, agent_id: &str, issue_id: &str, now: DateTime<Utc>) -> Result<()> {
         // Find agent
         let agent = self
             .agents
@@ -99,9 +124,62 @@ impl AgentTracker {
             );
         }
 
-        assigned.push(issue_id.to_string());
+        assigned.push(Assignment {
+            issue_id: issue_id.to_string(),
+            claimed_at: now,
+            last_seen: now,
+        });
         Ok(())
     }
+
+    /// Assign work to an agent
+    pub fn assign_work(&mut self, agent_id: &str, issue_id: &str) -> Result<()> {
+        self.assign_work_at(agent_id, issue_id, Utc::now())
+    }
+
+    /// Refresh `agent_id`'s heartbeat for its `issue_id` assignment.
+    pub fn heartbeat(&mut self, agent_id: &str, issue_id: &str, now: DateTime<Utc>) -> Result<()> {
+        let assignment = self
+            .assignments
+            .get_mut(agent_id)
+            .and_then(|assignments| assignments.iter_mut().find(|a| a.issue_id == issue_id))
+            .ok_or_else(|| {
+                anyhow::anyhow!("No active assignment of {} to agent {}", issue_id, agent_id)
+            })?;
+
+        assignment.last_seen = now;
+        Ok(())
+    }
+
+    /// Drop `issue_id` from `agent_id`'s assignments, freeing a capacity slot.
+    pub fn release_work(&mut self, agent_id: &str, issue_id: &str) {
+        if let Some(assignments) = self.assignments.get_mut(agent_id) {
+            assignments.retain(|a| a.issue_id != issue_id);
+        }
+    }
+
+    /// Find and release every assignment whose heartbeat hasn't been
+    /// refreshed within `stall_timeout`, returning `(agent_id, issue_id)`
+    /// pairs so the caller can return each issue to `Ready`.
+    pub fn sweep_stalled(
+        &mut self,
+        now: DateTime<Utc>,
+        stall_timeout: chrono::Duration,
+    ) -> Vec<(String, String)> {
+        let mut stalled = Vec::new();
+
+        for (agent_id, assignments) in self.assignments.iter_mut() {
+            assignments.retain(|a| {
+                let is_stalled = now - a.last_seen > stall_timeout;
+                if is_stalled {
+                    stalled.push((agent_id.clone(), a.issue_id.clone()));
+                }
+                !is_stalled
+            });
+        }
+
+        stalled
+    }
 }
 
 /// Represents a ready issue from jit
@@ -112,10 +190,23 @@ pub struct ReadyIssue {
     pub priority: String,
 }
 
+/// Summary of one [`Orchestrator::run_dispatch_cycle_with_sweep`] pass,
+/// suitable for structured (JSON) logging by the dispatch daemon.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CycleReport {
+    /// `(issue_id, agent_id)` pairs newly assigned this cycle.
+    pub assigned: Vec<(String, String)>,
+    /// Agents with spare capacity after this cycle's assignments.
+    pub idle_agents: usize,
+    /// Stalled assignments released back to `Ready` this cycle.
+    pub stalls_recovered: usize,
+}
+
 /// Main orchestrator
 pub struct Orchestrator {
     repo_path: PathBuf,
     agent_tracker: Option<AgentTracker>,
+    stall_timeout_secs: u64,
 }
 
 impl Orchestrator {
@@ -124,16 +215,19 @@ impl Orchestrator {
         Self {
             repo_path: repo_path.to_path_buf(),
             agent_tracker: None,
+            stall_timeout_secs: default_stall_timeout_secs(),
         }
     }
 
     /// Create orchestrator with configuration
     pub fn with_config(repo_path: &Path, config: Config) -> Self {
+        let stall_timeout_secs = config.stall_timeout_secs;
         let agent_tracker = AgentTracker::new(config.agents);
 
         Self {
             repo_path: repo_path.to_path_buf(),
             agent_tracker: Some(agent_tracker),
+            stall_timeout_secs,
         }
     }
 
@@ -212,6 +306,150 @@ impl Orchestrator {
         Ok(())
     }
 
+    /// Release a stalled issue back to `Ready` so another agent can pick it up.
+    pub fn release_issue_for_agent(&self, issue_id: &str, reason: &str) -> Result<()> {
+        let jit_binary = self.find_jit_binary()?;
+
+        let status = Command::new(jit_binary)
+            .args(["issue", "release", issue_id, reason])
+            .current_dir(&self.repo_path)
+            .status()
+            .context("Failed to execute jit issue release")?;
+
+        if !status.success() {
+            bail!("jit issue release failed");
+        }
+
+        Ok(())
+    }
+
+    /// Record when an issue was claimed in its `context` map, so the claim
+    /// timestamp travels with the issue rather than living only in this
+    /// process's in-memory `AgentTracker`.
+    pub fn record_claim_timestamp(&self, issue_id: &str, claimed_at: DateTime<Utc>) -> Result<()> {
+        let jit_binary = self.find_jit_binary()?;
+
+        let status = Command::new(jit_binary)
+            .args([
+                "issue",
+                "set-context",
+                issue_id,
+                "jit.dispatch.claimed_at",
+                &claimed_at.to_rfc3339(),
+            ])
+            .current_dir(&self.repo_path)
+            .status()
+            .context("Failed to execute jit issue set-context")?;
+
+        if !status.success() {
+            bail!("jit issue set-context failed");
+        }
+
+        Ok(())
+    }
+
+    /// Refresh an agent's heartbeat for the issue it's currently holding.
+    pub fn heartbeat_agent(&mut self, agent_id: &str, issue_id: &str) -> Result<()> {
+        let tracker = self
+            .agent_tracker
+            .as_mut()
+            .ok_or_else(|| anyhow::anyhow!("No agent tracker configured"))?;
+
+        tracker.heartbeat(agent_id, issue_id, Utc::now())
+    }
+
+    /// Run one dispatch cycle with stall recovery: release any assignment
+    /// whose heartbeat has gone quiet for longer than `stall_timeout_secs`,
+    /// then assign the highest-priority ready issues to the agents that
+    /// frees up (and any already idle), recording a claim timestamp on
+    /// each. Returns a [`CycleReport`] suitable for structured logging.
+    pub fn run_dispatch_cycle_with_sweep(&mut self) -> Result<CycleReport> {
+        let now = Utc::now();
+        let stall_timeout = chrono::Duration::seconds(self.stall_timeout_secs as i64);
+
+        let stalled = {
+            let tracker = self
+                .agent_tracker
+                .as_mut()
+                .ok_or_else(|| anyhow::anyhow!("No agent tracker configured"))?;
+            tracker.sweep_stalled(now, stall_timeout)
+        };
+
+        for (agent_id, issue_id) in &stalled {
+            self.release_issue_for_agent(
+                issue_id,
+                &format!("stalled: no heartbeat from {} within {}s", agent_id, self.stall_timeout_secs),
+            )?;
+        }
+
+        let mut sorted_issues = self.query_ready_issues()?;
+        sorted_issues.sort_by(|a, b| {
+            let priority_order = |p: &str| match p {
+                "critical" => 0,
+                "high" => 1,
+                "normal" => 2,
+                "low" => 3,
+                _ => 4,
+            };
+
+            priority_order(&a.priority).cmp(&priority_order(&b.priority))
+        });
+
+        let mut assigned = Vec::new();
+        for issue in sorted_issues {
+            let agent_id = {
+                let tracker = self
+                    .agent_tracker
+                    .as_ref()
+                    .ok_or_else(|| anyhow::anyhow!("No agent tracker configured"))?;
+                match tracker.available_agents().first() {
+                    Some(agent) => agent.id.clone(),
+                    None => break, // no more capacity
+                }
+            };
+
+            let agent_id_full = format!("agent:{}", agent_id);
+            self.claim_issue_for_agent(&issue.id, &agent_id_full)?;
+
+            // The claim above already succeeded, so the tracker must be
+            // updated regardless of whether stashing the timestamp works --
+            // otherwise this agent would look idle next cycle while already
+            // holding this issue, and it would never be eligible for the
+            // stall sweep either.
+            if let Err(e) = self.record_claim_timestamp(&issue.id, now) {
+                eprintln!(
+                    "Warning: failed to record claim timestamp for issue {}: {}",
+                    issue.id, e
+                );
+            }
+
+            self.agent_tracker
+                .as_mut()
+                .ok_or_else(|| anyhow::anyhow!("No agent tracker configured"))?
+                .assign_work_at(&agent_id, &issue.id, now)
+                .with_context(|| {
+                    format!(
+                        "Issue {} was claimed for agent {} but could not be tracked",
+                        issue.id, agent_id
+                    )
+                })?;
+
+            assigned.push((issue.id, agent_id));
+        }
+
+        let idle_agents = self
+            .agent_tracker
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("No agent tracker configured"))?
+            .idle_count();
+
+        Ok(CycleReport {
+            assigned,
+            idle_agents,
+            stalls_recovered: stalled.len(),
+        })
+    }
+
     /// Run one dispatch cycle: assign ready issues to available agents
     pub fn run_dispatch_cycle(&mut self) -> Result<usize> {
         let ready_issues = self.query_ready_issues()?;
@@ -156,8 +156,5 @@ fn test_dispatch_respects_priority() {
 }
 
 // TODO: Add tests for:
-// - Config file loading
-// - Agent pool management
 // - Periodic polling
-// - Stalled work detection
 // - Multiple concurrent agents
@@ -6,6 +6,7 @@
 //! 3. Assign issues to available agents based on priority
 //! 4. Track agent capacity
 
+use chrono::{Duration, Utc};
 use std::fs;
 use std::path::Path;
 use std::process::Command;
@@ -214,8 +215,95 @@ command = "echo test"
     assert_eq!(ready.len(), 0);
 }
 
+#[test]
+fn test_agent_tracker_heartbeat_and_stall_sweep() {
+    let agents = vec![jit_dispatch::AgentConfig {
+        id: "agent-1".to_string(),
+        agent_type: "test".to_string(),
+        max_concurrent: 1,
+        command: "echo test".to_string(),
+    }];
+    let mut tracker = jit_dispatch::AgentTracker::new(agents);
+
+    let claimed_at = Utc::now() - Duration::seconds(120);
+    tracker
+        .assign_work_at("agent-1", "issue-1", claimed_at)
+        .expect("Should assign");
+    assert_eq!(tracker.idle_count(), 0);
+
+    // A fresh heartbeat keeps the assignment alive past a short timeout.
+    tracker
+        .heartbeat("agent-1", "issue-1", Utc::now())
+        .expect("Should heartbeat");
+    let stalled = tracker.sweep_stalled(Utc::now(), Duration::seconds(30));
+    assert!(stalled.is_empty());
+    assert_eq!(tracker.idle_count(), 0);
+
+    // No heartbeat since claim, and the timeout has elapsed: it's stalled.
+    let stalled = tracker.sweep_stalled(Utc::now(), Duration::seconds(1));
+    assert_eq!(stalled, vec![("agent-1".to_string(), "issue-1".to_string())]);
+    assert_eq!(tracker.idle_count(), 1);
+}
+
+#[test]
+fn test_agent_tracker_release_work_frees_capacity() {
+    let agents = vec![jit_dispatch::AgentConfig {
+        id: "agent-1".to_string(),
+        agent_type: "test".to_string(),
+        max_concurrent: 1,
+        command: "echo test".to_string(),
+    }];
+    let mut tracker = jit_dispatch::AgentTracker::new(agents);
+
+    tracker.assign_work("agent-1", "issue-1").unwrap();
+    assert_eq!(tracker.idle_count(), 0);
+
+    tracker.release_work("agent-1", "issue-1");
+    assert_eq!(tracker.idle_count(), 1);
+}
+
+#[test]
+fn test_dispatch_cycle_with_sweep_recovers_stalled_work() {
+    let repo = setup_jit_repo();
+    let issue_id = create_ready_issue(repo.path(), "Task", "normal");
+
+    let temp = TempDir::new().unwrap();
+    let config_content = r#"
+poll_interval_secs = 5
+stall_timeout_secs = 1
+
+[[agents]]
+id = "agent-1"
+type = "test"
+max_concurrent = 1
+command = "echo test"
+"#;
+
+    let config_path = temp.path().join("dispatch.toml");
+    fs::write(&config_path, config_content).unwrap();
+
+    let config = jit_dispatch::Config::from_file(&config_path).unwrap();
+    let mut orchestrator = jit_dispatch::Orchestrator::with_config(repo.path(), config);
+
+    // First cycle claims the only ready issue; no stalls to recover yet.
+    let report = orchestrator
+        .run_dispatch_cycle_with_sweep()
+        .expect("Should dispatch");
+    assert_eq!(report.assigned, vec![(issue_id.clone(), "agent-1".to_string())]);
+    assert_eq!(report.stalls_recovered, 0);
+    assert_eq!(report.idle_agents, 0);
+
+    // No heartbeat arrives; once the stall timeout elapses the next cycle
+    // should release it back to Ready and reassign it.
+    std::thread::sleep(std::time::Duration::from_secs(2));
+    let report = orchestrator
+        .run_dispatch_cycle_with_sweep()
+        .expect("Should dispatch");
+    assert_eq!(report.stalls_recovered, 1);
+    assert_eq!(report.assigned, vec![(issue_id, "agent-1".to_string())]);
+}
+
 // TODO: Add tests for:
-// - Stalled work detection
 // - Agent failure handling
 // - Config reload
 // - Multiple dispatch cycles
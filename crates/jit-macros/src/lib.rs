@@ -0,0 +1,43 @@
+//! Procedural macros supporting `jit`'s document adapter discovery system.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, ItemStruct};
+
+/// Mark a unit struct implementing `DocFormatAdapter` for automatic
+/// discovery by `AdapterRegistry::with_discovered()`.
+///
+/// Expands to the struct definition unchanged, plus a submission into the
+/// crate-wide `DocAdapterFactory` inventory so the adapter is picked up
+/// without editing `AdapterRegistry::with_builtins` by hand. The struct
+/// still needs its own `impl DocFormatAdapter for ...` block -- this
+/// attribute only wires up discovery.
+///
+/// # Example
+///
+/// ```ignore
+/// use jit::document::DocFormatAdapter;
+/// use jit_macros::doc_adapter;
+///
+/// #[doc_adapter]
+/// pub struct MyAdapter;
+///
+/// impl DocFormatAdapter for MyAdapter {
+///     // ...
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn doc_adapter(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as ItemStruct);
+    let name = &input.ident;
+
+    let expanded = quote! {
+        #input
+
+        ::jit::document::inventory::submit! {
+            ::jit::document::DocAdapterFactory(|| ::std::boxed::Box::new(#name))
+        }
+    };
+
+    expanded.into()
+}
@@ -3,8 +3,19 @@
 //! This module implements agent identity resolution with the following priority:
 //! 1. `--agent-id` CLI flag (highest priority, explicit override)
 //! 2. `JIT_AGENT_ID` environment variable (session-specific)
-//! 3. `~/.config/jit/agent.toml` config file (persistent identity)
-//! 4. Error (no default, must be explicitly configured)
+//! 3. Layered `agent.toml` configuration (persistent identity), resolved as:
+//!    a. Global defaults from `~/.config/jit/agent.toml`
+//!    b. The selected profile (`--profile` flag, else `JIT_AGENT_PROFILE`),
+//!       looked up by name under that file's `[profiles.<name>]` tables
+//!    c. Repo-local `.jit/agent.toml`, merged in field-by-field on top
+//! 4. CI auto-detection (opt-in via `--ci-auto` or `[behavior] ci_autodetect
+//!    = true`): inspects well-known CI environment variables and synthesizes
+//!    a `ci:{provider}-{run-identifier}` agent ID
+//! 5. Error (no default, must be explicitly configured)
+//!
+//! Each layer in step 3 is independently parseable (missing fields just
+//! don't override anything); the fully merged `id` is validated once, at
+//! the end of resolution.
 //!
 //! Agent IDs follow the format `{type}:{identifier}`, for example:
 //! - `agent:copilot-1` - GitHub Copilot session 1
@@ -13,8 +24,9 @@
 
 use anyhow::{anyhow, bail, Context, Result};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::env;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Agent configuration from `~/.config/jit/agent.toml`.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -49,6 +61,11 @@ pub struct BehaviorSection {
     /// Heartbeat interval in seconds (default: 30)
     #[serde(default = "default_heartbeat_interval")]
     pub heartbeat_interval: u64,
+    /// Opt in to synthesizing an agent ID from well-known CI environment
+    /// variables when no explicit identity is configured (see
+    /// [`resolve_agent_id`])
+    #[serde(default)]
+    pub ci_autodetect: bool,
 }
 
 impl Default for BehaviorSection {
@@ -56,6 +73,7 @@ impl Default for BehaviorSection {
         Self {
             auto_heartbeat: false,
             heartbeat_interval: 30,
+            ci_autodetect: false,
         }
     }
 }
@@ -91,7 +109,7 @@ impl AgentConfig {
         Ok(Some(config))
     }
 
-    /// Get the path to the agent config file.
+    /// Get the path to the global agent config file.
     fn config_path() -> Result<PathBuf> {
         let config_dir =
             dirs::config_dir().ok_or_else(|| anyhow!("Could not determine config directory"))?;
@@ -100,11 +118,234 @@ impl AgentConfig {
     }
 }
 
-/// Resolve agent identity with priority: CLI flag > env var > config file > error.
+/// A partial [`AgentSection`] where every field is optional, so a layer
+/// (global defaults, a profile, or a repo-local override) only needs to
+/// specify the fields it actually changes.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AgentSectionOverlay {
+    pub id: Option<String>,
+    pub created_at: Option<String>,
+    pub description: Option<String>,
+    pub default_ttl_secs: Option<u64>,
+}
+
+impl AgentSectionOverlay {
+    /// Overlay `other`'s fields onto `self`, field-by-field; a field
+    /// present in `other` replaces `self`'s, a field absent leaves `self`
+    /// untouched.
+    fn merge_onto(&mut self, other: &AgentSectionOverlay) {
+        if other.id.is_some() {
+            self.id = other.id.clone();
+        }
+        if other.created_at.is_some() {
+            self.created_at = other.created_at.clone();
+        }
+        if other.description.is_some() {
+            self.description = other.description.clone();
+        }
+        if other.default_ttl_secs.is_some() {
+            self.default_ttl_secs = other.default_ttl_secs;
+        }
+    }
+}
+
+/// A partial [`BehaviorSection`], same overlay semantics as
+/// [`AgentSectionOverlay`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BehaviorSectionOverlay {
+    pub auto_heartbeat: Option<bool>,
+    pub heartbeat_interval: Option<u64>,
+    pub ci_autodetect: Option<bool>,
+}
+
+impl BehaviorSectionOverlay {
+    fn merge_onto(&mut self, other: &BehaviorSectionOverlay) {
+        if other.auto_heartbeat.is_some() {
+            self.auto_heartbeat = other.auto_heartbeat;
+        }
+        if other.heartbeat_interval.is_some() {
+            self.heartbeat_interval = other.heartbeat_interval;
+        }
+        if other.ci_autodetect.is_some() {
+            self.ci_autodetect = other.ci_autodetect;
+        }
+    }
+}
+
+/// A named profile: a partial `agent`/`behavior` overlay selectable via
+/// `--profile` or `JIT_AGENT_PROFILE`, e.g. `[profiles.copilot]`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AgentProfile {
+    #[serde(default)]
+    pub agent: AgentSectionOverlay,
+    #[serde(default)]
+    pub behavior: BehaviorSectionOverlay,
+}
+
+/// One layer of agent configuration as parsed straight from a TOML file:
+/// top-level defaults plus a table of named profiles. Used for both the
+/// global (`~/.config/jit/agent.toml`) and repo-local (`.jit/agent.toml`)
+/// files -- each is independently parseable, since every field is optional.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AgentConfigLayer {
+    #[serde(default)]
+    pub agent: AgentSectionOverlay,
+    #[serde(default)]
+    pub behavior: BehaviorSectionOverlay,
+    #[serde(default)]
+    pub profiles: HashMap<String, AgentProfile>,
+}
+
+impl AgentConfigLayer {
+    /// Parse a layer from `path`, or `None` if the file doesn't exist.
+    fn load_from(path: &Path) -> Result<Option<Self>> {
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let layer: AgentConfigLayer = toml::from_str(&content)
+            .with_context(|| format!("Failed to parse {}", path.display()))?;
+        Ok(Some(layer))
+    }
+}
+
+/// Build the effective [`AgentConfig`] by layering, in order:
+/// 1. Global defaults from `~/.config/jit/agent.toml`
+/// 2. The selected profile (`profile`, falling back to `JIT_AGENT_PROFILE`),
+///    looked up in whichever layer(s) define it
+/// 3. Repo-local overrides from `<repo_jit_dir>/agent.toml`, if given
+///
+/// Later layers override earlier ones field-by-field (a layer that omits a
+/// field leaves the running value untouched). Returns `Ok(None)` if neither
+/// the global nor the repo-local file exists, or if the files that do exist
+/// never set an `id` (e.g. a repo-local file that only carries `[behavior]`
+/// overrides) -- in both cases there's simply no identity in this layer to
+/// report, not an error. A merged `id` that's present but malformed is
+/// still a hard error, validated once at the end.
+pub fn resolve_layered_config(
+    profile: Option<&str>,
+    repo_jit_dir: Option<&Path>,
+) -> Result<Option<AgentConfig>> {
+    let merged = match merge_agent_config_layers(profile, repo_jit_dir)? {
+        Some(merged) => merged,
+        None => return Ok(None),
+    };
+
+    let id = match merged.agent.id.clone() {
+        Some(id) => id,
+        // No layer set an id -- e.g. a behavior-only repo-local override.
+        // Not an error: the caller falls back to later resolution tiers.
+        None => return Ok(None),
+    };
+    validate_agent_id(&id)?;
+
+    Ok(Some(AgentConfig {
+        agent: AgentSection {
+            id,
+            created_at: merged.agent.created_at.unwrap_or_default(),
+            description: merged.agent.description.unwrap_or_default(),
+            default_ttl_secs: merged.agent.default_ttl_secs,
+        },
+        behavior: BehaviorSection {
+            auto_heartbeat: merged.behavior.auto_heartbeat.unwrap_or(false),
+            heartbeat_interval: merged
+                .behavior
+                .heartbeat_interval
+                .unwrap_or_else(default_heartbeat_interval),
+            ci_autodetect: merged.behavior.ci_autodetect.unwrap_or(false),
+        },
+    }))
+}
+
+/// The field-by-field merge of every configured layer (global, selected
+/// profile, repo-local), before the `id` is required or validated. Shared
+/// by [`resolve_layered_config`] and [`resolve_agent_id`]'s CI
+/// auto-detection tier so both read `agent.toml` exactly once.
+struct MergedAgentLayers {
+    agent: AgentSectionOverlay,
+    behavior: BehaviorSectionOverlay,
+}
+
+/// Load and merge the global and repo-local `agent.toml` layers (plus the
+/// selected profile, if any), in the same order and precedence documented
+/// on [`resolve_layered_config`]. Returns `Ok(None)` only when neither file
+/// exists; an unknown `--profile`/`JIT_AGENT_PROFILE` selection is an error.
+fn merge_agent_config_layers(
+    profile: Option<&str>,
+    repo_jit_dir: Option<&Path>,
+) -> Result<Option<MergedAgentLayers>> {
+    let profile_name = profile
+        .map(|s| s.to_string())
+        .or_else(|| env::var("JIT_AGENT_PROFILE").ok());
+
+    let global = AgentConfigLayer::load_from(&AgentConfig::config_path()?)?;
+    let repo = match repo_jit_dir {
+        Some(dir) => AgentConfigLayer::load_from(&dir.join("agent.toml"))?,
+        None => None,
+    };
+
+    if global.is_none() && repo.is_none() {
+        return Ok(None);
+    }
+
+    let mut agent = AgentSectionOverlay::default();
+    let mut behavior = BehaviorSectionOverlay::default();
+    let mut profile_found = profile_name.is_none();
+
+    if let Some(layer) = &global {
+        agent.merge_onto(&layer.agent);
+        behavior.merge_onto(&layer.behavior);
+        if let Some(name) = &profile_name {
+            if let Some(selected) = layer.profiles.get(name) {
+                agent.merge_onto(&selected.agent);
+                behavior.merge_onto(&selected.behavior);
+                profile_found = true;
+            }
+        }
+    }
+
+    if let Some(layer) = &repo {
+        if let Some(name) = &profile_name {
+            if let Some(selected) = layer.profiles.get(name) {
+                agent.merge_onto(&selected.agent);
+                behavior.merge_onto(&selected.behavior);
+                profile_found = true;
+            }
+        }
+        agent.merge_onto(&layer.agent);
+        behavior.merge_onto(&layer.behavior);
+    }
+
+    if let Some(name) = &profile_name {
+        if !profile_found {
+            bail!(
+                "Unknown agent profile '{}': not defined in global or repo-local agent.toml",
+                name
+            );
+        }
+    }
+
+    Ok(Some(MergedAgentLayers { agent, behavior }))
+}
+
+/// Resolve agent identity with priority: CLI flag > env var > layered
+/// config file (global + profile + repo-local) > CI auto-detection > error.
 ///
 /// # Arguments
 ///
 /// * `cli_flag` - Optional agent ID from CLI `--agent-id` flag
+/// * `profile` - Optional profile name from CLI `--profile` flag; falls
+///   back to `JIT_AGENT_PROFILE` if not given
+/// * `repo_jit_dir` - Path to the repo's `.jit` directory, if known, so a
+///   repo-local `agent.toml` can override the global one
+/// * `ci_auto` - Opt-in from a `--ci-auto` CLI flag; also enabled by
+///   `[behavior] ci_autodetect = true` in either layer of `agent.toml`.
+///   When enabled and nothing else resolved an identity, well-known CI
+///   environment variables are inspected and synthesized into a
+///   `ci:{provider}-{run-identifier}` agent ID. Off by default so
+///   interactive users are never surprised by an auto-picked identity.
 ///
 /// # Errors
 ///
@@ -116,10 +357,15 @@ impl AgentConfig {
 /// use jit::agent_config::resolve_agent_id;
 ///
 /// // With CLI flag (highest priority)
-/// let agent_id = resolve_agent_id(Some("agent:cli-override".to_string())).unwrap();
+/// let agent_id = resolve_agent_id(Some("agent:cli-override".to_string()), None, None, false).unwrap();
 /// assert_eq!(agent_id, "agent:cli-override");
 /// ```
-pub fn resolve_agent_id(cli_flag: Option<String>) -> Result<String> {
+pub fn resolve_agent_id(
+    cli_flag: Option<String>,
+    profile: Option<String>,
+    repo_jit_dir: Option<&Path>,
+    ci_auto: bool,
+) -> Result<String> {
     // Priority 1: CLI flag
     if let Some(id) = cli_flag {
         validate_agent_id(&id)?;
@@ -132,9 +378,27 @@ pub fn resolve_agent_id(cli_flag: Option<String>) -> Result<String> {
         return Ok(id);
     }
 
-    // Priority 3: Config file
-    if let Some(config) = AgentConfig::load()? {
-        return Ok(config.agent.id);
+    // Priority 3: Layered config file (global -> profile -> repo-local),
+    // and priority 4's opt-in check, from the same single read of the
+    // layers so a CI auto-detection fallback doesn't re-parse agent.toml.
+    let merged = merge_agent_config_layers(profile.as_deref(), repo_jit_dir)?;
+    if let Some(merged) = &merged {
+        if let Some(id) = &merged.agent.id {
+            validate_agent_id(id)?;
+            return Ok(id.clone());
+        }
+    }
+
+    // Priority 4: CI auto-detection (opt-in only)
+    let ci_autodetect_configured = merged
+        .as_ref()
+        .and_then(|merged| merged.behavior.ci_autodetect)
+        .unwrap_or(false);
+    if ci_auto || ci_autodetect_configured {
+        if let Some(id) = detect_ci_agent_id() {
+            validate_agent_id(&id)?;
+            return Ok(id);
+        }
     }
 
     // No configuration found
@@ -144,13 +408,44 @@ pub fn resolve_agent_id(cli_flag: Option<String>) -> Result<String> {
          Set one of the following (priority order):\n\
          1. CLI flag: --agent-id agent:your-name\n\
          2. Environment: export JIT_AGENT_ID=agent:your-name\n\
-         3. Config file: ~/.config/jit/agent.toml\n\
+         3. Config file: ~/.config/jit/agent.toml (optionally layered with\n\
+            a --profile/JIT_AGENT_PROFILE selection and a repo-local\n\
+            .jit/agent.toml)\n\
+         4. CI auto-detection: pass --ci-auto or set\n\
+            [behavior] ci_autodetect = true in agent.toml, and run under a\n\
+            recognized CI provider (GitHub Actions, GitLab CI, or generic CI)\n\
          \n\
          Format: {{type}}:{{identifier}}\n\
          Examples: agent:copilot-1, human:alice, ci:github-actions"
     );
 }
 
+/// Synthesize a `ci:{provider}-{run-identifier}` agent ID from well-known
+/// CI environment variables, or `None` if none of them are recognized.
+///
+/// Checked in order of specificity: GitHub Actions, GitLab CI, then a
+/// generic `CI=true` fallback keyed on hostname.
+fn detect_ci_agent_id() -> Option<String> {
+    if env::var("GITHUB_ACTIONS").map(|v| v == "true").unwrap_or(false) {
+        let run_identifier = env::var("GITHUB_RUN_ID")
+            .or_else(|_| env::var("GITHUB_ACTOR"))
+            .unwrap_or_else(|_| "unknown-run".to_string());
+        return Some(format!("ci:github-actions-{}", run_identifier));
+    }
+
+    if env::var("GITLAB_CI").map(|v| v == "true").unwrap_or(false) {
+        let run_identifier = env::var("CI_JOB_ID").unwrap_or_else(|_| "unknown-job".to_string());
+        return Some(format!("ci:gitlab-ci-{}", run_identifier));
+    }
+
+    if env::var("CI").map(|v| v == "true").unwrap_or(false) {
+        let run_identifier = env::var("HOSTNAME").unwrap_or_else(|_| "unknown-run".to_string());
+        return Some(format!("ci:generic-{}", run_identifier));
+    }
+
+    None
+}
+
 /// Validate agent ID format: {type}:{identifier}
 ///
 /// # Errors
@@ -192,6 +487,7 @@ fn validate_agent_id(id: &str) -> Result<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use tempfile::TempDir;
 
     #[test]
     fn test_validate_agent_id_valid() {
@@ -285,7 +581,7 @@ description = "Alice's development machine"
         // CLI flag should take highest priority
         env::set_var("JIT_AGENT_ID", "env:should-not-use");
 
-        let result = resolve_agent_id(Some("agent:cli-override".to_string())).unwrap();
+        let result = resolve_agent_id(Some("agent:cli-override".to_string()), None, None, false).unwrap();
         assert_eq!(result, "agent:cli-override");
 
         // Restore original env state
@@ -302,7 +598,7 @@ description = "Alice's development machine"
 
         env::set_var("JIT_AGENT_ID", "agent:from-env");
 
-        let result = resolve_agent_id(None).unwrap();
+        let result = resolve_agent_id(None, None, None, false).unwrap();
         assert_eq!(result, "agent:from-env");
 
         // Restore original env state
@@ -319,7 +615,7 @@ description = "Alice's development machine"
 
         env::set_var("JIT_AGENT_ID", "invalid-no-colon");
 
-        let result = resolve_agent_id(None);
+        let result = resolve_agent_id(None, None, None, false);
         assert!(result.is_err());
         assert!(result
             .unwrap_err()
@@ -341,7 +637,7 @@ description = "Alice's development machine"
         // Ensure no env var set
         env::remove_var("JIT_AGENT_ID");
 
-        let result = resolve_agent_id(None);
+        let result = resolve_agent_id(None, None, None, false);
         assert!(result.is_err());
         let err = result.unwrap_err().to_string();
         assert!(err.contains("No agent identity configured"));
@@ -369,4 +665,245 @@ description = "Test"
         assert!(!config.behavior.auto_heartbeat);
         assert_eq!(config.behavior.heartbeat_interval, 30);
     }
+
+    #[test]
+    fn test_layered_config_repo_local_overrides_fields_not_replaces_file() {
+        let repo = TempDir::new().unwrap();
+        std::fs::write(
+            repo.path().join("agent.toml"),
+            r#"
+[agent]
+id = "agent:repo-override"
+default_ttl_secs = 1200
+"#,
+        )
+        .unwrap();
+
+        let resolved = resolve_layered_config(None, Some(repo.path()))
+            .unwrap()
+            .expect("repo-local layer alone should resolve");
+
+        assert_eq!(resolved.agent.id, "agent:repo-override");
+        assert_eq!(resolved.agent.default_ttl_secs, Some(1200));
+        // Fields the repo-local file didn't set fall back to layer defaults.
+        assert_eq!(resolved.behavior.heartbeat_interval, 30);
+    }
+
+    #[test]
+    fn test_layered_config_profile_overlays_onto_defaults() {
+        let repo = TempDir::new().unwrap();
+        std::fs::write(
+            repo.path().join("agent.toml"),
+            r#"
+[agent]
+id = "agent:default"
+description = "fallback identity"
+
+[profiles.copilot]
+[profiles.copilot.agent]
+id = "agent:copilot-1"
+
+[profiles.copilot.behavior]
+auto_heartbeat = true
+"#,
+        )
+        .unwrap();
+
+        let resolved = resolve_layered_config(Some("copilot"), Some(repo.path()))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(resolved.agent.id, "agent:copilot-1");
+        // Profile didn't set description, so the file's top-level default survives.
+        assert_eq!(resolved.agent.description, "fallback identity");
+        assert!(resolved.behavior.auto_heartbeat);
+    }
+
+    #[test]
+    fn test_layered_config_unknown_profile_errors() {
+        let repo = TempDir::new().unwrap();
+        std::fs::write(
+            repo.path().join("agent.toml"),
+            r#"
+[agent]
+id = "agent:default"
+"#,
+        )
+        .unwrap();
+
+        let result = resolve_layered_config(Some("nonexistent"), Some(repo.path()));
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Unknown agent profile"));
+    }
+
+    #[test]
+    fn test_layered_config_env_var_selects_profile() {
+        let original = env::var("JIT_AGENT_PROFILE").ok();
+        env::set_var("JIT_AGENT_PROFILE", "alice");
+
+        let repo = TempDir::new().unwrap();
+        std::fs::write(
+            repo.path().join("agent.toml"),
+            r#"
+[agent]
+id = "agent:default"
+
+[profiles.alice]
+[profiles.alice.agent]
+id = "human:alice"
+"#,
+        )
+        .unwrap();
+
+        let resolved = resolve_layered_config(None, Some(repo.path()))
+            .unwrap()
+            .unwrap();
+        assert_eq!(resolved.agent.id, "human:alice");
+
+        match original {
+            Some(val) => env::set_var("JIT_AGENT_PROFILE", val),
+            None => env::remove_var("JIT_AGENT_PROFILE"),
+        }
+    }
+
+    #[test]
+    fn test_layered_config_no_files_returns_none() {
+        // Neither a global file (assumed absent in CI sandboxes) nor a
+        // repo-local one exists under this empty temp dir.
+        let repo = TempDir::new().unwrap();
+        if AgentConfig::config_path().unwrap().exists() {
+            // Global agent.toml happens to exist on this machine; skip, as
+            // this test only covers the "nothing configured" case.
+            return;
+        }
+        let result = resolve_layered_config(None, Some(repo.path())).unwrap();
+        assert!(result.is_none());
+    }
+
+    /// Clear every CI-related env var this module inspects, returning their
+    /// prior values so tests can restore them afterwards.
+    fn clear_ci_env() -> Vec<(&'static str, Option<String>)> {
+        let vars = [
+            "GITHUB_ACTIONS",
+            "GITHUB_RUN_ID",
+            "GITHUB_ACTOR",
+            "GITLAB_CI",
+            "CI_JOB_ID",
+            "CI",
+            "HOSTNAME",
+        ];
+        let saved = vars.iter().map(|v| (*v, env::var(v).ok())).collect();
+        for v in vars {
+            env::remove_var(v);
+        }
+        saved
+    }
+
+    fn restore_env(saved: Vec<(&'static str, Option<String>)>) {
+        for (name, value) in saved {
+            match value {
+                Some(v) => env::set_var(name, v),
+                None => env::remove_var(name),
+            }
+        }
+    }
+
+    #[test]
+    fn test_detect_ci_agent_id_github_actions() {
+        let saved = clear_ci_env();
+        env::set_var("GITHUB_ACTIONS", "true");
+        env::set_var("GITHUB_RUN_ID", "123456");
+
+        assert_eq!(
+            detect_ci_agent_id(),
+            Some("ci:github-actions-123456".to_string())
+        );
+
+        restore_env(saved);
+    }
+
+    #[test]
+    fn test_detect_ci_agent_id_gitlab_ci() {
+        let saved = clear_ci_env();
+        env::set_var("GITLAB_CI", "true");
+        env::set_var("CI_JOB_ID", "987");
+
+        assert_eq!(detect_ci_agent_id(), Some("ci:gitlab-ci-987".to_string()));
+
+        restore_env(saved);
+    }
+
+    #[test]
+    fn test_detect_ci_agent_id_generic() {
+        let saved = clear_ci_env();
+        env::set_var("CI", "true");
+        env::set_var("HOSTNAME", "runner-42");
+
+        assert_eq!(detect_ci_agent_id(), Some("ci:generic-runner-42".to_string()));
+
+        restore_env(saved);
+    }
+
+    #[test]
+    fn test_detect_ci_agent_id_none_outside_ci() {
+        let saved = clear_ci_env();
+
+        assert_eq!(detect_ci_agent_id(), None);
+
+        restore_env(saved);
+    }
+
+    #[test]
+    fn test_resolve_agent_id_ci_auto_requires_opt_in() {
+        let ci_saved = clear_ci_env();
+        env::set_var("GITHUB_ACTIONS", "true");
+        env::set_var("GITHUB_RUN_ID", "42");
+        let agent_id_saved = env::var("JIT_AGENT_ID").ok();
+        env::remove_var("JIT_AGENT_ID");
+
+        // Without --ci-auto, CI env vars are ignored and resolution still errors.
+        let result = resolve_agent_id(None, None, None, false);
+        assert!(result.is_err());
+
+        // With --ci-auto, the same environment resolves to a synthesized ID.
+        let result = resolve_agent_id(None, None, None, true).unwrap();
+        assert_eq!(result, "ci:github-actions-42");
+
+        match agent_id_saved {
+            Some(v) => env::set_var("JIT_AGENT_ID", v),
+            None => env::remove_var("JIT_AGENT_ID"),
+        }
+        restore_env(ci_saved);
+    }
+
+    #[test]
+    fn test_layered_config_ci_autodetect_enables_without_cli_flag() {
+        let ci_saved = clear_ci_env();
+        env::set_var("CI", "true");
+        env::set_var("HOSTNAME", "box-1");
+        let agent_id_saved = env::var("JIT_AGENT_ID").ok();
+        env::remove_var("JIT_AGENT_ID");
+
+        let repo = TempDir::new().unwrap();
+        std::fs::write(
+            repo.path().join("agent.toml"),
+            r#"
+[behavior]
+ci_autodetect = true
+"#,
+        )
+        .unwrap();
+
+        let result = resolve_agent_id(None, None, Some(repo.path()), false).unwrap();
+        assert_eq!(result, "ci:generic-box-1");
+
+        match agent_id_saved {
+            Some(v) => env::set_var("JIT_AGENT_ID", v),
+            None => env::remove_var("JIT_AGENT_ID"),
+        }
+        restore_env(ci_saved);
+    }
 }
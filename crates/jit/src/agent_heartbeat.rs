@@ -0,0 +1,225 @@
+//! Background lease-renewal daemon for agents with `auto_heartbeat` enabled.
+//!
+//! `BehaviorSection::auto_heartbeat` in `~/.config/jit/agent.toml` promises
+//! that an agent's active leases keep renewing themselves so a long-running
+//! session doesn't need to call `jit claim renew` by hand. This module is
+//! the daemon that keeps that promise: on a fixed interval it asks the
+//! [`ClaimCoordinator`] for the agent's active leases and renews each one,
+//! retrying a failed renewal with the same bounded exponential backoff used
+//! for webhook delivery (see `crate::notifier::deliver_webhook_with_retry`)
+//! before logging and moving on to the next tick -- a renewal hiccup must
+//! not take down the daemon or the process hosting it.
+//!
+//! This is distinct from [`crate::storage::heartbeat`], which is a pure
+//! liveness ping (PID + last-seen timestamp, no lease awareness); this is
+//! the daemon that actually keeps leases from expiring.
+
+use crate::storage::claim_coordinator::{ClaimCoordinator, Lease};
+use anyhow::Result;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+/// Maximum attempts to renew a single lease before giving up for this tick.
+const MAX_RENEW_ATTEMPTS: u32 = 3;
+
+/// Base delay for renewal retry backoff; doubles after each failed attempt.
+const RENEW_RETRY_BASE_DELAY: Duration = Duration::from_millis(200);
+
+/// Handle to a running heartbeat daemon thread.
+///
+/// Dropping this handle stops the daemon (it does not detach), but does
+/// not join the thread or release any leases; call [`HeartbeatDaemon::stop`]
+/// for a deterministic, joined shutdown.
+pub struct HeartbeatDaemon {
+    stop: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl HeartbeatDaemon {
+    /// Spawn a daemon that renews `agent_id`'s active leases every
+    /// `interval_secs` seconds through `coordinator`, extending each lease's
+    /// TTL by `extension_secs` (the agent's configured `default_ttl_secs`).
+    pub fn spawn(
+        coordinator: ClaimCoordinator,
+        agent_id: String,
+        interval_secs: u64,
+        extension_secs: u64,
+    ) -> Self {
+        let interval_secs = interval_secs.max(1);
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread_stop = Arc::clone(&stop);
+
+        let handle = thread::spawn(move || {
+            while !thread_stop.load(Ordering::Relaxed) {
+                renew_active_leases(&coordinator, &agent_id, extension_secs);
+                sleep_in_slices(Duration::from_secs(interval_secs), &thread_stop);
+            }
+        });
+
+        Self {
+            stop,
+            handle: Some(handle),
+        }
+    }
+
+    /// Request the daemon to stop and wait for its current tick to finish.
+    ///
+    /// Does not release any leases held by the agent -- call
+    /// [`ClaimCoordinator::release_lease`] first if a clean shutdown should
+    /// give them up.
+    pub fn stop(mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for HeartbeatDaemon {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Sleep for `total`, but wake up every second to check `stop` so shutdown
+/// latency doesn't scale with the configured heartbeat interval.
+fn sleep_in_slices(total: Duration, stop: &AtomicBool) {
+    let slice = Duration::from_secs(1);
+    let mut slept = Duration::ZERO;
+    while slept < total && !stop.load(Ordering::Relaxed) {
+        let remaining = total - slept;
+        thread::sleep(remaining.min(slice));
+        slept += slice;
+    }
+}
+
+/// Release every active lease `agent_id` still holds, for a clean shutdown
+/// after stopping the heartbeat daemon. Returns the number of leases
+/// released.
+pub fn release_all_leases(coordinator: &ClaimCoordinator, agent_id: &str) -> Result<usize> {
+    let leases = coordinator.get_active_leases(None, Some(agent_id))?;
+    for lease in &leases {
+        coordinator.release_lease(&lease.lease_id)?;
+    }
+    Ok(leases.len())
+}
+
+fn renew_active_leases(coordinator: &ClaimCoordinator, agent_id: &str, extension_secs: u64) {
+    let leases = match coordinator.get_active_leases(None, Some(agent_id)) {
+        Ok(leases) => leases,
+        Err(err) => {
+            eprintln!("jit: heartbeat: failed to list active leases: {}", err);
+            return;
+        }
+    };
+
+    for lease in &leases {
+        renew_with_retry(coordinator, lease, extension_secs);
+    }
+}
+
+fn renew_with_retry(coordinator: &ClaimCoordinator, lease: &Lease, extension_secs: u64) {
+    let mut last_error = None;
+    for attempt in 1..=MAX_RENEW_ATTEMPTS {
+        match coordinator.renew_lease(&lease.lease_id, extension_secs) {
+            Ok(_) => return,
+            Err(err) => {
+                last_error = Some(err);
+                if attempt < MAX_RENEW_ATTEMPTS {
+                    thread::sleep(RENEW_RETRY_BASE_DELAY * 2u32.pow(attempt - 1));
+                }
+            }
+        }
+    }
+    eprintln!(
+        "jit: heartbeat: failed to renew lease {} after {} attempt(s): {}",
+        lease.lease_id,
+        MAX_RENEW_ATTEMPTS,
+        last_error.unwrap()
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::lock::FileLocker;
+    use crate::storage::worktree_paths::WorktreePaths;
+    use std::time::Duration as StdDuration;
+    use tempfile::TempDir;
+
+    fn setup_coordinator(temp_dir: &TempDir, agent_id: &str) -> ClaimCoordinator {
+        let paths = WorktreePaths {
+            common_dir: temp_dir.path().join(".git"),
+            worktree_root: temp_dir.path().to_path_buf(),
+            local_jit: temp_dir.path().join(".jit"),
+            shared_jit: temp_dir.path().join(".git/jit"),
+        };
+
+        let locker = FileLocker::new(StdDuration::from_secs(5));
+        ClaimCoordinator::new(paths, locker, "wt:test123".to_string(), agent_id.to_string())
+    }
+
+    #[test]
+    fn test_daemon_renews_active_lease_past_its_original_expiry() {
+        let temp = TempDir::new().unwrap();
+        let coordinator = setup_coordinator(&temp, "agent:heartbeat-test");
+        coordinator.init().unwrap();
+        let lease = coordinator.acquire_claim("issue-1", 1).unwrap();
+
+        let daemon = HeartbeatDaemon::spawn(
+            setup_coordinator(&temp, "agent:heartbeat-test"),
+            "agent:heartbeat-test".to_string(),
+            1,
+            600,
+        );
+
+        // Give the daemon a couple of ticks to renew past the 1s original TTL.
+        thread::sleep(Duration::from_millis(2500));
+        daemon.stop();
+
+        let renewed = coordinator
+            .get_active_leases(None, Some("agent:heartbeat-test"))
+            .unwrap()
+            .into_iter()
+            .find(|l| l.lease_id == lease.lease_id)
+            .expect("lease should still be active after renewal");
+
+        assert!(renewed.expires_at.unwrap() > lease.expires_at.unwrap());
+    }
+
+    #[test]
+    fn test_daemon_stop_joins_promptly_even_mid_interval() {
+        let temp = TempDir::new().unwrap();
+        let coordinator = setup_coordinator(&temp, "agent:idle");
+        coordinator.init().unwrap();
+
+        let daemon = HeartbeatDaemon::spawn(coordinator, "agent:idle".to_string(), 3600, 3600);
+        thread::sleep(Duration::from_millis(50));
+
+        let start = std::time::Instant::now();
+        daemon.stop();
+        assert!(
+            start.elapsed() < Duration::from_secs(2),
+            "stop() should not block for the full heartbeat interval"
+        );
+    }
+
+    #[test]
+    fn test_release_all_leases_releases_every_active_lease() {
+        let temp = TempDir::new().unwrap();
+        let coordinator = setup_coordinator(&temp, "agent:releaser");
+        coordinator.init().unwrap();
+        coordinator.acquire_claim("issue-1", 600).unwrap();
+        coordinator.acquire_claim("issue-2", 600).unwrap();
+
+        let released = release_all_leases(&coordinator, "agent:releaser").unwrap();
+        assert_eq!(released, 2);
+
+        let remaining = coordinator
+            .get_active_leases(None, Some("agent:releaser"))
+            .unwrap();
+        assert!(remaining.is_empty());
+    }
+}
@@ -0,0 +1,222 @@
+//! Portable bundle format for handing off in-progress issues across
+//! repositories that don't share a git remote (air-gapped or fork-based
+//! workflows).
+//!
+//! A bundle packs a selected set of [`Issue`]s together with their full
+//! [`Event`] history, and the file contents of any documents they
+//! reference, into a single, signed, content-addressed archive file. So
+//! `jit validate` on the importing side can resolve those document links
+//! instead of reporting them broken/missing, the way it would if only the
+//! `DocumentReference` metadata (and not the file it points at) made the
+//! trip. Content addressing follows the same SHA-256-over-canonical-bytes
+//! convention used elsewhere in the repo (e.g. gate approval hashing in
+//! [`crate::crypto`]); signing reuses that same module's Ed25519 key, so
+//! a bundle produced by a contributor with `JIT_GATE_SIGNING_KEY` set can
+//! later be checked against the same trusted-key list used for gate
+//! verification.
+//!
+//! Importing a bundle doesn't trust its issue snapshots directly; it
+//! replays the packed events through the same CRDT merge path used by
+//! `jit merge`/`jit pull` (see `crate::commands::merge`), so the issues
+//! graft cleanly into a repo that may already know some of them.
+
+use crate::crypto;
+use crate::domain::{Event, Issue};
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// A document's file contents as packed into a bundle, keyed by the same
+/// repo-relative path an issue's `DocumentReference` points at.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundledDocument {
+    pub path: String,
+    pub content: String,
+}
+
+/// On-disk bundle format: a manifest plus the issues, events, and
+/// referenced document contents it covers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Bundle {
+    pub manifest: BundleManifest,
+    pub issues: Vec<Issue>,
+    pub events: Vec<Event>,
+    #[serde(default)]
+    pub documents: Vec<BundledDocument>,
+}
+
+/// Manifest describing and authenticating a bundle's contents.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundleManifest {
+    /// Manifest version (currently "1")
+    pub version: String,
+    /// Timestamp when the bundle was created
+    pub created_at: DateTime<Utc>,
+    /// Issue IDs included in the bundle, in export order
+    pub issue_ids: Vec<String>,
+    /// Number of events packed alongside the issues
+    pub event_count: usize,
+    /// SHA-256 (hex) over the canonical `(issues, events, documents)`
+    /// content, so tampering or truncation in transit is detectable
+    /// before import
+    pub content_hash: String,
+    /// Detached Ed25519 signature (hex) over `content_hash`, present only
+    /// when the exporting repository had `JIT_GATE_SIGNING_KEY` set
+    #[serde(default)]
+    pub signature: Option<String>,
+}
+
+impl Bundle {
+    /// Build a bundle from a set of issues and their events (plus the
+    /// contents of any documents they link to), hashing (and
+    /// opportunistically signing) its content.
+    pub fn new(
+        issues: Vec<Issue>,
+        events: Vec<Event>,
+        documents: Vec<BundledDocument>,
+    ) -> Result<Self> {
+        let issue_ids = issues.iter().map(|i| i.id.clone()).collect();
+        let (hash_bytes, content_hash) = hash_content(&issues, &events, &documents)?;
+        let signature = crypto::sign_with_env_key(&hash_bytes)?;
+
+        Ok(Self {
+            manifest: BundleManifest {
+                version: "1".to_string(),
+                created_at: Utc::now(),
+                issue_ids,
+                event_count: events.len(),
+                content_hash,
+                signature,
+            },
+            issues,
+            events,
+            documents,
+        })
+    }
+
+    /// Verify the manifest's content hash against the bundle's actual
+    /// issues/events/documents, failing loudly if they've diverged
+    /// (truncated transfer, hand-edited file, etc).
+    pub fn verify_content_hash(&self) -> Result<()> {
+        let (_, actual) = hash_content(&self.issues, &self.events, &self.documents)?;
+        if actual != self.manifest.content_hash {
+            return Err(anyhow!(
+                "bundle content hash mismatch: manifest claims {}, contents hash to {}",
+                self.manifest.content_hash,
+                actual
+            ));
+        }
+        Ok(())
+    }
+
+    /// Verify the manifest's signature against a trusted public key.
+    /// Returns `false` (not an error) for an unsigned bundle or a
+    /// malformed/mismatched signature.
+    pub fn verify_signature(&self, public_key_hex: &str) -> bool {
+        let Some(signature) = &self.manifest.signature else {
+            return false;
+        };
+        let Ok((hash_bytes, _)) = hash_content(&self.issues, &self.events, &self.documents) else {
+            return false;
+        };
+        crypto::verify_signature(&hash_bytes, signature, public_key_hex)
+    }
+
+    pub fn to_json_string(&self) -> Result<String> {
+        serde_json::to_string_pretty(self).context("Failed to serialize bundle")
+    }
+
+    pub fn from_json_str(data: &str) -> Result<Self> {
+        serde_json::from_str(data).context("Failed to deserialize bundle")
+    }
+}
+
+/// Hash the canonical bytes of `(issues, events, documents)`, returning
+/// both the raw digest (for signing) and its hex encoding (for the
+/// manifest).
+fn hash_content(
+    issues: &[Issue],
+    events: &[Event],
+    documents: &[BundledDocument],
+) -> Result<([u8; 32], String)> {
+    let canonical = serde_json::to_vec(&(issues, events, documents))
+        .context("Failed to canonicalize bundle content")?;
+    let mut hasher = Sha256::new();
+    hasher.update(&canonical);
+    let digest: [u8; 32] = hasher.finalize().into();
+    let hex = digest.iter().map(|b| format!("{:02x}", b)).collect();
+    Ok((digest, hex))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::Priority;
+
+    fn sample_issue() -> Issue {
+        Issue::new("Test issue".to_string(), String::new())
+    }
+
+    #[test]
+    fn test_new_bundle_hashes_content() {
+        let issue = sample_issue();
+        let event = Event::new_issue_created(&issue);
+
+        let bundle = Bundle::new(vec![issue], vec![event], Vec::new()).unwrap();
+
+        assert_eq!(bundle.manifest.version, "1");
+        assert_eq!(bundle.manifest.event_count, 1);
+        assert!(bundle.verify_content_hash().is_ok());
+    }
+
+    #[test]
+    fn test_tampered_content_fails_hash_check() {
+        let issue = sample_issue();
+        let event = Event::new_issue_created(&issue);
+        let mut bundle = Bundle::new(vec![issue], vec![event], Vec::new()).unwrap();
+
+        bundle.issues[0].priority = Priority::Critical;
+
+        assert!(bundle.verify_content_hash().is_err());
+    }
+
+    #[test]
+    fn test_json_roundtrip() {
+        let issue = sample_issue();
+        let event = Event::new_issue_created(&issue);
+        let bundle = Bundle::new(vec![issue], vec![event], Vec::new()).unwrap();
+
+        let json = bundle.to_json_string().unwrap();
+        let restored = Bundle::from_json_str(&json).unwrap();
+
+        assert_eq!(restored.manifest.content_hash, bundle.manifest.content_hash);
+        assert_eq!(restored.issues.len(), 1);
+    }
+
+    #[test]
+    fn test_unsigned_bundle_rejects_any_signature_check() {
+        std::env::remove_var("JIT_GATE_SIGNING_KEY");
+        let issue = sample_issue();
+        let event = Event::new_issue_created(&issue);
+        let bundle = Bundle::new(vec![issue], vec![event], Vec::new()).unwrap();
+
+        assert!(bundle.manifest.signature.is_none());
+        assert!(!bundle.verify_signature("00".repeat(32).as_str()));
+    }
+
+    #[test]
+    fn test_tampered_document_content_fails_hash_check() {
+        let issue = sample_issue();
+        let event = Event::new_issue_created(&issue);
+        let documents = vec![BundledDocument {
+            path: "docs/design.md".to_string(),
+            content: "original".to_string(),
+        }];
+        let mut bundle = Bundle::new(vec![issue], vec![event], documents).unwrap();
+
+        bundle.documents[0].content = "tampered".to_string();
+
+        assert!(bundle.verify_content_hash().is_err());
+    }
+}
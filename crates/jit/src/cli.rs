@@ -24,6 +24,22 @@ pub struct Cli {
     #[arg(long)]
     pub schema: bool,
 
+    /// Run against a remote jit-server instead of the local .jit directory
+    ///
+    /// Only the query/claim/report surface is served remotely (see
+    /// `jit-server`'s `/api/query/*` and `/api/issues/:id/{claim,report}`
+    /// routes); every other command still requires a local repository.
+    /// Can also be set via JIT_REMOTE_URL.
+    #[arg(long, env = "JIT_REMOTE_URL")]
+    pub remote: Option<String>,
+
+    /// Bearer token to authenticate against --remote.
+    ///
+    /// Can also be set via JIT_REMOTE_TOKEN. Required whenever --remote is
+    /// set; the server rejects unauthenticated requests.
+    #[arg(long, env = "JIT_REMOTE_TOKEN")]
+    pub token: Option<String>,
+
     #[command(subcommand)]
     pub command: Option<Commands>,
 }
@@ -45,6 +61,11 @@ pub enum Commands {
     #[command(subcommand)]
     Dep(DepCommands),
 
+    /// Exclusive, TTL-bound lease coordination commands, distinct from the
+    /// simple assignee-based claiming in `jit issue claim`
+    #[command(subcommand)]
+    Claim(ClaimCommands),
+
     /// Gate management commands
     ///
     /// Gates are quality checkpoints (tests, reviews, scans) that enforce workflow quality.
@@ -70,6 +91,12 @@ pub enum Commands {
     #[command(subcommand)]
     Doc(DocCommands),
 
+    /// Portable issue bundle export/import, for handing off in-progress
+    /// work across air-gapped or fork-based workflows without a shared
+    /// git remote
+    #[command(subcommand)]
+    Bundle(BundleCommands),
+
     /// Graph query commands
     #[command(subcommand)]
     Graph(GraphCommands),
@@ -134,7 +161,158 @@ pub enum Commands {
         /// Show what would be fixed without applying changes (requires --fix)
         #[arg(long)]
         dry_run: bool,
+
+        /// Path to a TOML file of custom hierarchy validation rules (see
+        /// `jit::type_hierarchy::load_custom_rules`) to run alongside the
+        /// built-in strategic-label/orphan warnings.
+        #[arg(long)]
+        rules: Option<String>,
+    },
+
+    /// Apply a batch of operations from an NDJSON file as a single transaction
+    Batch {
+        /// Path to an NDJSON file of operations (create, dep_add, update, claim)
+        file: String,
+
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Sweep issues for passed deadlines and SLA warnings
+    Sweep {
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Tail the local notifier feed (`.jit/watch.jsonl`), printing each
+    /// event as it's delivered to the `watch` sink
+    Watch {
+        /// Print events already in the feed before tailing for new ones
+        #[arg(long)]
+        from_start: bool,
+
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Emit scrape-friendly aggregate metrics
+    Metrics {
+        /// Output format: "prometheus" (default) or "json"
+        #[arg(long, default_value = "prometheus")]
+        format: String,
     },
+
+    /// Compute the critical path over the dependency graph using each
+    /// issue's estimated duration
+    CriticalPath {
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// List issues ready to work, optionally bounded to a concurrency limit
+    Ready {
+        /// Bound the ready set to at most N issues, so N workers can be
+        /// dispatched concurrently without contention
+        #[arg(long)]
+        parallel: Option<usize>,
+
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Block until an issue reaches a terminal state, polling the store
+    Wait {
+        id: String,
+
+        /// Poll interval in milliseconds
+        #[arg(long, default_value = "500")]
+        interval_ms: u64,
+
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Report an outcome for an issue an agent was working on: `done`
+    /// clears the assignee and completes it (subject to gate validation),
+    /// `failed` requeues it to `ready` up to a retry budget before
+    /// rejecting it, and `progress` just records a status message
+    Report {
+        /// Issue ID
+        id: String,
+
+        /// Outcome: "done", "failed", or "progress"
+        #[arg(long)]
+        status: String,
+
+        /// Free-form detail, e.g. an error message
+        #[arg(long)]
+        message: Option<String>,
+
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Mirror local events to git refs and push them to a remote
+    Push {
+        /// Git remote name
+        #[arg(default_value = "origin")]
+        remote: String,
+
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Fetch event refs from a remote and replay any new events locally
+    Pull {
+        /// Git remote name
+        #[arg(default_value = "origin")]
+        remote: String,
+
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Merge two `events.jsonl` logs and recompute issue state, for use as
+    /// a git merge driver (invoke as `jit merge %O %A %B`; the result is
+    /// written back to the "ours" path, matching git's merge driver
+    /// contract)
+    Merge {
+        /// Common-ancestor event log (unused; union merge needs no base)
+        ancestor: String,
+
+        /// "Ours" event log; overwritten with the merged result
+        ours: String,
+
+        /// "Theirs" event log
+        theirs: String,
+
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Export every issue, the gate registry, and config files into a
+    /// single gzip-tar archive for backup or migration to another repo
+    Dump {
+        /// Output file path; defaults to stdout when omitted
+        #[arg(long)]
+        out: Option<String>,
+
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Restore issues and gates from an archive produced by `jit dump`
+    Restore {
+        /// Path to the dump archive
+        file: String,
+
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Manage durable `jit issue update --filter ... --async` jobs
+    #[command(subcommand)]
+    Job(JobCommands),
 }
 
 #[derive(Subcommand)]
@@ -169,6 +347,12 @@ pub enum IssueCommands {
         #[arg(long)]
         orphan: bool,
 
+        /// Path to a TOML file of custom hierarchy validation rules (see
+        /// `jit::type_hierarchy::load_custom_rules`) to run alongside the
+        /// built-in strategic-label/orphan warnings.
+        #[arg(long)]
+        rules: Option<String>,
+
         #[arg(long)]
         json: bool,
     },
@@ -214,30 +398,62 @@ pub enum IssueCommands {
         json: bool,
     },
 
-    /// Update an issue
+    /// Update an issue, or batch-apply updates described by a JSON file
     Update {
-        id: String,
+        /// Issue to update. Omit when using `--filter` or `--batch`.
+        #[arg(conflicts_with = "filter")]
+        id: Option<String>,
 
-        #[arg(short, long)]
+        #[arg(short, long, conflicts_with_all = ["batch", "filter"])]
         title: Option<String>,
 
-        #[arg(short = 'd', long = "description")]
+        #[arg(short = 'd', long = "description", conflicts_with_all = ["batch", "filter"])]
         description: Option<String>,
 
-        #[arg(short, long)]
+        #[arg(short, long, conflicts_with = "batch")]
         priority: Option<String>,
 
-        #[arg(short, long)]
+        #[arg(short, long, conflicts_with = "batch")]
         state: Option<String>,
 
         /// Add label(s) (format: namespace:value, repeatable)
-        #[arg(short, long)]
+        #[arg(short, long, conflicts_with = "batch")]
         label: Vec<String>,
 
         /// Remove label(s) (repeatable)
-        #[arg(long)]
+        #[arg(long, conflicts_with = "batch")]
         remove_label: Vec<String>,
 
+        /// New assignee to set on every issue matched by `--filter`
+        #[arg(long, conflicts_with = "batch")]
+        assignee: Option<String>,
+
+        /// Query filter selecting issues to update in bulk, instead of a
+        /// single `id` (same syntax as `jit query`)
+        #[arg(long, conflicts_with = "batch")]
+        filter: Option<String>,
+
+        /// With `--filter`: enqueue a durable job instead of applying
+        /// immediately; drain it later with `jit job run <id>`
+        #[arg(long = "async", requires = "filter")]
+        r#async: bool,
+
+        /// Path to a JSON file of batch entries, each with its own
+        /// `filter` (or `ids`) plus `state`/`add_label`/`remove_label`/
+        /// `assignee` mutations, applied as a single transaction
+        #[arg(long, conflicts_with = "id")]
+        batch: Option<String>,
+
+        /// With `--batch`: resolve and validate every entry without
+        /// writing anything
+        #[arg(long, requires = "batch")]
+        dry_run: bool,
+
+        /// With `--batch`: if any entry errors, roll back every write so
+        /// the store is left untouched
+        #[arg(long, requires = "batch")]
+        atomic: bool,
+
         #[arg(long)]
         json: bool,
     },
@@ -250,6 +466,34 @@ pub enum IssueCommands {
         json: bool,
     },
 
+    /// Transition an issue to a new state, validated against the
+    /// repository's configured workflow transition table
+    Transition {
+        id: String,
+
+        /// Target state name (e.g. "ready", "in-progress", "done")
+        to: String,
+
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Propose an LLM-assisted breakdown of an issue into subtasks (requires
+    /// the `llm-suggest` feature); review the suggestion, then re-run with
+    /// `--accept` to materialize it
+    #[cfg(feature = "llm-suggest")]
+    Suggest {
+        /// Parent issue ID to propose a breakdown for
+        id: String,
+
+        /// Materialize the suggestion into real subtask issues
+        #[arg(long)]
+        accept: bool,
+
+        #[arg(long)]
+        json: bool,
+    },
+
     /// Break down an issue into subtasks with automatic dependency inheritance
     Breakdown {
         /// Parent issue ID to break down
@@ -326,6 +570,22 @@ pub enum IssueCommands {
         json: bool,
     },
 
+    /// Set a key in an issue's free-form context map (e.g. for orchestrators
+    /// to stash a claim timestamp alongside an assignment)
+    SetContext {
+        /// Issue ID
+        id: String,
+
+        /// Context key
+        key: String,
+
+        /// Context value
+        value: String,
+
+        #[arg(long)]
+        json: bool,
+    },
+
     /// Claim the next available ready issue
     ClaimNext {
         /// Assignee (format: type:identifier, e.g., agent:worker-1)
@@ -336,6 +596,56 @@ pub enum IssueCommands {
     },
 }
 
+#[derive(Subcommand)]
+pub enum ClaimCommands {
+    /// Acquire an exclusive, TTL-bound lease on an issue
+    Acquire {
+        /// Issue ID
+        id: String,
+
+        /// Lease time-to-live in seconds (0 = indefinite, subject to the
+        /// repo's max-indefinite-leases policy)
+        #[arg(long, default_value = "600")]
+        ttl_secs: u64,
+
+        /// Agent ID (format: {type}:{identifier}); falls back to the usual
+        /// --agent-id/JIT_AGENT_ID/agent.toml resolution if omitted
+        #[arg(long)]
+        agent_id: Option<String>,
+
+        /// Reason for the lease, required when --ttl-secs is 0
+        #[arg(long)]
+        reason: Option<String>,
+
+        /// Keep this process running and renewing the lease on an interval
+        /// until interrupted, instead of returning immediately. Implied by
+        /// `[behavior] auto_heartbeat = true` in the resolved agent.toml.
+        #[arg(long)]
+        watch: bool,
+
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Send a heartbeat for an indefinite lease to prevent staleness
+    Heartbeat {
+        /// Lease ID
+        lease_id: String,
+
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Release a previously acquired lease
+    Release {
+        /// Lease ID
+        lease_id: String,
+
+        #[arg(long)]
+        json: bool,
+    },
+}
+
 #[derive(Subcommand)]
 pub enum DepCommands {
     /// Add a work dependency: FROM is blocked until TO completes
@@ -504,6 +814,40 @@ pub enum GateCommands {
         #[arg(long)]
         json: bool,
     },
+
+    /// Verify signed gate approvals against trusted public keys
+    ///
+    /// Recomputes the approval hash for each of the issue's required gates
+    /// and reports whether its signature is valid, missing, or forged
+    /// (signed by an untrusted identity or failing verification).
+    Verify {
+        /// Issue ID
+        id: String,
+
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Run `auto` gates' commands for in-progress issues
+    ///
+    /// Scans in-progress issues (or a single issue, if given) for required
+    /// gates with `auto: true` and a resolved command, runs each command,
+    /// and records a signed pass/fail based on its exit code.
+    Run {
+        /// Issue ID; if omitted, scans all in-progress issues
+        id: Option<String>,
+
+        /// Keep running, re-scanning on an interval, instead of a single pass
+        #[arg(long)]
+        watch: bool,
+
+        /// Interval in seconds between scans when `--watch` is set
+        #[arg(long, default_value = "30")]
+        interval: u64,
+
+        #[arg(long)]
+        json: bool,
+    },
 }
 
 #[derive(Subcommand)]
@@ -635,6 +979,41 @@ pub enum DocCommands {
     },
 }
 
+#[derive(Subcommand)]
+pub enum BundleCommands {
+    /// Pack one or more issues and their full event history into a
+    /// signed, content-addressed bundle file
+    Export {
+        /// Issue IDs to include
+        #[arg(required = true)]
+        issues: Vec<String>,
+
+        /// Path to write the bundle to
+        #[arg(short, long)]
+        output: String,
+
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Replay a bundle's events through the CRDT merge path, grafting its
+    /// issues onto the local repository
+    Import {
+        /// Path to the bundle file
+        path: String,
+
+        /// Skip signature verification and import even an unsigned or
+        /// untrusted-signer bundle. Off by default: import normally
+        /// requires the bundle's signature to verify against one of this
+        /// repo's `[signing] trusted_keys`.
+        #[arg(long)]
+        insecure: bool,
+
+        #[arg(long)]
+        json: bool,
+    },
+}
+
 #[derive(Subcommand)]
 pub enum AssetCommands {
     /// List assets for a document
@@ -856,3 +1235,28 @@ pub enum ConfigCommands {
         json: bool,
     },
 }
+
+#[derive(Subcommand)]
+pub enum JobCommands {
+    /// Show a job's current status and live progress totals
+    Status {
+        id: String,
+
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// List every persisted job, oldest first
+    List {
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Drain a queued or partially-run job, resuming from its cursor
+    Run {
+        id: String,
+
+        #[arg(long)]
+        json: bool,
+    },
+}
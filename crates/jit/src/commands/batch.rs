@@ -0,0 +1,227 @@
+//! Atomic batch application of operations from an NDJSON file.
+//!
+//! Lets a whole epic-with-dependencies-and-gates be provisioned in a single
+//! call instead of command-by-command. Operations may forward-reference
+//! issues created earlier in the same batch via a caller-supplied alias.
+//! If any operation fails, the store is rolled back to its pre-batch state.
+
+use super::*;
+use serde::Deserialize;
+
+/// A single operation parsed from one line of the NDJSON batch file.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum BatchOperation {
+    /// Create a new issue, optionally registering it under `alias` for
+    /// forward-reference by later operations in the same batch.
+    Create {
+        #[serde(default)]
+        alias: Option<String>,
+        title: String,
+        #[serde(default)]
+        description: String,
+        #[serde(default)]
+        priority: Option<String>,
+        #[serde(default)]
+        labels: Vec<String>,
+        #[serde(default)]
+        gates: Vec<String>,
+    },
+    /// Add a dependency edge. `from`/`to` may be an issue id, a short hash,
+    /// or an alias defined earlier in the batch.
+    DepAdd { from: String, to: String },
+    /// Update an issue's state and/or assignee.
+    Update {
+        id: String,
+        #[serde(default)]
+        state: Option<String>,
+        #[serde(default)]
+        assignee: Option<String>,
+    },
+    /// Claim an issue for an assignee.
+    Claim { id: String, assignee: String },
+}
+
+/// Why a batch operation failed, with its index in the file.
+#[derive(Debug, Clone, Serialize)]
+pub struct BatchFailure {
+    /// Zero-based index of the failing operation within the file.
+    pub index: usize,
+    /// Raw operation text, for diagnostics.
+    pub op: String,
+    /// Human-readable failure reason.
+    pub reason: String,
+}
+
+/// Outcome of a successful batch apply.
+#[derive(Debug, Clone, Serialize)]
+pub struct BatchResult {
+    /// Number of operations applied.
+    pub applied: usize,
+    /// Aliases resolved to their generated issue ids.
+    pub created: HashMap<String, String>,
+}
+
+impl<S: IssueStore> CommandExecutor<S> {
+    /// Apply every operation in `ops` as a single transaction.
+    ///
+    /// On success, returns the number of applied operations and the
+    /// alias -> id map for any issues created along the way. On failure,
+    /// every issue that existed before the batch is restored to its prior
+    /// state and any issues created during the batch are deleted, so the
+    /// store is left exactly as it was found.
+    pub fn apply_batch(&self, ops: &[(usize, String, BatchOperation)]) -> Result<BatchResult> {
+        let snapshot: Vec<Issue> = self.storage.list_issues()?;
+        let snapshot_ids: std::collections::HashSet<String> =
+            snapshot.iter().map(|i| i.id.clone()).collect();
+
+        let mut aliases: HashMap<String, String> = HashMap::new();
+        let mut created_ids: Vec<String> = Vec::new();
+
+        let result = (|| -> Result<()> {
+            for (index, raw, op) in ops {
+                self.apply_batch_op(op, &mut aliases, &mut created_ids)
+                    .map_err(|e| BatchApplyError {
+                        index: *index,
+                        op: raw.clone(),
+                        reason: e.to_string(),
+                    })?;
+            }
+            Ok(())
+        })();
+
+        if let Err(err) = result {
+            self.rollback_batch(&snapshot, &snapshot_ids, &created_ids)?;
+            if let Some(batch_err) = err.downcast_ref::<BatchApplyError>() {
+                return Err(anyhow!(
+                    "batch failed at operation {} ({}): {}",
+                    batch_err.index,
+                    batch_err.op,
+                    batch_err.reason
+                ));
+            }
+            return Err(err);
+        }
+
+        Ok(BatchResult {
+            applied: ops.len(),
+            created: aliases,
+        })
+    }
+
+    fn apply_batch_op(
+        &self,
+        op: &BatchOperation,
+        aliases: &mut HashMap<String, String>,
+        created_ids: &mut Vec<String>,
+    ) -> Result<()> {
+        match op {
+            BatchOperation::Create {
+                alias,
+                title,
+                description,
+                priority,
+                labels,
+                gates,
+            } => {
+                let priority = match priority {
+                    Some(p) => parse_priority(p)?,
+                    None => Priority::Normal,
+                };
+                let id = self.create_issue(
+                    title.clone(),
+                    description.clone(),
+                    priority,
+                    gates.clone(),
+                    labels.clone(),
+                )?;
+                created_ids.push(id.clone());
+                if let Some(alias) = alias {
+                    aliases.insert(alias.clone(), id);
+                }
+            }
+            BatchOperation::DepAdd { from, to } => {
+                let from_id = self.resolve_batch_ref(from, aliases)?;
+                let to_id = self.resolve_batch_ref(to, aliases)?;
+                self.add_dependency(&from_id, &to_id)?;
+            }
+            BatchOperation::Update {
+                id,
+                state,
+                assignee,
+            } => {
+                let issue_id = self.resolve_batch_ref(id, aliases)?;
+                if let Some(state) = state {
+                    self.update_issue_state(&issue_id, parse_state(state)?)?;
+                }
+                if let Some(assignee) = assignee {
+                    self.assign_issue(&issue_id, assignee.clone())?;
+                }
+            }
+            BatchOperation::Claim { id, assignee } => {
+                let issue_id = self.resolve_batch_ref(id, aliases)?;
+                self.claim_issue(&issue_id, assignee.clone())?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Resolve a batch-local alias first, falling back to a stored issue id.
+    fn resolve_batch_ref(&self, reference: &str, aliases: &HashMap<String, String>) -> Result<String> {
+        if let Some(id) = aliases.get(reference) {
+            return Ok(id.clone());
+        }
+        self.storage.resolve_issue_id(reference)
+    }
+
+    fn rollback_batch(
+        &self,
+        snapshot: &[Issue],
+        snapshot_ids: &std::collections::HashSet<String>,
+        created_ids: &[String],
+    ) -> Result<()> {
+        for issue in snapshot {
+            self.storage.save_issue(issue)?;
+        }
+        for id in created_ids {
+            if !snapshot_ids.contains(id) {
+                // Ignore errors: the issue may not have been persisted yet
+                // (e.g. validation failed before the save).
+                let _ = self.storage.delete_issue(id);
+            }
+        }
+        Ok(())
+    }
+
+}
+
+/// Parse an NDJSON batch file into operations, preserving the raw line
+/// text and index for error reporting.
+pub fn parse_batch_file(contents: &str) -> Result<Vec<(usize, String, BatchOperation)>> {
+    let mut ops = Vec::new();
+    for (index, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let op: BatchOperation = serde_json::from_str(line)
+            .map_err(|e| anyhow!("invalid operation at line {}: {}", index + 1, e))?;
+        ops.push((index, line.to_string(), op));
+    }
+    Ok(ops)
+}
+
+#[derive(Debug, Clone)]
+struct BatchApplyError {
+    index: usize,
+    op: String,
+    reason: String,
+}
+
+impl std::fmt::Display for BatchApplyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "operation {} ({}) failed: {}", self.index, self.op, self.reason)
+    }
+}
+
+impl std::error::Error for BatchApplyError {}
@@ -5,11 +5,58 @@
 
 use super::*;
 use crate::domain::{Issue, Priority, State};
-use crate::query::QueryFilter;
+use crate::output::ErrorCode;
+use crate::query::{QueryFilter, QueryParseError};
 use serde::Serialize;
+use thiserror::Error;
+
+/// Why an issue failed bulk-update validation, carrying the stable error
+/// code ([`ErrorCode`]) that gets surfaced alongside it in [`BulkUpdateError`].
+#[derive(Debug, Error)]
+enum BulkValidationError {
+    #[error("Cannot transition to {0:?}: blocked by dependencies")]
+    BlockedByDependency(State),
+    #[error("Cannot transition to Done: {0} gates pending")]
+    GateNotSatisfied(usize),
+}
+
+impl BulkValidationError {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::BlockedByDependency(_) => ErrorCode::BLOCKED,
+            Self::GateNotSatisfied(_) => ErrorCode::GATE_NOT_SATISFIED,
+        }
+    }
+}
+
+/// Classify an error from [`CommandExecutor::validate_update`] or a batch
+/// entry resolution into the stable [`ErrorCode`] it corresponds to, so
+/// every failure path -- per-issue or per-entry -- reports the same taxonomy.
+fn classify_error(e: &anyhow::Error) -> &'static str {
+    if let Some(validation_err) = e.downcast_ref::<BulkValidationError>() {
+        return validation_err.code();
+    }
+    if e.downcast_ref::<QueryParseError>().is_some() {
+        return ErrorCode::QUERY_PARSE_ERROR;
+    }
+    if e.to_string().contains("must specify either") {
+        return ErrorCode::MISSING_ID_OR_FILTER;
+    }
+    ErrorCode::VALIDATION_FAILED
+}
+
+/// Outcome of [`CommandExecutor::apply_update_step`] for one issue.
+pub(crate) enum StepOutcome {
+    /// The issue had real changes and (unless dry-run) was saved.
+    Modified,
+    /// The issue matched but `operations` was a no-op for it.
+    Skipped,
+    /// Validation rejected the operation for this issue.
+    Error(BulkUpdateError),
+}
 
 /// Operations to apply to issues
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, serde::Deserialize)]
 pub struct UpdateOperations {
     /// New state to set
     pub state: Option<State>,
@@ -26,7 +73,7 @@ pub struct UpdateOperations {
 }
 
 /// Result of bulk update operation
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, serde::Deserialize)]
 pub struct BulkUpdateResult {
     /// IDs that matched the filter
     pub matched: Vec<String>,
@@ -34,14 +81,24 @@ pub struct BulkUpdateResult {
     pub modified: Vec<String>,
     /// IDs skipped with reasons (id, reason)
     pub skipped: Vec<(String, String)>,
-    /// IDs that failed with errors (id, error)
-    pub errors: Vec<(String, String)>,
+    /// Issues that failed, each with a stable [`ErrorCode`] to branch on
+    pub errors: Vec<BulkUpdateError>,
     /// Summary statistics
     pub summary: BulkUpdateSummary,
 }
 
+/// One failed item in a [`BulkUpdateResult`]: the issue it applies to (or
+/// `<batch-entry>` for an entry-level failure that never resolved to
+/// issues), a stable machine-readable code, and a human-readable message.
+#[derive(Debug, Serialize, serde::Deserialize)]
+pub struct BulkUpdateError {
+    pub id: String,
+    pub code: String,
+    pub message: String,
+}
+
 /// Summary statistics for bulk update
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, serde::Deserialize)]
 pub struct BulkUpdateSummary {
     pub total_matched: usize,
     pub total_modified: usize,
@@ -144,6 +201,74 @@ impl Default for BulkUpdatePreview {
     }
 }
 
+/// One entry in a `jit issue update --batch <file>` file: a target
+/// selector (`filter` query or explicit `ids`) plus the mutations to apply
+/// to every issue it selects. `filter` and `ids` are mutually exclusive;
+/// `ids` wins if both are present.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+pub struct BatchUpdateEntry {
+    /// Query filter selecting target issues
+    #[serde(default)]
+    pub filter: Option<String>,
+    /// Explicit issue ids to target, instead of `filter`
+    #[serde(default)]
+    pub ids: Vec<String>,
+    /// New state to set
+    #[serde(default)]
+    pub state: Option<String>,
+    /// Labels to add
+    #[serde(default)]
+    pub add_label: Vec<String>,
+    /// Labels to remove
+    #[serde(default)]
+    pub remove_label: Vec<String>,
+    /// New assignee to set
+    #[serde(default)]
+    pub assignee: Option<String>,
+}
+
+impl BatchUpdateEntry {
+    fn to_update_operations(&self) -> Result<UpdateOperations> {
+        let state = self.state.as_deref().map(parse_state).transpose()?;
+        Ok(UpdateOperations {
+            state,
+            add_labels: self.add_label.clone(),
+            remove_labels: self.remove_label.clone(),
+            assignee: self.assignee.clone(),
+            unassign: false,
+            priority: None,
+        })
+    }
+}
+
+/// Report for a `jit issue update --batch` run: the result of each entry,
+/// in file order, plus totals aggregated across all of them.
+#[derive(Debug, Serialize)]
+pub struct BatchUpdateReport {
+    /// Per-entry result, in file order
+    pub operations: Vec<BulkUpdateResult>,
+    /// Totals aggregated across all entries
+    pub summary: BulkUpdateSummary,
+    /// True if `--atomic` reverted every write because some entry errored
+    pub rolled_back: bool,
+}
+
+impl BatchUpdateReport {
+    fn from_operations(operations: Vec<BulkUpdateResult>, rolled_back: bool) -> Self {
+        let summary = BulkUpdateSummary {
+            total_matched: operations.iter().map(|o| o.summary.total_matched).sum(),
+            total_modified: operations.iter().map(|o| o.summary.total_modified).sum(),
+            total_skipped: operations.iter().map(|o| o.summary.total_skipped).sum(),
+            total_errors: operations.iter().map(|o| o.summary.total_errors).sum(),
+        };
+        BatchUpdateReport {
+            operations,
+            summary,
+            rolled_back,
+        }
+    }
+}
+
 impl<S: IssueStore> CommandExecutor<S> {
     /// Preview bulk update without applying changes (dry-run)
     pub fn preview_bulk_update(
@@ -229,6 +354,208 @@ impl<S: IssueStore> CommandExecutor<S> {
         Ok(changes)
     }
 
+    /// Apply an update to every issue matching `filter`, writing changes as
+    /// they're computed. Unlike [`Self::preview_bulk_update`], this mutates
+    /// storage: each matched issue that passes [`Self::validate_update`] and
+    /// has at least one real change is saved immediately, so a later
+    /// validation failure on a different issue does not undo earlier writes
+    /// (matching the per-issue atomicity the single-issue `update_issue` has
+    /// always had).
+    pub fn apply_bulk_update(
+        &self,
+        filter: &QueryFilter,
+        operations: &UpdateOperations,
+    ) -> Result<BulkUpdateResult> {
+        let all_issues = self.storage.list_issues()?;
+        let matched = filter.filter_issues(&all_issues)?;
+        self.apply_update_to(matched, operations, false)
+    }
+
+    /// Shared by [`Self::apply_bulk_update`] and [`Self::apply_batch_update`]:
+    /// validate and compute changes for each already-matched issue, saving
+    /// them unless `dry_run` is set. `dry_run` still reports issues with real
+    /// changes as `modified` (they're what *would* be modified) so preview
+    /// and apply runs share one result shape.
+    fn apply_update_to(
+        &self,
+        matched: Vec<&Issue>,
+        operations: &UpdateOperations,
+        dry_run: bool,
+    ) -> Result<BulkUpdateResult> {
+        let mut result = BulkUpdateResult::new();
+        result.matched = matched.iter().map(|i| i.id.clone()).collect();
+
+        for issue in matched {
+            match self.apply_update_step(issue, operations, dry_run)? {
+                StepOutcome::Modified => result.modified.push(issue.id.clone()),
+                StepOutcome::Skipped => result
+                    .skipped
+                    .push((issue.id.clone(), "no changes".to_string())),
+                StepOutcome::Error(e) => result.errors.push(e),
+            }
+        }
+
+        result.compute_summary();
+        Ok(result)
+    }
+
+    /// Validate, compute changes for, and (unless `dry_run`) save a single
+    /// issue -- the unit of work both [`Self::apply_update_to`] (processing
+    /// a whole matched batch) and the job queue's [`Self::run_job`]
+    /// (processing one issue at a time, checkpointing between each) build on.
+    pub(crate) fn apply_update_step(
+        &self,
+        issue: &Issue,
+        operations: &UpdateOperations,
+        dry_run: bool,
+    ) -> Result<StepOutcome> {
+        if let Err(e) = self.validate_update(issue, operations) {
+            return Ok(StepOutcome::Error(BulkUpdateError {
+                id: issue.id.clone(),
+                code: classify_error(&e).to_string(),
+                message: e.to_string(),
+            }));
+        }
+
+        let changes = self.compute_changes(issue, operations)?;
+        if changes.is_empty() {
+            return Ok(StepOutcome::Skipped);
+        }
+
+        if !dry_run {
+            let mut updated = issue.clone();
+            self.apply_operations(&mut updated, operations);
+            self.storage.save_issue(&updated)?;
+        }
+        Ok(StepOutcome::Modified)
+    }
+
+    /// Resolve one batch entry's targets and apply its mutations.
+    fn resolve_and_apply_entry(
+        &self,
+        entry: &BatchUpdateEntry,
+        dry_run: bool,
+    ) -> Result<BulkUpdateResult> {
+        let all_issues = self.storage.list_issues()?;
+        let matched = self.resolve_batch_targets(entry, &all_issues)?;
+        let ops = entry.to_update_operations()?;
+        self.apply_update_to(matched, &ops, dry_run)
+    }
+
+    /// Resolve a batch entry's targets: explicit `ids` if given, otherwise
+    /// its `filter` query.
+    fn resolve_batch_targets<'a>(
+        &self,
+        entry: &BatchUpdateEntry,
+        all_issues: &'a [Issue],
+    ) -> Result<Vec<&'a Issue>> {
+        if !entry.ids.is_empty() {
+            Ok(all_issues
+                .iter()
+                .filter(|i| entry.ids.contains(&i.id))
+                .collect())
+        } else if let Some(filter_str) = &entry.filter {
+            let filter = QueryFilter::parse(filter_str)?;
+            filter.filter_issues(all_issues)
+        } else {
+            Err(anyhow!(
+                "batch entry must specify either `filter` or `ids`"
+            ))
+        }
+    }
+
+    /// Apply a `jit issue update --batch` file: each entry is resolved and
+    /// applied independently, in file order, each entry seeing the previous
+    /// entries' writes (so a later `filter` can match on an earlier
+    /// mutation).
+    ///
+    /// `dry_run` resolves every entry and runs gate validation exactly as
+    /// [`Self::apply_bulk_update`] would, but writes nothing -- the returned
+    /// report describes what *would* happen.
+    ///
+    /// `atomic`, combined with `dry_run = false`, makes the whole batch
+    /// all-or-nothing: if any entry reports an error, every issue already
+    /// written by earlier entries is restored to its pre-batch snapshot
+    /// before returning, the same snapshot/restore strategy
+    /// [`super::batch`] uses for NDJSON batches.
+    pub fn apply_batch_update(
+        &self,
+        entries: &[BatchUpdateEntry],
+        dry_run: bool,
+        atomic: bool,
+    ) -> Result<BatchUpdateReport> {
+        // Only `--atomic` (non-dry-run) ever needs to restore the
+        // pre-batch state, so only pay for the snapshot then.
+        let snapshot = if atomic && !dry_run {
+            Some(self.storage.list_issues()?)
+        } else {
+            None
+        };
+
+        let mut operations = Vec::new();
+        let mut any_errors = false;
+
+        for entry in entries {
+            // A malformed entry (bad filter syntax, unknown state name, no
+            // `filter`/`ids`) is reported the same way a per-issue
+            // validation error is -- as an entry-level error -- rather than
+            // bubbling out via `?` and skipping the rollback below.
+            let result = match self.resolve_and_apply_entry(entry, dry_run) {
+                Ok(result) => result,
+                Err(e) => {
+                    let mut result = BulkUpdateResult::new();
+                    result.errors.push(BulkUpdateError {
+                        id: "<batch-entry>".to_string(),
+                        code: classify_error(&e).to_string(),
+                        message: e.to_string(),
+                    });
+                    result.compute_summary();
+                    result
+                }
+            };
+
+            if result.summary.total_errors > 0 {
+                any_errors = true;
+            }
+            operations.push(result);
+        }
+
+        let rolled_back = atomic && !dry_run && any_errors;
+        if rolled_back {
+            for issue in snapshot.as_deref().unwrap_or_default() {
+                self.storage.save_issue(issue)?;
+            }
+        }
+
+        Ok(BatchUpdateReport::from_operations(operations, rolled_back))
+    }
+
+    /// Mutate `issue` in place according to `operations`.
+    fn apply_operations(&self, issue: &mut Issue, operations: &UpdateOperations) {
+        if let Some(new_state) = operations.state {
+            issue.state = new_state;
+        }
+
+        for label in &operations.add_labels {
+            if !issue.labels.contains(label) {
+                issue.labels.push(label.clone());
+            }
+        }
+        issue
+            .labels
+            .retain(|label| !operations.remove_labels.contains(label));
+
+        if let Some(ref assignee) = operations.assignee {
+            issue.assignee = Some(assignee.clone());
+        } else if operations.unassign {
+            issue.assignee = None;
+        }
+
+        if let Some(new_priority) = operations.priority {
+            issue.priority = new_priority;
+        }
+    }
+
     /// Validate that update can be applied to issue
     fn validate_update(&self, issue: &Issue, operations: &UpdateOperations) -> Result<()> {
         // Validate state transition
@@ -239,19 +566,16 @@ impl<S: IssueStore> CommandExecutor<S> {
                 let context = crate::query::QueryContext::from_issues(&all_issues);
 
                 if issue.is_blocked(&context.all_issues) {
-                    return Err(anyhow::anyhow!(
-                        "Cannot transition to {:?}: blocked by dependencies",
-                        new_state
-                    ));
+                    return Err(BulkValidationError::BlockedByDependency(new_state).into());
                 }
             }
 
             // Check gates for Done state
-            if new_state == State::Done && issue.has_unpassed_gates() {
-                return Err(anyhow::anyhow!(
-                    "Cannot transition to Done: {} gates pending",
-                    issue.get_unpassed_gates().len()
-                ));
+            if new_state == State::Done && self.gates_unsatisfied(issue)? {
+                return Err(BulkValidationError::GateNotSatisfied(
+                    issue.get_unpassed_gates().len(),
+                )
+                .into());
             }
         }
 
@@ -389,4 +713,60 @@ mod tests {
         let changes = executor.compute_changes(&issue, &ops).unwrap();
         assert!(changes.is_empty());
     }
+
+    #[test]
+    fn test_apply_bulk_update_adds_labels_to_matched_issues() {
+        let storage = crate::storage::InMemoryStorage::new();
+        storage.init().unwrap();
+        let executor = crate::commands::CommandExecutor::new(storage);
+
+        let task1 = create_test_issue("1", State::Ready, vec!["type:task"]);
+        let task2 = create_test_issue("2", State::Ready, vec!["type:task"]);
+        let epic = create_test_issue("3", State::Ready, vec!["type:epic"]);
+        executor.storage().save_issue(&task1).unwrap();
+        executor.storage().save_issue(&task2).unwrap();
+        executor.storage().save_issue(&epic).unwrap();
+
+        let filter = QueryFilter::parse("label:type:task").unwrap();
+        let ops = UpdateOperations {
+            add_labels: vec!["milestone:v1.0".to_string()],
+            ..Default::default()
+        };
+
+        let result = executor.apply_bulk_update(&filter, &ops).unwrap();
+
+        assert_eq!(result.summary.total_matched, 2);
+        assert_eq!(result.summary.total_modified, 2);
+
+        let reloaded = executor.storage().list_issues().unwrap();
+        let modified: Vec<_> = reloaded
+            .iter()
+            .filter(|i| i.labels.contains(&"milestone:v1.0".to_string()))
+            .collect();
+        assert_eq!(modified.len(), 2);
+    }
+
+    #[test]
+    fn test_apply_bulk_update_reports_validation_errors() {
+        let storage = crate::storage::InMemoryStorage::new();
+        storage.init().unwrap();
+        let executor = crate::commands::CommandExecutor::new(storage);
+
+        let mut issue = create_test_issue("1", State::Ready, vec!["type:task"]);
+        issue.gates_required = vec!["tests".to_string()];
+        executor.storage().save_issue(&issue).unwrap();
+
+        let filter = QueryFilter::parse("state:ready").unwrap();
+        let ops = UpdateOperations {
+            state: Some(State::Done),
+            ..Default::default()
+        };
+
+        let result = executor.apply_bulk_update(&filter, &ops).unwrap();
+
+        assert_eq!(result.summary.total_errors, 1);
+        assert_eq!(result.summary.total_modified, 0);
+        assert_eq!(result.errors[0].id, issue.id);
+        assert_eq!(result.errors[0].code, ErrorCode::GATE_NOT_SATISFIED);
+    }
 }
@@ -0,0 +1,311 @@
+//! Exports and imports portable issue bundles (see [`crate::bundle`]).
+//!
+//! Import replays packed events through [`super::merge::merge_event_logs`]
+//! and [`super::merge::replay_all`] exactly as `jit merge`/`jit pull` do,
+//! so a bundle grafts onto a repo that may already know some of the
+//! events without duplicating them or re-firing notifications for events
+//! that already happened elsewhere.
+//!
+//! A bundle's signature is checked against the importing repo's
+//! `[signing] trusted_keys` (the same trust store `gate_verify` uses for
+//! gate approvals) before anything is merged: unlike a gate approval, a
+//! bundle doesn't record which signer produced it, so any one matching
+//! trusted key is accepted. Import refuses an unsigned or unverifiable
+//! bundle unless the caller passes `insecure: true`.
+
+use super::merge::{merge_event_logs, replay_all};
+use super::*;
+use crate::bundle::{Bundle, BundledDocument, BundleManifest};
+use anyhow::Context;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+/// Summary of a `jit bundle import` run.
+#[derive(Debug, Clone, Serialize)]
+pub struct BundleImportReport {
+    pub issues_in_bundle: usize,
+    pub events_merged: usize,
+    pub events_new: usize,
+    pub issues_updated: usize,
+    pub documents_written: usize,
+}
+
+impl<S: IssueStore> CommandExecutor<S> {
+    /// Pack the given issues, their full event history, and the contents
+    /// of any documents they link to into a signed, content-addressed
+    /// bundle, written to `output`.
+    pub fn export_bundle(&self, issue_ids: &[String], output: &Path) -> Result<BundleManifest> {
+        let mut issues = Vec::with_capacity(issue_ids.len());
+        let mut full_ids = HashSet::with_capacity(issue_ids.len());
+        for issue_id in issue_ids {
+            let full_id = self.storage.resolve_issue_id(issue_id)?;
+            let issue = self.storage.load_issue(&full_id)?;
+            full_ids.insert(full_id);
+            issues.push(issue);
+        }
+
+        let events: Vec<Event> = self
+            .storage
+            .read_events()?
+            .into_iter()
+            .filter(|event| full_ids.contains(event.get_issue_id()))
+            .collect();
+
+        let documents = self.collect_bundled_documents(&issues);
+
+        let bundle = Bundle::new(issues, events, documents)?;
+        std::fs::write(output, bundle.to_json_string()?)
+            .with_context(|| format!("Failed to write bundle to {}", output.display()))?;
+
+        Ok(bundle.manifest)
+    }
+
+    /// Read the contents of every document referenced by `issues`, relative
+    /// to the repository root, deduplicated by path. Documents that can't
+    /// be read (missing, not checked in yet, etc.) are skipped with a
+    /// warning rather than failing the export -- the bundle still carries
+    /// whatever it could read.
+    fn collect_bundled_documents(&self, issues: &[Issue]) -> Vec<BundledDocument> {
+        let Some(repo_root) = self.storage.root().parent() else {
+            return Vec::new();
+        };
+
+        let mut seen = HashSet::new();
+        let mut documents = Vec::new();
+        for issue in issues {
+            for doc_ref in &issue.documents {
+                if !seen.insert(doc_ref.path.clone()) {
+                    continue;
+                }
+                match std::fs::read_to_string(repo_root.join(&doc_ref.path)) {
+                    Ok(content) => documents.push(BundledDocument {
+                        path: doc_ref.path.clone(),
+                        content,
+                    }),
+                    Err(e) => {
+                        eprintln!("Warning: skipping document '{}' ({})", doc_ref.path, e);
+                    }
+                }
+            }
+        }
+        documents
+    }
+
+    /// Read a bundle, verify its content hash and signature, and replay
+    /// its events through the CRDT merge path so its issues graft onto
+    /// local state. Any bundled document whose path doesn't already exist
+    /// locally is written to the repository root, so `jit validate` can
+    /// resolve the links the imported issues carry.
+    ///
+    /// Imported events go through `self.storage.append_event` directly
+    /// (not the notifying [`super::notify`] wrapper), matching
+    /// `merge_event_files`/`pull_events`: these events already happened
+    /// elsewhere, so replaying them locally shouldn't re-fire
+    /// notifications as if they were new.
+    ///
+    /// Unless `insecure` is true, the bundle must carry a signature that
+    /// verifies against one of `self.config_manager.get_trusted_gate_keys()`
+    /// -- the same trust store used to verify gate approvals -- or import
+    /// is refused.
+    pub fn import_bundle(&self, path: &Path, insecure: bool) -> Result<BundleImportReport> {
+        let data = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read bundle at {}", path.display()))?;
+        let bundle = Bundle::from_json_str(&data)?;
+        bundle.verify_content_hash()?;
+
+        if !insecure {
+            let trusted_keys = self.config_manager.get_trusted_gate_keys()?;
+            let signed_by_trusted_key = trusted_keys
+                .values()
+                .any(|public_key| bundle.verify_signature(public_key));
+            if !signed_by_trusted_key {
+                return Err(anyhow!(
+                    "bundle is not signed by a trusted key; re-export it from a \
+                     repository with JIT_GATE_SIGNING_KEY set, add its signer to this \
+                     repo's [signing] trusted_keys, or pass --insecure to import it anyway"
+                ));
+            }
+        }
+
+        let documents_written = self.write_bundled_documents(&bundle.documents)?;
+
+        let local = self.storage.read_events()?;
+        let local_ids: HashSet<String> = local.iter().map(|e| e.get_id().to_string()).collect();
+        let events_new = bundle
+            .events
+            .iter()
+            .filter(|e| !local_ids.contains(e.get_id()))
+            .count();
+
+        let merged = merge_event_logs(&local, &bundle.events);
+        for event in &merged {
+            if !local_ids.contains(event.get_id()) {
+                self.storage.append_event(event)?;
+            }
+        }
+
+        let existing: HashMap<String, Issue> = self
+            .storage
+            .list_issues()?
+            .into_iter()
+            .map(|issue| (issue.id.clone(), issue))
+            .collect();
+        let recomputed = replay_all(&merged, &existing);
+        let mut issues_updated = 0;
+        for issue in recomputed.values() {
+            self.storage.save_issue(issue)?;
+            issues_updated += 1;
+        }
+
+        Ok(BundleImportReport {
+            issues_in_bundle: bundle.issues.len(),
+            events_merged: merged.len(),
+            events_new,
+            issues_updated,
+            documents_written,
+        })
+    }
+
+    /// Write bundled document contents to the repository root, skipping
+    /// any path that already exists locally so import never clobbers a
+    /// document the local repo already has (possibly with local edits).
+    fn write_bundled_documents(&self, documents: &[BundledDocument]) -> Result<usize> {
+        let Some(repo_root) = self.storage.root().parent() else {
+            return Ok(0);
+        };
+
+        let mut written = 0;
+        for document in documents {
+            let doc_path = repo_root.join(&document.path);
+            if doc_path.exists() {
+                continue;
+            }
+            if let Some(parent) = doc_path.parent() {
+                std::fs::create_dir_all(parent).with_context(|| {
+                    format!("Failed to create directory {}", parent.display())
+                })?;
+            }
+            std::fs::write(&doc_path, &document.content)
+                .with_context(|| format!("Failed to write document {}", doc_path.display()))?;
+            written += 1;
+        }
+        Ok(written)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::InMemoryStorage;
+    use tempfile::tempdir;
+
+    fn executor() -> CommandExecutor<InMemoryStorage> {
+        let storage = InMemoryStorage::new();
+        storage.init().unwrap();
+        CommandExecutor::new(storage)
+    }
+
+    #[test]
+    fn test_export_then_import_round_trips_into_fresh_repo() {
+        let source = executor();
+        let issue = Issue::new("Hand off this work".to_string(), String::new());
+        source.storage().save_issue(&issue).unwrap();
+        source
+            .append_event(&Event::new_issue_created(&issue))
+            .unwrap();
+
+        let dir = tempdir().unwrap();
+        let bundle_path = dir.path().join("work.bundle");
+        let manifest = source
+            .export_bundle(&[issue.id.clone()], &bundle_path)
+            .unwrap();
+        assert_eq!(manifest.issue_ids, vec![issue.id.clone()]);
+
+        let dest = executor();
+        let report = dest.import_bundle(&bundle_path, true).unwrap();
+
+        assert_eq!(report.issues_in_bundle, 1);
+        assert_eq!(report.issues_updated, 1);
+        assert_eq!(dest.storage().load_issue(&issue.id).unwrap().title, issue.title);
+    }
+
+    #[test]
+    fn test_import_dedupes_events_already_known_locally() {
+        let source = executor();
+        let issue = Issue::new("Already known".to_string(), String::new());
+        source.storage().save_issue(&issue).unwrap();
+        let event = Event::new_issue_created(&issue);
+        source.append_event(&event).unwrap();
+
+        let dir = tempdir().unwrap();
+        let bundle_path = dir.path().join("work.bundle");
+        source
+            .export_bundle(&[issue.id.clone()], &bundle_path)
+            .unwrap();
+
+        let dest = executor();
+        dest.storage().save_issue(&issue).unwrap();
+        dest.append_event(&event).unwrap();
+
+        let report = dest.import_bundle(&bundle_path, true).unwrap();
+
+        assert_eq!(report.events_new, 0);
+        assert_eq!(dest.storage().read_events().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_import_rejects_unsigned_bundle_without_insecure_flag() {
+        std::env::remove_var("JIT_GATE_SIGNING_KEY");
+        let source = executor();
+        let issue = Issue::new("Unsigned handoff".to_string(), String::new());
+        source.storage().save_issue(&issue).unwrap();
+        source
+            .append_event(&Event::new_issue_created(&issue))
+            .unwrap();
+
+        let dir = tempdir().unwrap();
+        let bundle_path = dir.path().join("work.bundle");
+        source
+            .export_bundle(&[issue.id.clone()], &bundle_path)
+            .unwrap();
+
+        let dest = executor();
+        assert!(dest.import_bundle(&bundle_path, false).is_err());
+        assert!(dest.import_bundle(&bundle_path, true).is_ok());
+    }
+
+    #[test]
+    fn test_export_then_import_carries_linked_document_contents() {
+        let (source_temp, source_storage) = crate::test_utils::setup_test_repo().unwrap();
+        let source = CommandExecutor::new(source_storage);
+
+        let mut issue = Issue::new("Hand off with a design doc".to_string(), String::new());
+        issue.documents.push(crate::domain::DocumentReference {
+            path: "docs/design.md".to_string(),
+            commit: None,
+            label: None,
+            doc_type: None,
+        });
+        source.storage().save_issue(&issue).unwrap();
+        source
+            .append_event(&Event::new_issue_created(&issue))
+            .unwrap();
+
+        let docs_dir = source_temp.path().join("docs");
+        std::fs::create_dir_all(&docs_dir).unwrap();
+        std::fs::write(docs_dir.join("design.md"), "# Design\n").unwrap();
+
+        let bundle_path = source_temp.path().join("work.bundle");
+        source
+            .export_bundle(&[issue.id.clone()], &bundle_path)
+            .unwrap();
+
+        let (dest_temp, dest_storage) = crate::test_utils::setup_test_repo().unwrap();
+        let dest = CommandExecutor::new(dest_storage);
+        let report = dest.import_bundle(&bundle_path, true).unwrap();
+
+        assert_eq!(report.documents_written, 1);
+        let imported = std::fs::read_to_string(dest_temp.path().join("docs/design.md")).unwrap();
+        assert_eq!(imported, "# Design\n");
+    }
+}
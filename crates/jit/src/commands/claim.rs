@@ -2,6 +2,7 @@
 //!
 //! Provides CLI interface to the lease-based claim coordination system.
 
+use crate::agent_heartbeat::HeartbeatDaemon;
 use crate::config::ConfigLoader;
 use crate::storage::worktree_identity::load_or_create_worktree_identity;
 use crate::storage::worktree_paths::WorktreePaths;
@@ -31,15 +32,23 @@ fn get_current_branch() -> Result<String> {
 
 /// Execute `jit claim acquire` command.
 ///
-/// Acquires an exclusive lease on an issue for the specified agent.
+/// Acquires an exclusive lease on an issue for the specified agent. When
+/// `watch` is true, or the resolved agent config has `[behavior]
+/// auto_heartbeat = true`, also spawns a [`HeartbeatDaemon`] on the same
+/// agent/coordinator context so the lease keeps renewing itself for as
+/// long as the caller holds onto the returned daemon -- matching the
+/// promise `BehaviorSection::auto_heartbeat` makes in `agent_config`.
+/// Callers that don't want this (e.g. a one-shot script) get `None` back
+/// and must renew the lease by hand via `jit claim heartbeat`.
 pub fn execute_claim_acquire<S: IssueStore>(
     storage: &S,
     issue_id: &str,
     ttl_secs: u64,
     agent_id: Option<&str>,
     reason: Option<&str>,
-) -> Result<String> {
-    use crate::agent_config::resolve_agent_id;
+    watch: bool,
+) -> Result<(String, Option<HeartbeatDaemon>)> {
+    use crate::agent_config::{resolve_agent_id, resolve_layered_config};
 
     // Resolve short ID to full ID
     let full_id = storage.resolve_issue_id(issue_id)?;
@@ -61,7 +70,7 @@ pub fn execute_claim_acquire<S: IssueStore>(
         load_or_create_worktree_identity(&paths.local_jit, &paths.worktree_root, &branch)?;
 
     // Resolve agent ID using proper priority: CLI flag > JIT_AGENT_ID > ~/.config/jit/agent.toml > error
-    let agent = resolve_agent_id(agent_id.map(|s| s.to_string()))?;
+    let agent = resolve_agent_id(agent_id.map(|s| s.to_string()), None, Some(&paths.local_jit), false)?;
 
     // Load config for policy limits
     let config = ConfigLoader::new()
@@ -96,11 +105,39 @@ pub fn execute_claim_acquire<S: IssueStore>(
     // Also set the assignee on the issue for visibility
     let mut issue = storage.load_issue(&full_id)?;
     if issue.assignee.is_none() || issue.assignee.as_ref() != Some(&agent) {
-        issue.assignee = Some(agent);
+        issue.assignee = Some(agent.clone());
         storage.save_issue(&issue)?;
     }
 
-    Ok(lease.lease_id)
+    // Decide whether to keep this lease renewed automatically: an explicit
+    // --watch always does, otherwise fall back to the resolved agent
+    // config's [behavior] auto_heartbeat (defaulting to off, so a script
+    // calling this without any config set never blocks on a daemon it
+    // didn't ask for).
+    let behavior = resolve_layered_config(None, Some(&paths.local_jit))
+        .ok()
+        .flatten()
+        .map(|agent_config| agent_config.behavior);
+
+    let daemon = if watch || behavior.as_ref().is_some_and(|b| b.auto_heartbeat) {
+        let heartbeat_interval = behavior.map(|b| b.heartbeat_interval).unwrap_or(30);
+        let daemon_coordinator = ClaimCoordinator::new(
+            paths,
+            FileLocker::new(Duration::from_secs(5)),
+            identity.worktree_id,
+            agent.clone(),
+        );
+        Some(HeartbeatDaemon::spawn(
+            daemon_coordinator,
+            agent,
+            heartbeat_interval,
+            ttl_secs,
+        ))
+    } else {
+        None
+    };
+
+    Ok((lease.lease_id, daemon))
 }
 
 /// Execute `jit claim heartbeat` command.
@@ -121,7 +158,7 @@ pub fn execute_claim_heartbeat(lease_id: &str) -> Result<()> {
         load_or_create_worktree_identity(&paths.local_jit, &paths.worktree_root, &branch)?;
 
     // Resolve agent ID
-    let agent = resolve_agent_id(None)?;
+    let agent = resolve_agent_id(None, None, Some(&paths.local_jit), false)?;
 
     // Create coordinator
     let locker = FileLocker::new(Duration::from_secs(5));
@@ -151,7 +188,7 @@ pub fn execute_claim_release(lease_id: &str) -> Result<()> {
         load_or_create_worktree_identity(&paths.local_jit, &paths.worktree_root, &branch)?;
 
     // Resolve agent ID using proper priority: JIT_AGENT_ID > ~/.config/jit/agent.toml > error
-    let agent = resolve_agent_id(None)?;
+    let agent = resolve_agent_id(None, None, Some(&paths.local_jit), false)?;
 
     // Create file locker
     let locker = FileLocker::new(Duration::from_secs(5));
@@ -195,7 +232,7 @@ pub fn execute_claim_renew<S: IssueStore>(lease_id: &str, extension_secs: u64) -
         load_or_create_worktree_identity(&paths.local_jit, &paths.worktree_root, &branch)?;
 
     // Resolve agent ID
-    let agent = resolve_agent_id(None)?;
+    let agent = resolve_agent_id(None, None, Some(&paths.local_jit), false)?;
 
     // Create locker and coordinator
     let locker = FileLocker::new(Duration::from_secs(5));
@@ -238,7 +275,7 @@ pub fn execute_claim_status<S: IssueStore>(
         load_or_create_worktree_identity(&paths.local_jit, &paths.worktree_root, &branch)?;
 
     // Resolve current agent ID using proper priority: JIT_AGENT_ID > ~/.config/jit/agent.toml > error
-    let current_agent_id = resolve_agent_id(None)?;
+    let current_agent_id = resolve_agent_id(None, None, Some(&paths.local_jit), false)?;
 
     // Create claim coordinator
     let locker = FileLocker::new(Duration::from_secs(5));
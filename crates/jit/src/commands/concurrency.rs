@@ -0,0 +1,126 @@
+//! Parallel-ready work queue and completion join semantics.
+//!
+//! `jit ready --parallel N` bounds the ready set to at most N issues that can
+//! be worked concurrently without contention: ready issues are already
+//! unassigned and unblocked, so any subset of them can be claimed and
+//! dispatched to separate workers at once. `jit wait <id>` polls the store
+//! until an issue reaches a terminal state (as classified by the
+//! repository's configured workflow), so a script can fan work out across
+//! the bounded ready set and join on completion.
+
+use super::*;
+use std::thread;
+use std::time::Duration;
+
+/// Default interval between polls in [`CommandExecutor::wait`].
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+impl<S: IssueStore> CommandExecutor<S> {
+    /// Like [`Self::query_ready`], but bounded to at most `limit` issues,
+    /// ordered by priority (matching [`Self::claim_next`]'s ordering) so a
+    /// caller can fan out exactly that many concurrent workers.
+    pub fn query_ready_parallel(&self, limit: Option<usize>) -> Result<Vec<Issue>> {
+        let mut ready = self.query_ready()?;
+        ready.sort_by_key(|i| match i.priority {
+            Priority::Critical => 0,
+            Priority::High => 1,
+            Priority::Normal => 2,
+            Priority::Low => 3,
+        });
+
+        if let Some(limit) = limit {
+            ready.truncate(limit);
+        }
+
+        Ok(ready)
+    }
+
+    /// Block until `id` reaches a terminal state per the repository's
+    /// configured workflow, polling the store at `poll_interval`.
+    pub fn wait_for_terminal(&self, id: &str, poll_interval: Duration) -> Result<Issue> {
+        let full_id = self.storage.resolve_issue_id(id)?;
+        let workflow = self.config_manager.get_workflow_config()?;
+
+        loop {
+            let issue = self.storage.load_issue(&full_id)?;
+            if workflow.is_closed(issue.state) {
+                return Ok(issue);
+            }
+            thread::sleep(poll_interval);
+        }
+    }
+
+    /// [`Self::wait_for_terminal`] with the default poll interval.
+    pub fn wait(&self, id: &str) -> Result<Issue> {
+        self.wait_for_terminal(id, DEFAULT_POLL_INTERVAL)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::InMemoryStorage;
+    use std::time::Duration;
+
+    fn executor() -> CommandExecutor<InMemoryStorage> {
+        let storage = InMemoryStorage::new();
+        storage.init().unwrap();
+        CommandExecutor::new(storage)
+    }
+
+    fn ready_issue(executor: &CommandExecutor<InMemoryStorage>, priority: Priority) -> String {
+        let id = executor
+            .create_issue("Task".to_string(), "".to_string(), priority, vec![], vec![])
+            .unwrap();
+        let mut issue = executor.storage().load_issue(&id).unwrap();
+        issue.state = State::Ready;
+        executor.storage().save_issue(&issue).unwrap();
+        id
+    }
+
+    #[test]
+    fn test_query_ready_parallel_bounds_to_limit() {
+        let executor = executor();
+        ready_issue(&executor, Priority::Normal);
+        ready_issue(&executor, Priority::Normal);
+        ready_issue(&executor, Priority::Normal);
+
+        let bounded = executor.query_ready_parallel(Some(2)).unwrap();
+        assert_eq!(bounded.len(), 2);
+    }
+
+    #[test]
+    fn test_query_ready_parallel_orders_by_priority() {
+        let executor = executor();
+        ready_issue(&executor, Priority::Low);
+        let critical_id = ready_issue(&executor, Priority::Critical);
+
+        let bounded = executor.query_ready_parallel(Some(1)).unwrap();
+        assert_eq!(bounded.len(), 1);
+        assert_eq!(bounded[0].id, critical_id);
+    }
+
+    #[test]
+    fn test_query_ready_parallel_no_limit_returns_all() {
+        let executor = executor();
+        ready_issue(&executor, Priority::Normal);
+        ready_issue(&executor, Priority::Normal);
+
+        let bounded = executor.query_ready_parallel(None).unwrap();
+        assert_eq!(bounded.len(), 2);
+    }
+
+    #[test]
+    fn test_wait_returns_immediately_for_already_terminal_issue() {
+        let executor = executor();
+        let mut issue = Issue::new("Done already".to_string(), "".to_string());
+        issue.state = State::Done;
+        let id = issue.id.clone();
+        executor.storage().save_issue(&issue).unwrap();
+
+        let result = executor
+            .wait_for_terminal(&id, Duration::from_millis(1))
+            .unwrap();
+        assert_eq!(result.state, State::Done);
+    }
+}
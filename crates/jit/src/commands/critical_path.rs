@@ -0,0 +1,335 @@
+//! Critical-path analysis over the dependency graph.
+//!
+//! Treats issues as nodes and `depends-on` edges as a DAG, with each issue's
+//! `estimated_duration_secs` as its duration (unset = zero, i.e. a
+//! milestone). A forward pass in topological order computes earliest start
+//! `ES(n) = max(EF(preds))` and earliest finish `EF(n) = ES(n) + dur(n)`; a
+//! backward pass computes latest finish `LF(n) = min(LS(succs))` and latest
+//! start `LS(n) = LF(n) - dur(n)`, seeded from the project's finish time.
+//! Slack is `LS - ES`; the critical path is the chain of zero-slack nodes.
+
+use super::*;
+use std::collections::{HashMap, VecDeque};
+
+/// Per-issue result of a critical-path computation.
+#[derive(Debug, Clone, Serialize)]
+pub struct CriticalPathNode {
+    pub issue_id: String,
+    pub title: String,
+    pub duration_secs: i64,
+    pub earliest_start: i64,
+    pub earliest_finish: i64,
+    pub latest_start: i64,
+    pub latest_finish: i64,
+    pub slack: i64,
+    pub critical: bool,
+}
+
+/// Full critical-path report for the issue set.
+#[derive(Debug, Clone, Serialize)]
+pub struct CriticalPathReport {
+    pub nodes: Vec<CriticalPathNode>,
+    /// IDs of the zero-slack chain, in execution order.
+    pub critical_path: Vec<String>,
+    pub total_duration_secs: i64,
+}
+
+impl<S: IssueStore> CommandExecutor<S> {
+    /// Compute the critical path across every issue in the store.
+    ///
+    /// Returns an error if the dependency graph contains a cycle.
+    pub fn critical_path(&self) -> Result<CriticalPathReport> {
+        let issues = self.storage.list_issues()?;
+        let issue_refs: Vec<&Issue> = issues.iter().collect();
+        let graph = DependencyGraph::new(&issue_refs);
+        graph
+            .validate_dag()
+            .map_err(|e| anyhow!("Cannot compute critical path: {}", e))?;
+
+        let by_id: HashMap<String, &Issue> =
+            issues.iter().map(|i| (i.id.clone(), i)).collect();
+        let duration = |id: &str| -> i64 {
+            by_id
+                .get(id)
+                .and_then(|i| i.estimated_duration_secs)
+                .unwrap_or(0)
+        };
+
+        let order = topological_order(&issues)?;
+
+        // Forward pass: earliest start/finish.
+        let mut es: HashMap<String, i64> = HashMap::new();
+        let mut ef: HashMap<String, i64> = HashMap::new();
+        for id in &order {
+            let issue = by_id[id];
+            let start = issue
+                .dependencies
+                .iter()
+                .map(|dep| *ef.get(dep).unwrap_or(&0))
+                .max()
+                .unwrap_or(0);
+            es.insert(id.clone(), start);
+            ef.insert(id.clone(), start + duration(id));
+        }
+
+        let total_duration_secs = ef.values().copied().max().unwrap_or(0);
+
+        // Backward pass: latest finish/start, seeded from the project finish time.
+        let successors: HashMap<String, Vec<String>> = {
+            let mut map: HashMap<String, Vec<String>> =
+                order.iter().map(|id| (id.clone(), Vec::new())).collect();
+            for id in &order {
+                for dep in &by_id[id].dependencies {
+                    map.entry(dep.clone()).or_default().push(id.clone());
+                }
+            }
+            map
+        };
+
+        let mut lf: HashMap<String, i64> = HashMap::new();
+        let mut ls: HashMap<String, i64> = HashMap::new();
+        for id in order.iter().rev() {
+            let succs = &successors[id];
+            let finish = if succs.is_empty() {
+                total_duration_secs
+            } else {
+                succs
+                    .iter()
+                    .map(|s| *ls.get(s).unwrap_or(&total_duration_secs))
+                    .min()
+                    .unwrap_or(total_duration_secs)
+            };
+            lf.insert(id.clone(), finish);
+            ls.insert(id.clone(), finish - duration(id));
+        }
+
+        let mut nodes = Vec::with_capacity(order.len());
+        for id in &order {
+            let issue = by_id[id];
+            let slack = ls[id] - es[id];
+            nodes.push(CriticalPathNode {
+                issue_id: id.clone(),
+                title: issue.title.clone(),
+                duration_secs: duration(id),
+                earliest_start: es[id],
+                earliest_finish: ef[id],
+                latest_start: ls[id],
+                latest_finish: lf[id],
+                slack,
+                critical: slack == 0,
+            });
+        }
+
+        let critical_path = critical_chain(&order, &by_id, &nodes);
+
+        Ok(CriticalPathReport {
+            nodes,
+            critical_path,
+            total_duration_secs,
+        })
+    }
+
+    /// Slack and critical-path membership for a single issue, computed over
+    /// the whole repository's dependency graph. Used by `jit issue show`.
+    pub fn issue_slack(&self, id: &str) -> Result<Option<CriticalPathNode>> {
+        let full_id = self.storage.resolve_issue_id(id)?;
+        let report = self.critical_path()?;
+        Ok(report.nodes.into_iter().find(|n| n.issue_id == full_id))
+    }
+}
+
+/// Kahn's algorithm; returns an error if the graph has a cycle (should not
+/// happen once `validate_dag` has already passed, but guards against
+/// disagreement between the two cycle checks).
+fn topological_order(issues: &[Issue]) -> Result<Vec<String>> {
+    let by_id: HashMap<&str, &Issue> = issues.iter().map(|i| (i.id.as_str(), i)).collect();
+
+    let mut in_degree: HashMap<String, usize> =
+        issues.iter().map(|i| (i.id.clone(), 0)).collect();
+    for issue in issues {
+        for dep in &issue.dependencies {
+            if by_id.contains_key(dep.as_str()) {
+                *in_degree.get_mut(&issue.id).unwrap() += 1;
+            }
+        }
+    }
+
+    let mut queue: VecDeque<String> = in_degree
+        .iter()
+        .filter(|(_, degree)| **degree == 0)
+        .map(|(id, _)| id.clone())
+        .collect();
+
+    let dependents: HashMap<String, Vec<String>> = {
+        let mut map: HashMap<String, Vec<String>> =
+            issues.iter().map(|i| (i.id.clone(), Vec::new())).collect();
+        for issue in issues {
+            for dep in &issue.dependencies {
+                if let Some(entry) = map.get_mut(dep) {
+                    entry.push(issue.id.clone());
+                }
+            }
+        }
+        map
+    };
+
+    let mut order = Vec::with_capacity(issues.len());
+    while let Some(id) = queue.pop_front() {
+        order.push(id.clone());
+        for dependent in &dependents[&id] {
+            let degree = in_degree.get_mut(dependent).unwrap();
+            *degree -= 1;
+            if *degree == 0 {
+                queue.push_back(dependent.clone());
+            }
+        }
+    }
+
+    if order.len() != issues.len() {
+        return Err(anyhow!("Cycle detected in dependency graph"));
+    }
+
+    Ok(order)
+}
+
+/// Walk the zero-slack nodes in dependency order to produce the critical
+/// chain, starting from a root (no dependencies) zero-slack node.
+fn critical_chain(
+    order: &[String],
+    by_id: &HashMap<String, &Issue>,
+    nodes: &[CriticalPathNode],
+) -> Vec<String> {
+    let critical: HashMap<&str, &CriticalPathNode> = nodes
+        .iter()
+        .filter(|n| n.critical)
+        .map(|n| (n.issue_id.as_str(), n))
+        .collect();
+
+    let Some(start) = order
+        .iter()
+        .find(|id| critical.contains_key(id.as_str()) && by_id[*id].dependencies.is_empty())
+    else {
+        return vec![];
+    };
+
+    let mut chain = vec![start.clone()];
+    let mut current = start.clone();
+    loop {
+        let next = order.iter().find(|id| {
+            critical.contains_key(id.as_str()) && by_id[*id].dependencies.contains(&current)
+        });
+        match next {
+            Some(id) => {
+                chain.push(id.clone());
+                current = id.clone();
+            }
+            None => break,
+        }
+    }
+
+    chain
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::InMemoryStorage;
+
+    fn executor() -> CommandExecutor<InMemoryStorage> {
+        let storage = InMemoryStorage::new();
+        storage.init().unwrap();
+        CommandExecutor::new(storage)
+    }
+
+    fn issue_with_duration(
+        executor: &CommandExecutor<InMemoryStorage>,
+        title: &str,
+        duration_secs: i64,
+        deps: &[&str],
+    ) -> String {
+        let id = executor
+            .create_issue(
+                title.to_string(),
+                "".to_string(),
+                Priority::Normal,
+                vec![],
+                vec![],
+            )
+            .unwrap();
+        let mut issue = executor.storage().load_issue(&id).unwrap();
+        issue.estimated_duration_secs = Some(duration_secs);
+        for dep in deps {
+            issue.dependencies.push(dep.to_string());
+        }
+        executor.storage().save_issue(&issue).unwrap();
+        id
+    }
+
+    #[test]
+    fn test_linear_chain_is_fully_critical() {
+        let executor = executor();
+        let a = issue_with_duration(&executor, "A", 10, &[]);
+        let b = issue_with_duration(&executor, "B", 20, &[&a]);
+        let c = issue_with_duration(&executor, "C", 5, &[&b]);
+
+        let report = executor.critical_path().unwrap();
+        assert_eq!(report.total_duration_secs, 35);
+        assert_eq!(report.critical_path, vec![a, b, c]);
+        assert!(report.nodes.iter().all(|n| n.critical));
+    }
+
+    #[test]
+    fn test_parallel_branch_has_slack() {
+        let executor = executor();
+        let a = issue_with_duration(&executor, "A", 10, &[]);
+        let long = issue_with_duration(&executor, "Long", 20, &[&a]);
+        let short = issue_with_duration(&executor, "Short", 2, &[&a]);
+        let join = issue_with_duration(&executor, "Join", 5, &[&long, &short]);
+
+        let report = executor.critical_path().unwrap();
+        assert_eq!(report.total_duration_secs, 35); // A(10) + Long(20) + Join(5)
+
+        let short_node = report.nodes.iter().find(|n| n.issue_id == short).unwrap();
+        assert!(!short_node.critical);
+        assert_eq!(short_node.slack, 18); // Long(20) - Short(2)
+
+        let long_node = report.nodes.iter().find(|n| n.issue_id == long).unwrap();
+        assert!(long_node.critical);
+        assert_eq!(long_node.slack, 0);
+
+        let join_node = report.nodes.iter().find(|n| n.issue_id == join).unwrap();
+        assert!(join_node.critical);
+    }
+
+    #[test]
+    fn test_rejects_cyclic_graph() {
+        let executor = executor();
+        let a_id = issue_with_duration(&executor, "A", 1, &[]);
+        let mut a = executor.storage().load_issue(&a_id).unwrap();
+        let b_id = issue_with_duration(&executor, "B", 1, &[&a_id]);
+        a.dependencies.push(b_id.clone());
+        executor.storage().save_issue(&a).unwrap();
+
+        let result = executor.critical_path();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_unestimated_issue_treated_as_zero_duration() {
+        let executor = executor();
+        let id = executor
+            .create_issue(
+                "Milestone".to_string(),
+                "".to_string(),
+                Priority::Normal,
+                vec![],
+                vec![],
+            )
+            .unwrap();
+
+        let report = executor.critical_path().unwrap();
+        let node = report.nodes.iter().find(|n| n.issue_id == id).unwrap();
+        assert_eq!(node.duration_secs, 0);
+        assert_eq!(node.earliest_finish, 0);
+    }
+}
@@ -52,7 +52,7 @@ impl<S: IssueStore> CommandExecutor<S> {
             // Log state change
             let event =
                 Event::new_issue_state_changed(from_issue.id.clone(), old_state, State::Backlog);
-            self.storage.append_event(&event)?;
+            self.append_event(&event)?;
         } else {
             self.storage.save_issue(&from_issue)?;
         }
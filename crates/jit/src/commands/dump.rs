@@ -0,0 +1,321 @@
+//! Versioned, gzip-tar dump/restore of the entire issue store.
+//!
+//! `jit dump` packages every issue, the gate registry, and the repo's
+//! config files into a single portable archive; `jit restore` reverses
+//! that into a (possibly different) `.jit` directory. Unlike
+//! [`super::bundle`]'s signed, content-addressed per-issue bundles (meant
+//! for handing off a handful of in-progress issues), a dump is a full,
+//! unsigned backup/migration snapshot of the whole store.
+
+use super::*;
+use crate::storage::GateRegistry;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use std::fs;
+use std::io::{Read, Write};
+use std::path::Path;
+
+/// Archive format version. Bumped whenever the on-disk layout inside the
+/// archive (not the gate/issue schemas themselves) changes incompatibly.
+const DUMP_FORMAT_VERSION: u32 = 1;
+
+/// Config files copied verbatim into the archive's `config/` directory, if
+/// present in the storage root.
+const CONFIG_FILES: &[&str] = &["config.toml", "label-namespaces.json"];
+
+/// `metadata.json` at the root of every dump archive.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct DumpMetadata {
+    dump_version: u32,
+    db_version: String,
+    created_at: String,
+}
+
+/// Summary of a `jit dump` run.
+#[derive(Debug, Clone, Serialize)]
+pub struct DumpReport {
+    pub dump_version: u32,
+    pub issue_count: usize,
+    pub gate_count: usize,
+}
+
+/// Summary of a `jit restore` run.
+#[derive(Debug, Clone, Serialize)]
+pub struct RestoreReport {
+    pub dump_version: u32,
+    pub issues_restored: usize,
+    pub gates_restored: usize,
+}
+
+impl<S: IssueStore> CommandExecutor<S> {
+    /// Write a gzip-tar dump of the whole issue store to `writer` (a file
+    /// or stdout). Builds the archive contents in a temp directory first
+    /// so a failure partway through never produces a truncated stream.
+    pub fn dump(&self, writer: impl Write) -> Result<DumpReport> {
+        let issues = self.storage.list_issues()?;
+        let gate_registry = self.storage.load_gate_registry()?;
+
+        let temp_dir = tempfile::TempDir::new()?;
+        let base = temp_dir.path();
+
+        let metadata = DumpMetadata {
+            dump_version: DUMP_FORMAT_VERSION,
+            db_version: env!("CARGO_PKG_VERSION").to_string(),
+            created_at: Utc::now().to_rfc3339(),
+        };
+        fs::write(
+            base.join("metadata.json"),
+            serde_json::to_string_pretty(&metadata)?,
+        )
+        .context("Failed to write metadata.json")?;
+
+        self.write_issues(base, &issues)?;
+        self.write_gates(base, &gate_registry)?;
+        self.write_config(base)?;
+
+        let encoder = GzEncoder::new(writer, Compression::default());
+        let mut tar_builder = tar::Builder::new(encoder);
+        tar_builder
+            .append_dir_all(".", base)
+            .context("Failed to build dump archive")?;
+        tar_builder
+            .into_inner()
+            .context("Failed to finalize dump tar stream")?
+            .finish()
+            .context("Failed to finalize dump gzip stream")?;
+
+        Ok(DumpReport {
+            dump_version: metadata.dump_version,
+            issue_count: issues.len(),
+            gate_count: gate_registry.gates.len(),
+        })
+    }
+
+    /// Serialize every issue as one line-delimited JSON file under `issues/`.
+    fn write_issues(&self, base: &Path, issues: &[Issue]) -> Result<()> {
+        let issues_dir = base.join("issues");
+        fs::create_dir_all(&issues_dir)?;
+
+        let mut ndjson = String::new();
+        for issue in issues {
+            ndjson.push_str(&serde_json::to_string(issue)?);
+            ndjson.push('\n');
+        }
+        fs::write(issues_dir.join("issues.jsonl"), ndjson)
+            .context("Failed to write issues.jsonl")?;
+        Ok(())
+    }
+
+    /// Serialize the gate registry under `gates/`.
+    fn write_gates(&self, base: &Path, registry: &GateRegistry) -> Result<()> {
+        let gates_dir = base.join("gates");
+        fs::create_dir_all(&gates_dir)?;
+        fs::write(
+            gates_dir.join("gates.json"),
+            serde_json::to_string_pretty(registry)?,
+        )
+        .context("Failed to write gates.json")?;
+        Ok(())
+    }
+
+    /// Copy whichever top-level config files exist in the storage root
+    /// into `config/` (absent files are silently skipped, same as
+    /// [`super::snapshot::SnapshotExporter::copy_jit_state`]'s config.toml/gates.json handling).
+    fn write_config(&self, base: &Path) -> Result<()> {
+        let config_dir = base.join("config");
+        fs::create_dir_all(&config_dir)?;
+
+        for file_name in CONFIG_FILES {
+            let src = self.storage.root().join(file_name);
+            if src.exists() {
+                fs::copy(&src, config_dir.join(file_name))
+                    .with_context(|| format!("Failed to copy {} into dump archive", file_name))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Restore issues and gates from a gzip-tar archive produced by
+    /// [`Self::dump`]. Rejects archives whose `dump_version` is newer than
+    /// this binary's [`DUMP_FORMAT_VERSION`], since it may contain a layout
+    /// this version doesn't know how to read.
+    pub fn restore(&self, reader: impl Read) -> Result<RestoreReport> {
+        let decoder = GzDecoder::new(reader);
+        let mut archive = tar::Archive::new(decoder);
+
+        let temp_dir = tempfile::TempDir::new()?;
+        archive
+            .unpack(temp_dir.path())
+            .context("Failed to unpack dump archive")?;
+        let base = temp_dir.path();
+
+        let metadata = Self::read_metadata(base)?;
+        if metadata.dump_version > DUMP_FORMAT_VERSION {
+            return Err(anyhow!(
+                "Dump archive version {} is newer than this binary supports (max {})",
+                metadata.dump_version,
+                DUMP_FORMAT_VERSION
+            ));
+        }
+
+        let issues_restored = self.restore_issues(base)?;
+        let gates_restored = self.restore_gates(base)?;
+
+        Ok(RestoreReport {
+            dump_version: metadata.dump_version,
+            issues_restored,
+            gates_restored,
+        })
+    }
+
+    fn read_metadata(base: &Path) -> Result<DumpMetadata> {
+        let path = base.join("metadata.json");
+        let content = fs::read_to_string(&path)
+            .context("Dump archive is missing metadata.json")?;
+        serde_json::from_str(&content).context("Failed to parse metadata.json")
+    }
+
+    fn restore_issues(&self, base: &Path) -> Result<usize> {
+        let path = base.join("issues").join("issues.jsonl");
+        if !path.exists() {
+            return Ok(0);
+        }
+
+        let content = fs::read_to_string(&path).context("Failed to read issues.jsonl")?;
+        let mut restored = 0;
+        for line in content.lines() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let issue: Issue =
+                serde_json::from_str(line).context("Failed to parse issue in dump archive")?;
+            self.storage.save_issue(&issue)?;
+            restored += 1;
+        }
+        Ok(restored)
+    }
+
+    fn restore_gates(&self, base: &Path) -> Result<usize> {
+        let path = base.join("gates").join("gates.json");
+        if !path.exists() {
+            return Ok(0);
+        }
+
+        let content = fs::read_to_string(&path).context("Failed to read gates.json")?;
+        let registry: GateRegistry =
+            serde_json::from_str(&content).context("Failed to parse gates.json in dump archive")?;
+        let gate_count = registry.gates.len();
+        self.storage.save_gate_registry(&registry)?;
+        Ok(gate_count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::Gate;
+    use crate::storage::InMemoryStorage;
+
+    fn make_executor() -> CommandExecutor<InMemoryStorage> {
+        let storage = InMemoryStorage::new();
+        storage.init().unwrap();
+        CommandExecutor::new(storage)
+    }
+
+    #[test]
+    fn test_dump_and_restore_round_trips_issues() {
+        let executor = make_executor();
+        let issue = Issue::new("Dump me".to_string(), "Body".to_string());
+        let issue_id = issue.id.clone();
+        executor.storage().save_issue(&issue).unwrap();
+
+        let mut archive = Vec::new();
+        let report = executor.dump(&mut archive).unwrap();
+        assert_eq!(report.issue_count, 1);
+
+        let restored_executor = make_executor();
+        let restore_report = restored_executor.restore(archive.as_slice()).unwrap();
+        assert_eq!(restore_report.issues_restored, 1);
+
+        let loaded = restored_executor.storage().load_issue(&issue_id).unwrap();
+        assert_eq!(loaded.title, "Dump me");
+    }
+
+    #[test]
+    fn test_dump_and_restore_round_trips_gates() {
+        let executor = make_executor();
+        let mut registry = GateRegistry::default();
+        registry.gates.insert(
+            "tests".to_string(),
+            Gate {
+                key: "tests".to_string(),
+                title: "All tests pass".to_string(),
+                description: "cargo test".to_string(),
+                auto: false,
+                example_integration: None,
+                command: None,
+            },
+        );
+        executor.storage().save_gate_registry(&registry).unwrap();
+
+        let mut archive = Vec::new();
+        let report = executor.dump(&mut archive).unwrap();
+        assert_eq!(report.gate_count, 1);
+
+        let restored_executor = make_executor();
+        let restore_report = restored_executor.restore(archive.as_slice()).unwrap();
+        assert_eq!(restore_report.gates_restored, 1);
+
+        let restored_registry = restored_executor.storage().load_gate_registry().unwrap();
+        assert!(restored_registry.gates.contains_key("tests"));
+    }
+
+    #[test]
+    fn test_restore_rejects_future_dump_version() {
+        let executor = make_executor();
+
+        let mut archive = Vec::new();
+        executor.dump(&mut archive).unwrap();
+
+        // Tamper with metadata.json inside the archive to claim a future version.
+        let decoder = GzDecoder::new(archive.as_slice());
+        let mut unpacked = tar::Archive::new(decoder);
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        unpacked.unpack(temp_dir.path()).unwrap();
+
+        let metadata_path = temp_dir.path().join("metadata.json");
+        let mut metadata: DumpMetadata =
+            serde_json::from_str(&fs::read_to_string(&metadata_path).unwrap()).unwrap();
+        metadata.dump_version = DUMP_FORMAT_VERSION + 1;
+        fs::write(
+            &metadata_path,
+            serde_json::to_string_pretty(&metadata).unwrap(),
+        )
+        .unwrap();
+
+        let mut retarred = Vec::new();
+        {
+            let enc = GzEncoder::new(&mut retarred, Compression::default());
+            let mut builder = tar::Builder::new(enc);
+            builder.append_dir_all(".", temp_dir.path()).unwrap();
+            builder.into_inner().unwrap().finish().unwrap();
+        }
+
+        let result = executor.restore(retarred.as_slice());
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("newer"));
+    }
+
+    #[test]
+    fn test_restore_empty_archive_is_ok_with_zero_counts() {
+        let executor = make_executor();
+
+        let mut archive = Vec::new();
+        executor.dump(&mut archive).unwrap();
+
+        let report = executor.restore(archive.as_slice()).unwrap();
+        assert_eq!(report.issues_restored, 0);
+        assert_eq!(report.gates_restored, 0);
+    }
+}
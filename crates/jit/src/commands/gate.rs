@@ -37,20 +37,31 @@ impl<S: IssueStore> CommandExecutor<S> {
         }
 
         // Manual gate: mark as passed
+        let updated_at = Utc::now();
+        let hash = crate::crypto::hash_gate_event(
+            &issue.id,
+            &gate_key,
+            GateStatus::Passed,
+            updated_at,
+            &by,
+        );
+        let signature = crate::crypto::sign_with_env_key(&hash)?;
+
         issue.gates_status.insert(
             gate_key.clone(),
             GateState {
                 status: GateStatus::Passed,
                 updated_by: by.clone(),
-                updated_at: Utc::now(),
+                updated_at,
+                signature: signature.clone(),
             },
         );
 
         self.storage.save_issue(&issue)?;
 
         // Log event
-        let event = Event::new_gate_passed(issue.id.clone(), gate_key, by);
-        self.storage.append_event(&event)?;
+        let event = Event::new_gate_passed(issue.id.clone(), gate_key, by, signature);
+        self.append_event(&event)?;
 
         // Check if Gated issue can now transition to Done
         self.auto_transition_to_done(&full_id)?;
@@ -80,20 +91,31 @@ impl<S: IssueStore> CommandExecutor<S> {
             }
         }
 
+        let updated_at = Utc::now();
+        let hash = crate::crypto::hash_gate_event(
+            &issue.id,
+            &gate_key,
+            GateStatus::Failed,
+            updated_at,
+            &by,
+        );
+        let signature = crate::crypto::sign_with_env_key(&hash)?;
+
         issue.gates_status.insert(
             gate_key.clone(),
             GateState {
                 status: GateStatus::Failed,
                 updated_by: by.clone(),
-                updated_at: Utc::now(),
+                updated_at,
+                signature: signature.clone(),
             },
         );
 
         self.storage.save_issue(&issue)?;
 
         // Log event
-        let event = Event::new_gate_failed(issue.id.clone(), gate_key, by);
-        self.storage.append_event(&event)?;
+        let event = Event::new_gate_failed(issue.id.clone(), gate_key, by, signature);
+        self.append_event(&event)?;
 
         Ok(())
     }
@@ -64,11 +64,13 @@ impl<S: IssueStore> CommandExecutor<S> {
             _ => repo_root,
         };
 
+        let matchers = crate::gate_presets::matchers_for_gate(gate);
         let result = gate_execution::execute_gate_checker(
             gate_key,
             &full_id,
             gate.stage,
             checker,
+            &matchers,
             &working_dir,
         )?;
 
@@ -77,16 +79,27 @@ impl<S: IssueStore> CommandExecutor<S> {
 
         // Update issue gate status
         let mut issue = self.storage.load_issue(&full_id)?;
+        let status = match result.status {
+            GateRunStatus::Passed => GateStatus::Passed,
+            GateRunStatus::Failed | GateRunStatus::Error => GateStatus::Failed,
+            _ => GateStatus::Pending,
+        };
+        let hash = crate::crypto::hash_gate_event(
+            &full_id,
+            gate_key,
+            status,
+            result.started_at,
+            &result.by,
+        );
+        let signature = crate::crypto::sign_with_env_key(&hash)?;
+
         issue.gates_status.insert(
             gate_key.to_string(),
             GateState {
-                status: match result.status {
-                    GateRunStatus::Passed => GateStatus::Passed,
-                    GateRunStatus::Failed | GateRunStatus::Error => GateStatus::Failed,
-                    _ => GateStatus::Pending,
-                },
+                status,
                 updated_by: result.by.clone(),
                 updated_at: result.started_at,
+                signature: signature.clone(),
             },
         );
         self.storage.save_issue(&issue)?;
@@ -97,14 +110,16 @@ impl<S: IssueStore> CommandExecutor<S> {
                 full_id.clone(),
                 gate_key.to_string(),
                 result.by.clone(),
+                signature,
             ),
             _ => Event::new_gate_failed(
                 full_id.clone(),
                 gate_key.to_string(),
                 result.by.clone(),
+                signature,
             ),
         };
-        self.storage.append_event(&event)?;
+        self.append_event(&event)?;
 
         Ok(result)
     }
@@ -0,0 +1,355 @@
+//! Automatic gate runner: executes the external command configured on an
+//! `auto` gate for every in-progress issue that requires it.
+//!
+//! This is deliberately independent of [`crate::gate_execution`]'s
+//! `GateChecker`-based engine (used by `jit gate check`), which is wired to
+//! a richer, reserved gate schema (`stage`/`mode`/`checker`) that the
+//! current [`crate::domain::Gate`] struct no longer carries. `jit gate run`
+//! instead works directly off [`crate::domain::Gate::resolved_command`],
+//! matching the simpler schema the gate registry actually stores today.
+
+use super::*;
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+/// Default timeout for a single gate command.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Default number of gate commands run concurrently.
+const DEFAULT_MAX_CONCURRENT: usize = 4;
+
+/// Outcome of running a single auto gate's command for a single issue.
+#[derive(Debug, Clone, Serialize)]
+pub struct GateRunOutcome {
+    pub issue_id: String,
+    pub gate_key: String,
+    pub passed: bool,
+    pub exit_code: Option<i32>,
+}
+
+/// One (issue, gate) pair whose command needs to run.
+struct GateTask {
+    issue_id: String,
+    gate_key: String,
+    command: String,
+}
+
+impl<S: IssueStore> CommandExecutor<S> {
+    /// Run every required `auto` gate with a resolved command, for
+    /// in-progress issues. If `issue_id` is given, only that issue (in any
+    /// state) is considered; otherwise every [`State::InProgress`] issue is
+    /// scanned. Tasks are dispatched across at most
+    /// [`DEFAULT_MAX_CONCURRENT`] worker threads at a time.
+    pub fn run_auto_gates(&self, issue_id: Option<&str>) -> Result<Vec<GateRunOutcome>> {
+        let registry = self.storage.load_gate_registry()?;
+
+        let issues = match issue_id {
+            Some(id) => {
+                let full_id = self.storage.resolve_issue_id(id)?;
+                vec![self.storage.load_issue(&full_id)?]
+            }
+            None => self
+                .storage
+                .list_issues()?
+                .into_iter()
+                .filter(|issue| issue.state == State::InProgress)
+                .collect(),
+        };
+
+        let mut tasks = Vec::new();
+        for issue in &issues {
+            for gate_key in &issue.gates_required {
+                let Some(gate) = registry.gates.get(gate_key) else {
+                    continue;
+                };
+                if !gate.auto {
+                    continue;
+                }
+                if matches!(
+                    issue.gates_status.get(gate_key),
+                    Some(state) if state.status == GateStatus::Passed
+                ) {
+                    continue;
+                }
+                let Some(command) = gate.resolved_command() else {
+                    continue;
+                };
+                tasks.push(GateTask {
+                    issue_id: issue.id.clone(),
+                    gate_key: gate_key.clone(),
+                    command: command.to_string(),
+                });
+            }
+        }
+
+        let mut outcomes = Vec::new();
+        for batch in tasks.chunks(DEFAULT_MAX_CONCURRENT) {
+            let results: Vec<(String, String, std::io::Result<std::process::Output>)> =
+                std::thread::scope(|scope| {
+                    let handles: Vec<_> = batch
+                        .iter()
+                        .map(|task| {
+                            scope.spawn(move || {
+                                (
+                                    task.issue_id.clone(),
+                                    task.gate_key.clone(),
+                                    run_gate_command(&task.command, DEFAULT_TIMEOUT),
+                                )
+                            })
+                        })
+                        .collect();
+                    handles.into_iter().map(|h| h.join().unwrap()).collect()
+                });
+
+            for (issue_id, gate_key, result) in results {
+                let outcome = self.record_gate_run(&issue_id, &gate_key, result)?;
+                outcomes.push(outcome);
+            }
+        }
+
+        Ok(outcomes)
+    }
+
+    /// Save an executed gate command's output into the issue's context and
+    /// emit the resulting signed `GatePassed`/`GateFailed` event.
+    fn record_gate_run(
+        &self,
+        issue_id: &str,
+        gate_key: &str,
+        result: std::io::Result<std::process::Output>,
+    ) -> Result<GateRunOutcome> {
+        let (passed, exit_code, stdout, stderr) = match result {
+            Ok(output) => (
+                output.status.success(),
+                output.status.code(),
+                String::from_utf8_lossy(&output.stdout).to_string(),
+                String::from_utf8_lossy(&output.stderr).to_string(),
+            ),
+            Err(e) => (false, None, String::new(), e.to_string()),
+        };
+
+        let status = if passed {
+            GateStatus::Passed
+        } else {
+            GateStatus::Failed
+        };
+        let updated_by = Some(format!("auto:{}", gate_key));
+        let updated_at = Utc::now();
+
+        let mut issue = self.storage.load_issue(issue_id)?;
+        issue.context.insert(
+            format!("gate.{}.stdout", gate_key),
+            truncate_output(&stdout),
+        );
+        issue.context.insert(
+            format!("gate.{}.stderr", gate_key),
+            truncate_output(&stderr),
+        );
+
+        let hash = crate::crypto::hash_gate_event(issue_id, gate_key, status, updated_at, &updated_by);
+        let signature = crate::crypto::sign_with_env_key(&hash)?;
+
+        issue.gates_status.insert(
+            gate_key.to_string(),
+            GateState {
+                status,
+                updated_by: updated_by.clone(),
+                updated_at,
+                signature: signature.clone(),
+            },
+        );
+        self.storage.save_issue(&issue)?;
+
+        let event = if passed {
+            Event::new_gate_passed(issue_id.to_string(), gate_key.to_string(), updated_by, signature)
+        } else {
+            Event::new_gate_failed(issue_id.to_string(), gate_key.to_string(), updated_by, signature)
+        };
+        self.append_event(&event)?;
+
+        if passed {
+            self.auto_transition_to_done(issue_id)?;
+        }
+
+        Ok(GateRunOutcome {
+            issue_id: issue_id.to_string(),
+            gate_key: gate_key.to_string(),
+            passed,
+            exit_code,
+        })
+    }
+}
+
+/// Run `command` as a shell command with a timeout, killing it if it
+/// overruns. Mirrors the spawn/timeout/capture shape of
+/// `gate_execution::execute_command`, minus the git-context and
+/// `GateChecker` plumbing that command doesn't need here.
+fn run_gate_command(command: &str, timeout: Duration) -> std::io::Result<std::process::Output> {
+    #[cfg(unix)]
+    let mut cmd = {
+        let mut c = Command::new("sh");
+        c.arg("-c").arg(command);
+        c
+    };
+    #[cfg(windows)]
+    let mut cmd = {
+        let mut c = Command::new("cmd");
+        c.arg("/C").arg(command);
+        c
+    };
+
+    cmd.stdout(Stdio::piped()).stderr(Stdio::piped());
+    let mut child = cmd.spawn()?;
+
+    let start = Instant::now();
+    loop {
+        if let Some(_status) = child.try_wait()? {
+            return child.wait_with_output();
+        }
+        if start.elapsed() >= timeout {
+            let _ = child.kill();
+            return child.wait_with_output();
+        }
+        std::thread::sleep(Duration::from_millis(100));
+    }
+}
+
+/// Cap stored command output so a noisy gate can't blow up issue context.
+const MAX_OUTPUT_LEN: usize = 4096;
+
+fn truncate_output(output: &str) -> String {
+    if output.len() <= MAX_OUTPUT_LEN {
+        output.to_string()
+    } else {
+        let mut truncated = output[..MAX_OUTPUT_LEN].to_string();
+        truncated.push_str("...[truncated]");
+        truncated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::Gate;
+    use crate::storage::InMemoryStorage;
+
+    fn executor() -> CommandExecutor<InMemoryStorage> {
+        let storage = InMemoryStorage::new();
+        storage.init().unwrap();
+        CommandExecutor::new(storage)
+    }
+
+    fn define_auto_gate(executor: &CommandExecutor<InMemoryStorage>, key: &str, command: &str) {
+        let mut registry = executor.storage().load_gate_registry().unwrap();
+        registry.gates.insert(
+            key.to_string(),
+            Gate {
+                key: key.to_string(),
+                title: key.to_string(),
+                description: String::new(),
+                auto: true,
+                example_integration: None,
+                command: Some(command.to_string()),
+            },
+        );
+        executor.storage().save_gate_registry(&registry).unwrap();
+    }
+
+    fn in_progress_issue(executor: &CommandExecutor<InMemoryStorage>, gate_key: &str) -> String {
+        let id = executor
+            .create_issue(
+                "Task".to_string(),
+                "".to_string(),
+                Priority::Normal,
+                vec![],
+                vec![gate_key.to_string()],
+            )
+            .unwrap();
+        let mut issue = executor.storage().load_issue(&id).unwrap();
+        issue.state = State::InProgress;
+        executor.storage().save_issue(&issue).unwrap();
+        id
+    }
+
+    #[test]
+    fn test_passing_command_marks_gate_passed() {
+        let executor = executor();
+        define_auto_gate(&executor, "tests", "true");
+        let id = in_progress_issue(&executor, "tests");
+
+        let outcomes = executor.run_auto_gates(Some(&id)).unwrap();
+        assert_eq!(outcomes.len(), 1);
+        assert!(outcomes[0].passed);
+
+        let issue = executor.storage().load_issue(&id).unwrap();
+        assert_eq!(
+            issue.gates_status.get("tests").unwrap().status,
+            GateStatus::Passed
+        );
+    }
+
+    #[test]
+    fn test_failing_command_marks_gate_failed_and_records_output() {
+        let executor = executor();
+        define_auto_gate(&executor, "tests", "echo boom 1>&2; false");
+        let id = in_progress_issue(&executor, "tests");
+
+        let outcomes = executor.run_auto_gates(Some(&id)).unwrap();
+        assert!(!outcomes[0].passed);
+
+        let issue = executor.storage().load_issue(&id).unwrap();
+        assert_eq!(
+            issue.gates_status.get("tests").unwrap().status,
+            GateStatus::Failed
+        );
+        assert!(issue.context.get("gate.tests.stderr").unwrap().contains("boom"));
+    }
+
+    #[test]
+    fn test_already_passed_gate_is_not_rerun() {
+        let executor = executor();
+        define_auto_gate(&executor, "tests", "false");
+        let id = in_progress_issue(&executor, "tests");
+        executor.pass_gate_for_test(&id, "tests");
+
+        let outcomes = executor.run_auto_gates(Some(&id)).unwrap();
+        assert!(outcomes.is_empty());
+    }
+
+    #[test]
+    fn test_scan_without_issue_id_only_considers_in_progress() {
+        let executor = executor();
+        define_auto_gate(&executor, "tests", "true");
+        let in_progress_id = in_progress_issue(&executor, "tests");
+        let backlog_id = executor
+            .create_issue(
+                "Other".to_string(),
+                "".to_string(),
+                Priority::Normal,
+                vec![],
+                vec!["tests".to_string()],
+            )
+            .unwrap();
+
+        let outcomes = executor.run_auto_gates(None).unwrap();
+        let touched: Vec<_> = outcomes.iter().map(|o| o.issue_id.clone()).collect();
+        assert!(touched.contains(&in_progress_id));
+        assert!(!touched.contains(&backlog_id));
+    }
+
+    impl CommandExecutor<InMemoryStorage> {
+        fn pass_gate_for_test(&self, issue_id: &str, gate_key: &str) {
+            let mut issue = self.storage().load_issue(issue_id).unwrap();
+            issue.gates_status.insert(
+                gate_key.to_string(),
+                GateState {
+                    status: GateStatus::Passed,
+                    updated_by: Some("test".to_string()),
+                    updated_at: Utc::now(),
+                    signature: None,
+                },
+            );
+            self.storage().save_issue(&issue).unwrap();
+        }
+    }
+}
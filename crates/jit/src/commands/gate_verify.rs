@@ -0,0 +1,189 @@
+//! Verification of signed gate approvals against trusted public keys.
+
+use super::*;
+use crate::crypto;
+
+/// Verification outcome for a single required gate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GateSignatureStatus {
+    /// Gate passed with a signature that verifies against a trusted key.
+    Valid,
+    /// Gate has not passed, or passed with no signature attached.
+    Missing,
+    /// Gate passed with a signature, but it does not verify (unknown
+    /// identity, wrong key, or tampered content) — a forged approval.
+    Forged,
+}
+
+/// Verification result for one gate on one issue.
+#[derive(Debug, Clone, Serialize)]
+pub struct GateVerification {
+    pub gate_key: String,
+    pub status: GateSignatureStatus,
+    pub updated_by: Option<String>,
+}
+
+/// Report of signature verification across all of an issue's required gates.
+#[derive(Debug, Clone, Serialize)]
+pub struct GateVerificationReport {
+    pub issue_id: String,
+    pub gates: Vec<GateVerification>,
+}
+
+impl<S: IssueStore> CommandExecutor<S> {
+    /// Recompute the approval hash for each of `issue_id`'s required gates
+    /// and check it against the repository's trusted public keys.
+    pub fn verify_gate_signatures(&self, issue_id: &str) -> Result<GateVerificationReport> {
+        let full_id = self.storage.resolve_issue_id(issue_id)?;
+        let issue = self.storage.load_issue(&full_id)?;
+        let trusted_keys = self.config_manager.get_trusted_gate_keys()?;
+
+        let gates = issue
+            .gates_required
+            .iter()
+            .map(|gate_key| {
+                let gate_state = issue.gates_status.get(gate_key);
+                let status = match gate_state {
+                    Some(state) if state.status == GateStatus::Passed => {
+                        match (&state.signature, &state.updated_by) {
+                            (Some(signature), Some(updated_by)) => {
+                                match trusted_keys.get(updated_by) {
+                                    Some(public_key) => {
+                                        let hash = crypto::hash_gate_event(
+                                            &full_id,
+                                            gate_key,
+                                            state.status,
+                                            state.updated_at,
+                                            &state.updated_by,
+                                        );
+                                        if crypto::verify_signature(&hash, signature, public_key) {
+                                            GateSignatureStatus::Valid
+                                        } else {
+                                            GateSignatureStatus::Forged
+                                        }
+                                    }
+                                    None => GateSignatureStatus::Forged,
+                                }
+                            }
+                            _ => GateSignatureStatus::Missing,
+                        }
+                    }
+                    _ => GateSignatureStatus::Missing,
+                };
+                GateVerification {
+                    gate_key: gate_key.clone(),
+                    status,
+                    updated_by: gate_state.and_then(|s| s.updated_by.clone()),
+                }
+            })
+            .collect();
+
+        Ok(GateVerificationReport {
+            issue_id: full_id,
+            gates,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::{InMemoryStorage, JsonFileStorage};
+    use tempfile::TempDir;
+
+    fn executor() -> CommandExecutor<InMemoryStorage> {
+        let storage = InMemoryStorage::new();
+        storage.init().unwrap();
+        CommandExecutor::new(storage)
+    }
+
+    #[test]
+    fn test_unsigned_passed_gate_is_missing() {
+        let executor = executor();
+        let mut issue = Issue::new("Test".to_string(), "".to_string());
+        issue.gates_required.push("review".to_string());
+        issue.gates_status.insert(
+            "review".to_string(),
+            GateState {
+                status: GateStatus::Passed,
+                updated_by: Some("human:alice".to_string()),
+                updated_at: Utc::now(),
+                signature: None,
+            },
+        );
+        executor.storage().save_issue(&issue).unwrap();
+
+        let report = executor.verify_gate_signatures(&issue.id).unwrap();
+        assert_eq!(report.gates[0].status, GateSignatureStatus::Missing);
+    }
+
+    #[test]
+    fn test_signed_gate_with_untrusted_identity_is_forged() {
+        let executor = executor();
+        let mut issue = Issue::new("Test".to_string(), "".to_string());
+        issue.gates_required.push("review".to_string());
+        issue.gates_status.insert(
+            "review".to_string(),
+            GateState {
+                status: GateStatus::Passed,
+                updated_by: Some("human:alice".to_string()),
+                updated_at: Utc::now(),
+                signature: Some("deadbeef".to_string()),
+            },
+        );
+        executor.storage().save_issue(&issue).unwrap();
+
+        let report = executor.verify_gate_signatures(&issue.id).unwrap();
+        assert_eq!(report.gates[0].status, GateSignatureStatus::Forged);
+    }
+
+    #[test]
+    fn test_valid_signature_verifies() {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = JsonFileStorage::new(temp_dir.path());
+        storage.init().unwrap();
+        let executor = CommandExecutor::new(storage);
+
+        let mut issue = Issue::new("Test".to_string(), "".to_string());
+        issue.gates_required.push("review".to_string());
+
+        let updated_at = Utc::now();
+        let updated_by = Some("human:alice".to_string());
+        let hash =
+            crypto::hash_gate_event(&issue.id, "review", GateStatus::Passed, updated_at, &updated_by);
+
+        std::env::set_var("JIT_GATE_SIGNING_KEY", "0".repeat(64));
+        let signature = crypto::sign_with_env_key(&hash).unwrap().unwrap();
+        std::env::remove_var("JIT_GATE_SIGNING_KEY");
+
+        let seed = [0u8; 32];
+        let signing_key = ed25519_dalek::SigningKey::from_bytes(&seed);
+        let public_key_hex: String = signing_key
+            .verifying_key()
+            .as_bytes()
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect();
+
+        issue.gates_status.insert(
+            "review".to_string(),
+            GateState {
+                status: GateStatus::Passed,
+                updated_by: updated_by.clone(),
+                updated_at,
+                signature: Some(signature),
+            },
+        );
+        executor.storage().save_issue(&issue).unwrap();
+
+        std::fs::write(
+            temp_dir.path().join("config.toml"),
+            format!("[signing.trusted_keys]\n\"human:alice\" = \"{public_key_hex}\"\n"),
+        )
+        .unwrap();
+
+        let report = executor.verify_gate_signatures(&issue.id).unwrap();
+        assert_eq!(report.gates[0].status, GateSignatureStatus::Valid);
+    }
+}
@@ -76,7 +76,7 @@ pub struct InstallResult {
 }
 
 /// Find .git directory by walking up from current directory
-fn find_git_dir() -> Result<PathBuf> {
+pub(crate) fn find_git_dir() -> Result<PathBuf> {
     let current_dir = std::env::current_dir().context("Failed to get current directory")?;
 
     let mut dir = current_dir.as_path();
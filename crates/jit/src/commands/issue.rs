@@ -2,6 +2,10 @@
 
 use super::*;
 
+/// Maximum number of `failed` reports an issue may receive before
+/// [`CommandExecutor::report_issue`] rejects it instead of requeuing it.
+const MAX_REPORT_RETRIES: u32 = 3;
+
 impl<S: IssueStore> CommandExecutor<S> {
     pub fn create_issue(
         &self,
@@ -47,7 +51,7 @@ impl<S: IssueStore> CommandExecutor<S> {
 
         // Log event
         let event = Event::new_issue_created(&issue);
-        self.storage.append_event(&event)?;
+        self.append_event(&event)?;
 
         Ok(issue.id)
     }
@@ -165,7 +169,7 @@ impl<S: IssueStore> CommandExecutor<S> {
                 }
 
                 // If gates not passed, transition to Gated and return error
-                if issue.has_unpassed_gates() {
+                if self.gates_unsatisfied(&issue)? {
                     return self.handle_gate_blocking(&mut issue, old_state);
                 } else {
                     issue.state = State::Done;
@@ -178,12 +182,12 @@ impl<S: IssueStore> CommandExecutor<S> {
             if old_state != issue.state {
                 let event =
                     Event::new_issue_state_changed(issue.id.clone(), old_state, issue.state);
-                self.storage.append_event(&event)?;
+                self.append_event(&event)?;
 
                 // Log completion event if transitioning to Done
                 if issue.state == State::Done {
                     let event = Event::new_issue_completed(issue.id.clone());
-                    self.storage.append_event(&event)?;
+                    self.append_event(&event)?;
                 }
             }
         }
@@ -200,6 +204,17 @@ impl<S: IssueStore> CommandExecutor<S> {
         Ok(())
     }
 
+    /// Set a single key in an issue's free-form `context` map.
+    ///
+    /// Used for auxiliary metadata that doesn't warrant a dedicated field or
+    /// event -- e.g. an orchestrator stashing a claim timestamp.
+    pub fn set_issue_context(&self, id: &str, key: &str, value: &str) -> Result<()> {
+        let full_id = self.storage.resolve_issue_id(id)?;
+        let mut issue = self.storage.load_issue(&full_id)?;
+        issue.context.insert(key.to_string(), value.to_string());
+        self.storage.save_issue(&issue)
+    }
+
     pub fn delete_issue(&self, id: &str) -> Result<()> {
         let full_id = self.storage.resolve_issue_id(id)?;
         self.storage.delete_issue(&full_id)
@@ -253,7 +268,7 @@ impl<S: IssueStore> CommandExecutor<S> {
                 }
 
                 // If gates not passed, transition to Gated and return error
-                if issue.has_unpassed_gates() {
+                if self.gates_unsatisfied(&issue)? {
                     return self.handle_gate_blocking(&mut issue, old_state);
                 } else {
                     issue.state = State::Done;
@@ -268,12 +283,13 @@ impl<S: IssueStore> CommandExecutor<S> {
             State::Gated => {
                 // Run postchecks when moving to Gated
                 issue.state = State::Gated;
+                self.run_transition_hooks(old_state, State::Gated, &issue)?;
                 self.storage.save_issue(&issue)?;
 
                 // Log state change event
                 let event =
                     Event::new_issue_state_changed(issue.id.clone(), old_state, State::Gated);
-                self.storage.append_event(&event)?;
+                self.append_event(&event)?;
 
                 // Run postchecks (which may auto-transition to Done)
                 self.run_postchecks(&full_id)?;
@@ -286,15 +302,16 @@ impl<S: IssueStore> CommandExecutor<S> {
 
         // Save and log
         if old_state != issue.state {
+            self.run_transition_hooks(old_state, issue.state, &issue)?;
             self.storage.save_issue(&issue)?;
 
             let event = Event::new_issue_state_changed(issue.id.clone(), old_state, issue.state);
-            self.storage.append_event(&event)?;
+            self.append_event(&event)?;
 
             // Log completion event if transitioning to Done
             if issue.state == State::Done {
                 let event = Event::new_issue_completed(issue.id.clone());
-                self.storage.append_event(&event)?;
+                self.append_event(&event)?;
             }
         } else {
             self.storage.save_issue(&issue)?;
@@ -334,7 +351,7 @@ impl<S: IssueStore> CommandExecutor<S> {
 
         // Log assignment event
         let event = Event::new_issue_claimed(issue.id.clone(), assignee);
-        self.storage.append_event(&event)?;
+        self.append_event(&event)?;
 
         Ok(())
     }
@@ -368,17 +385,126 @@ impl<S: IssueStore> CommandExecutor<S> {
             old_assignee.unwrap_or_default(),
             reason.to_string(),
         );
-        self.storage.append_event(&event)?;
+        self.append_event(&event)?;
 
         // Log state change if it occurred
         if old_state != issue.state {
             let event = Event::new_issue_state_changed(full_id, old_state, issue.state);
-            self.storage.append_event(&event)?;
+            self.append_event(&event)?;
         }
 
         Ok(())
     }
 
+    /// Record an agent's outcome for an issue it was working on.
+    ///
+    /// - `Done` clears the assignee and transitions to `Done`, still
+    ///   subject to the usual gate/dependency validation -- a gate-blocked
+    ///   issue moves to `Gated` instead, same as `jit issue update-state`.
+    /// - `Failed` records the error in `context` and increments a retry
+    ///   counter; the issue is requeued to `Ready` while attempts remain
+    ///   under [`MAX_REPORT_RETRIES`], or moved to `Rejected` once
+    ///   exhausted.
+    /// - `Progress` is informational only and just stashes the message in
+    ///   `context`.
+    pub fn report_issue(
+        &self,
+        id: &str,
+        status: ReportStatus,
+        message: Option<String>,
+    ) -> Result<()> {
+        let full_id = self.storage.resolve_issue_id(id)?;
+
+        match status {
+            ReportStatus::Done => {
+                self.update_issue_state(&full_id, State::Done)?;
+
+                let mut issue = self.storage.load_issue(&full_id)?;
+                let old_assignee = issue.assignee.take();
+                // Also a retry_count from an earlier cycle on this issue
+                // shouldn't count against a fresh assignment.
+                issue.context.remove("report.retry_count");
+                self.storage.save_issue(&issue)?;
+
+                // Reuse IssueReleased (not just IssueReported, which replay
+                // treats as audit-only) so `jit merge`/`jit pull` reconstruct
+                // the cleared assignee from the event log alone.
+                let release_event = Event::new_issue_released(
+                    full_id.clone(),
+                    old_assignee.unwrap_or_default(),
+                    "report:done".to_string(),
+                );
+                self.append_event(&release_event)?;
+
+                let event = Event::new_issue_reported(full_id, status, message, 1);
+                self.append_event(&event)?;
+                Ok(())
+            }
+            ReportStatus::Progress => {
+                let mut issue = self.storage.load_issue(&full_id)?;
+                if let Some(message) = &message {
+                    issue
+                        .context
+                        .insert("report.progress_message".to_string(), message.clone());
+                }
+                self.storage.save_issue(&issue)?;
+
+                let event = Event::new_issue_reported(full_id, status, message, 1);
+                self.append_event(&event)?;
+                Ok(())
+            }
+            ReportStatus::Failed => {
+                let mut issue = self.storage.load_issue(&full_id)?;
+                let old_state = issue.state;
+                let old_assignee = issue.assignee.take();
+
+                let retry_count: u32 = issue
+                    .context
+                    .get("report.retry_count")
+                    .and_then(|v| v.parse().ok())
+                    .unwrap_or(0)
+                    + 1;
+                issue
+                    .context
+                    .insert("report.retry_count".to_string(), retry_count.to_string());
+                if let Some(message) = &message {
+                    issue
+                        .context
+                        .insert("report.last_error".to_string(), message.clone());
+                }
+
+                issue.state = if retry_count < MAX_REPORT_RETRIES {
+                    State::Ready
+                } else {
+                    State::Rejected
+                };
+
+                self.storage.save_issue(&issue)?;
+
+                // Reuse IssueReleased so `jit merge`/`jit pull` reconstruct
+                // the cleared assignee from the event log alone, same as
+                // the Done path above.
+                let release_event = Event::new_issue_released(
+                    full_id.clone(),
+                    old_assignee.unwrap_or_default(),
+                    format!("report:failed (attempt {})", retry_count),
+                );
+                self.append_event(&release_event)?;
+
+                let event =
+                    Event::new_issue_reported(full_id.clone(), status, message, retry_count);
+                self.append_event(&event)?;
+
+                if old_state != issue.state {
+                    let event = Event::new_issue_state_changed(full_id, old_state, issue.state);
+                    self.append_event(&event)?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+
     pub fn claim_next(&self, assignee: String, _filter: Option<String>) -> Result<String> {
         let issues = self.storage.list_issues()?;
         let issue_refs: Vec<&Issue> = issues.iter().collect();
@@ -415,13 +541,16 @@ impl<S: IssueStore> CommandExecutor<S> {
         let mut issue = self.storage.load_issue(&full_id)?;
 
         if issue.should_auto_transition_to_ready(&resolved) {
+            // Fire whichever transition the repo's workflow config assigns to
+            // "all dependencies closed", not just the built-in Ready state.
+            let target = self.config_manager.get_workflow_config()?.auto_ready_target;
             let old_state = issue.state;
-            issue.state = State::Ready;
+            issue.state = target;
             self.storage.save_issue(&issue)?;
 
             // Log state change event
-            let event = Event::new_issue_state_changed(issue.id.clone(), old_state, State::Ready);
-            self.storage.append_event(&event)?;
+            let event = Event::new_issue_state_changed(issue.id.clone(), old_state, target);
+            self.append_event(&event)?;
 
             Ok(true)
         } else {
@@ -429,6 +558,24 @@ impl<S: IssueStore> CommandExecutor<S> {
         }
     }
 
+    /// Transition an issue to a new state, validating the move against the
+    /// repository's configured workflow transition table.
+    pub fn transition_issue(&self, id: &str, to: State) -> Result<()> {
+        let full_id = self.storage.resolve_issue_id(id)?;
+        let issue = self.storage.load_issue(&full_id)?;
+        let workflow = self.config_manager.get_workflow_config()?;
+
+        if !workflow.is_allowed(issue.state, to) {
+            return Err(anyhow!(
+                "Illegal transition: {} -> {} is not permitted by the configured workflow",
+                issue.state,
+                to
+            ));
+        }
+
+        self.update_issue_state(&full_id, to)
+    }
+
     pub(super) fn auto_transition_to_done(&self, issue_id: &str) -> Result<bool> {
         let full_id = self.storage.resolve_issue_id(issue_id)?;
         let mut issue = self.storage.load_issue(&full_id)?;
@@ -440,11 +587,11 @@ impl<S: IssueStore> CommandExecutor<S> {
 
             // Log state change event
             let event = Event::new_issue_state_changed(issue.id.clone(), old_state, State::Done);
-            self.storage.append_event(&event)?;
+            self.append_event(&event)?;
 
             // Log completion event
             let event = Event::new_issue_completed(issue.id.clone());
-            self.storage.append_event(&event)?;
+            self.append_event(&event)?;
 
             Ok(true)
         } else {
@@ -470,6 +617,24 @@ impl<S: IssueStore> CommandExecutor<S> {
     /// Helper to handle gate blocking when transitioning to Done
     ///
     /// Transitions issue to Gated, saves, logs, and returns error with clear feedback
+    /// Whether `issue`'s required gates still block a transition to `Done`.
+    ///
+    /// Delegates to [`Issue::has_unpassed_gates_strict`] when `[signing]
+    /// require_verified_gates` is set in `.jit/config.toml`, so a gate
+    /// flipped to `Passed` by hand-editing the event log -- rather than
+    /// through a verifiable signature from a trusted key -- still counts as
+    /// unsatisfied. Falls back to the non-strict [`Issue::has_unpassed_gates`]
+    /// otherwise, preserving existing behavior for repositories that haven't
+    /// opted in.
+    fn gates_unsatisfied(&self, issue: &Issue) -> Result<bool> {
+        if self.config_manager.require_verified_gates()? {
+            let trusted_keys = self.config_manager.get_trusted_gate_keys()?;
+            Ok(issue.has_unpassed_gates_strict(&trusted_keys))
+        } else {
+            Ok(issue.has_unpassed_gates())
+        }
+    }
+
     fn handle_gate_blocking(&self, issue: &mut Issue, old_state: State) -> Result<()> {
         let unpassed = issue.get_unpassed_gates();
         issue.state = State::Gated;
@@ -479,7 +644,7 @@ impl<S: IssueStore> CommandExecutor<S> {
 
         // Log state change event
         let event = Event::new_issue_state_changed(issue.id.clone(), old_state, State::Gated);
-        self.storage.append_event(&event)?;
+        self.append_event(&event)?;
 
         Err(anyhow!(
             "Gate validation failed: Cannot transition to 'done' - {} gate(s) not passed: {}\n\
@@ -736,4 +901,102 @@ mod tests {
             "Issue state should be Ready after manual transition"
         );
     }
+
+    #[test]
+    fn test_report_done_clears_assignee_and_completes() {
+        let executor = setup();
+
+        let mut issue = crate::domain::Issue::new("Test".to_string(), "Test".to_string());
+        issue.state = State::InProgress;
+        issue.assignee = Some("agent:worker-1".to_string());
+        let issue_id = issue.id.clone();
+        executor.storage.save_issue(&issue).unwrap();
+
+        executor
+            .report_issue(&issue_id, crate::domain::ReportStatus::Done, None)
+            .expect("Should report done");
+
+        let issue = executor.storage.load_issue(&issue_id).unwrap();
+        assert_eq!(issue.state, State::Done);
+        assert!(issue.assignee.is_none());
+    }
+
+    #[test]
+    fn test_report_failed_requeues_until_retry_budget_exhausted() {
+        let executor = setup();
+
+        let mut issue = crate::domain::Issue::new("Test".to_string(), "Test".to_string());
+        issue.state = State::InProgress;
+        issue.assignee = Some("agent:worker-1".to_string());
+        let issue_id = issue.id.clone();
+        executor.storage.save_issue(&issue).unwrap();
+
+        for _ in 0..MAX_REPORT_RETRIES - 1 {
+            executor
+                .report_issue(
+                    &issue_id,
+                    crate::domain::ReportStatus::Failed,
+                    Some("boom".to_string()),
+                )
+                .expect("Should report failed");
+
+            let issue = executor.storage.load_issue(&issue_id).unwrap();
+            assert_eq!(issue.state, State::Ready, "Should requeue under the retry budget");
+            assert!(issue.assignee.is_none());
+
+            // Simulate redispatch
+            let mut issue = executor.storage.load_issue(&issue_id).unwrap();
+            issue.state = State::InProgress;
+            issue.assignee = Some("agent:worker-1".to_string());
+            executor.storage.save_issue(&issue).unwrap();
+        }
+
+        // One more failure exhausts the budget
+        executor
+            .report_issue(
+                &issue_id,
+                crate::domain::ReportStatus::Failed,
+                Some("boom again".to_string()),
+            )
+            .expect("Should report failed");
+
+        let issue = executor.storage.load_issue(&issue_id).unwrap();
+        assert_eq!(issue.state, State::Rejected);
+        assert!(issue.assignee.is_none());
+        assert_eq!(
+            issue.context.get("report.last_error"),
+            Some(&"boom again".to_string())
+        );
+        assert_eq!(
+            issue.context.get("report.retry_count"),
+            Some(&MAX_REPORT_RETRIES.to_string())
+        );
+    }
+
+    #[test]
+    fn test_report_progress_stores_message_without_changing_state() {
+        let executor = setup();
+
+        let mut issue = crate::domain::Issue::new("Test".to_string(), "Test".to_string());
+        issue.state = State::InProgress;
+        issue.assignee = Some("agent:worker-1".to_string());
+        let issue_id = issue.id.clone();
+        executor.storage.save_issue(&issue).unwrap();
+
+        executor
+            .report_issue(
+                &issue_id,
+                crate::domain::ReportStatus::Progress,
+                Some("halfway done".to_string()),
+            )
+            .expect("Should report progress");
+
+        let issue = executor.storage.load_issue(&issue_id).unwrap();
+        assert_eq!(issue.state, State::InProgress);
+        assert_eq!(issue.assignee, Some("agent:worker-1".to_string()));
+        assert_eq!(
+            issue.context.get("report.progress_message"),
+            Some(&"halfway done".to_string())
+        );
+    }
 }
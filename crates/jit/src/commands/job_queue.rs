@@ -0,0 +1,214 @@
+//! Persistent, resumable job queue for `jit issue update --filter ... --async`.
+//!
+//! A job captures the filter (resolved to a fixed list of matched issue ids
+//! up front) plus the mutations to apply, and a cursor over that list, so
+//! `jit job run <id>` can be killed or crash partway through and resume
+//! later from the next unprocessed issue instead of restarting the whole
+//! bulk update or double-applying earlier ones. Jobs are persisted as one
+//! JSON file per job under `<storage root>/jobs/`, written atomically the
+//! same way [`crate::storage::json::JsonFileStorage`] writes issues.
+
+use super::*;
+use super::bulk_update::StepOutcome;
+use crate::output::ErrorCode;
+use crate::query::QueryFilter;
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use uuid::Uuid;
+use std::fs;
+use std::path::PathBuf;
+
+/// Why a persisted job couldn't be loaded.
+#[derive(Debug, Error)]
+enum JobQueueError {
+    #[error("Job not found: {0}")]
+    NotFound(String),
+    #[error("Malformed job file {0}: {1}")]
+    Malformed(String, String),
+}
+
+impl JobQueueError {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::NotFound(_) => ErrorCode::JOB_NOT_FOUND,
+            Self::Malformed(_, _) => ErrorCode::MALFORMED_JOB,
+        }
+    }
+}
+
+/// Classify an error from a job-queue operation into its stable
+/// [`ErrorCode`], the same role [`super::bulk_update`]'s `classify_error`
+/// plays for bulk-update failures.
+pub fn classify_job_error(e: &anyhow::Error) -> &'static str {
+    match e.downcast_ref::<JobQueueError>() {
+        Some(err) => err.code(),
+        None => ErrorCode::VALIDATION_FAILED,
+    }
+}
+
+/// Lifecycle state of a [`BulkUpdateJob`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobStatus {
+    /// Persisted but not yet drained.
+    Queued,
+    /// `jit job run` is (or was, if interrupted) draining it.
+    Running,
+    /// Every matched issue has been processed.
+    Completed,
+}
+
+/// A durable `jit issue update --filter ... --async` job: the original
+/// request plus enough progress state to resume after an interrupted
+/// `jit job run`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkUpdateJob {
+    pub id: String,
+    pub filter: String,
+    pub operations: UpdateOperations,
+    pub status: JobStatus,
+    /// Index into `result.matched` of the next issue to process.
+    pub cursor: usize,
+    /// Live progress: `matched` is fixed at enqueue time, `modified`/
+    /// `skipped`/`errors`/`summary` grow as [`CommandExecutor::run_job`]
+    /// advances `cursor`.
+    pub result: BulkUpdateResult,
+    pub created_at: String,
+}
+
+impl<S: IssueStore> CommandExecutor<S> {
+    fn jobs_dir(&self) -> PathBuf {
+        self.storage.root().join("jobs")
+    }
+
+    fn job_path(&self, id: &str) -> PathBuf {
+        self.jobs_dir().join(format!("{}.json", id))
+    }
+
+    /// Atomic write: write to temp file, then rename -- the same pattern
+    /// [`crate::storage::json::JsonFileStorage::write_json`] uses for issues.
+    fn save_job(&self, job: &BulkUpdateJob) -> Result<()> {
+        let dir = self.jobs_dir();
+        fs::create_dir_all(&dir).context("Failed to create jobs directory")?;
+
+        let path = self.job_path(&job.id);
+        let json = serde_json::to_string_pretty(job).context("Failed to serialize job")?;
+        let temp_path = path.with_extension("json.tmp");
+        fs::write(&temp_path, json).context("Failed to write temporary job file")?;
+        fs::rename(&temp_path, &path).context("Failed to rename temporary job file")?;
+        Ok(())
+    }
+
+    /// Load a persisted job by id. A missing file is [`JobQueueError::NotFound`];
+    /// a present-but-unparseable file is [`JobQueueError::Malformed`] rather
+    /// than a panic, so a corrupted job file never crashes the worker.
+    fn load_job(&self, id: &str) -> Result<BulkUpdateJob> {
+        let path = self.job_path(id);
+        if !path.exists() {
+            return Err(JobQueueError::NotFound(id.to_string()).into());
+        }
+
+        let contents = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read job file {}", id))?;
+        serde_json::from_str(&contents)
+            .map_err(|e| JobQueueError::Malformed(id.to_string(), e.to_string()).into())
+    }
+
+    /// Enqueue a `jit issue update --filter ... --async` job: resolve the
+    /// filter now, so `matched`/`total_matched` are known up front, and
+    /// persist it as `Queued`. Nothing is applied until [`Self::run_job`].
+    pub fn enqueue_bulk_update_job(
+        &self,
+        filter_str: &str,
+        operations: UpdateOperations,
+    ) -> Result<BulkUpdateJob> {
+        let filter = QueryFilter::parse(filter_str)?;
+        let all_issues = self.storage.list_issues()?;
+        let matched = filter.filter_issues(&all_issues)?;
+
+        let mut result = BulkUpdateResult::new();
+        result.matched = matched.iter().map(|i| i.id.clone()).collect();
+        result.compute_summary();
+
+        let job = BulkUpdateJob {
+            id: Uuid::new_v4().to_string(),
+            filter: filter_str.to_string(),
+            operations,
+            status: JobStatus::Queued,
+            cursor: 0,
+            result,
+            created_at: Utc::now().to_rfc3339(),
+        };
+        self.save_job(&job)?;
+        Ok(job)
+    }
+
+    /// Look up a job's current status, including its live progress totals.
+    pub fn job_status(&self, id: &str) -> Result<BulkUpdateJob> {
+        self.load_job(id)
+    }
+
+    /// List every persisted job, oldest first.
+    pub fn list_jobs(&self) -> Result<Vec<BulkUpdateJob>> {
+        let dir = self.jobs_dir();
+        if !dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut jobs = Vec::new();
+        for entry in fs::read_dir(&dir).context("Failed to read jobs directory")? {
+            let entry = entry?;
+            if entry.path().extension().and_then(|e| e.to_str()) != Some("json") {
+                continue;
+            }
+            let id = entry
+                .path()
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .unwrap_or_default()
+                .to_string();
+            jobs.push(self.load_job(&id)?);
+        }
+        jobs.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+        Ok(jobs)
+    }
+
+    /// Drain a queued or partially-run job: process each remaining matched
+    /// issue in order, persisting the job (advanced `cursor`, updated
+    /// `result`) after every single issue, so a kill partway through
+    /// resumes from the next unprocessed issue instead of restarting or
+    /// double-applying earlier ones.
+    pub fn run_job(&self, id: &str) -> Result<BulkUpdateJob> {
+        let mut job = self.load_job(id)?;
+        job.status = JobStatus::Running;
+
+        while job.cursor < job.result.matched.len() {
+            let issue_id = job.result.matched[job.cursor].clone();
+
+            match self.storage.load_issue(&issue_id) {
+                Ok(issue) => match self.apply_update_step(&issue, &job.operations, false)? {
+                    StepOutcome::Modified => job.result.modified.push(issue_id),
+                    StepOutcome::Skipped => job
+                        .result
+                        .skipped
+                        .push((issue_id, "no changes".to_string())),
+                    StepOutcome::Error(err) => job.result.errors.push(err),
+                },
+                Err(e) => job.result.errors.push(BulkUpdateError {
+                    id: issue_id,
+                    code: ErrorCode::ISSUE_NOT_FOUND.to_string(),
+                    message: e.to_string(),
+                }),
+            }
+
+            job.cursor += 1;
+            job.result.compute_summary();
+            self.save_job(&job)?;
+        }
+
+        job.status = JobStatus::Completed;
+        self.save_job(&job)?;
+        Ok(job)
+    }
+}
@@ -0,0 +1,336 @@
+//! Deterministic, operation-based merge of two event logs.
+//!
+//! The event log is treated as an append-only set of operations keyed by
+//! `Event.id`, following the same reconciliation strategy as Bayou-style
+//! event-sourcing protocols: union the two sets, impose a total order by
+//! `(timestamp, id)`, and fold over the ordered stream to recompute every
+//! issue's derived state. Because the fold is a pure function of the
+//! ordered event list, replay is commutative and idempotent regardless of
+//! which replica fetched which events first, so two clones that merge the
+//! same pair of logs always converge on the same `index.json`.
+//!
+//! This is exposed both as a library function (`merge_event_logs`) and as
+//! `jit merge`, which can be registered as a git merge driver for
+//! `events.jsonl` so concurrent branches reconcile automatically instead of
+//! producing textual conflict markers.
+
+use crate::domain::{Event, GateState, GateStatus, Issue, State};
+use crate::storage::IssueStore;
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+/// Union two event logs by `Event.id` and sort into the canonical replay
+/// order: `(timestamp, id)`. `IssueCreated` events naturally sort first for
+/// their issue, since they're always the earliest timestamp recorded for
+/// it; ties (including conflicting terminal transitions at equal
+/// timestamps) resolve by UUID, so every replica that merges the same pair
+/// of logs arrives at the same order.
+pub fn merge_event_logs(a: &[Event], b: &[Event]) -> Vec<Event> {
+    let mut seen = HashSet::new();
+    let mut merged = Vec::with_capacity(a.len() + b.len());
+
+    for event in a.iter().chain(b.iter()) {
+        if seen.insert(event.get_id().to_string()) {
+            merged.push(event.clone());
+        }
+    }
+
+    merged.sort_by(|x, y| {
+        (x.get_timestamp(), x.get_id()).cmp(&(y.get_timestamp(), y.get_id()))
+    });
+
+    merged
+}
+
+/// Recompute every issue's `state`, `assignee`, and `gates_status` by
+/// folding over events in canonical order. A later event for an `issue_id`
+/// always supersedes an earlier one, so this is safe to call with a
+/// re-merged log even if some of its events were already applied.
+///
+/// `existing` seeds each issue from its currently-stored record (keyed by
+/// id) before folding events on top, rather than starting from
+/// `Issue::new`. The event log only carries `title`/`priority`/`state`/
+/// `assignee`/`gates_status` -- it has no events for `description`,
+/// `dependencies`, `labels`, `gates_required`, `documents`, `due_date`,
+/// `sla_window_secs`, or `estimated_duration_secs`, so replaying from
+/// scratch would silently wipe those fields on every issue touched by a
+/// merge. An id absent from `existing` (a brand-new issue on this replica)
+/// still falls back to `Issue::new`.
+pub fn replay_all(events: &[Event], existing: &HashMap<String, Issue>) -> HashMap<String, Issue> {
+    let mut issues: HashMap<String, Issue> = HashMap::new();
+
+    for event in events {
+        match event {
+            Event::IssueCreated {
+                issue_id,
+                title,
+                priority,
+                ..
+            } => {
+                let mut issue = existing.get(issue_id).cloned().unwrap_or_else(|| {
+                    let mut issue = Issue::new(title.clone(), String::new());
+                    issue.id = issue_id.clone();
+                    issue
+                });
+                issue.title = title.clone();
+                issue.priority = *priority;
+                issues.insert(issue_id.clone(), issue);
+            }
+            Event::IssueClaimed {
+                issue_id, assignee, ..
+            } => {
+                if let Some(issue) = issues.get_mut(issue_id) {
+                    issue.assignee = Some(assignee.clone());
+                    issue.state = State::InProgress;
+                }
+            }
+            Event::IssueStateChanged { issue_id, to, .. } => {
+                if let Some(issue) = issues.get_mut(issue_id) {
+                    issue.state = *to;
+                }
+            }
+            Event::GatePassed {
+                issue_id,
+                gate_key,
+                updated_by,
+                timestamp,
+                signature,
+            } => {
+                if let Some(issue) = issues.get_mut(issue_id) {
+                    issue.gates_status.insert(
+                        gate_key.clone(),
+                        GateState {
+                            status: GateStatus::Passed,
+                            updated_by: updated_by.clone(),
+                            updated_at: *timestamp,
+                            signature: signature.clone(),
+                        },
+                    );
+                }
+            }
+            Event::GateFailed {
+                issue_id,
+                gate_key,
+                updated_by,
+                timestamp,
+                signature,
+            } => {
+                if let Some(issue) = issues.get_mut(issue_id) {
+                    issue.gates_status.insert(
+                        gate_key.clone(),
+                        GateState {
+                            status: GateStatus::Failed,
+                            updated_by: updated_by.clone(),
+                            updated_at: *timestamp,
+                            signature: signature.clone(),
+                        },
+                    );
+                }
+            }
+            Event::IssueCompleted { issue_id, .. } => {
+                if let Some(issue) = issues.get_mut(issue_id) {
+                    issue.state = State::Done;
+                }
+            }
+            Event::IssueReleased { issue_id, .. } => {
+                if let Some(issue) = issues.get_mut(issue_id) {
+                    issue.assignee = None;
+                }
+            }
+            // IssueReported is audit-only: the state change and context
+            // update it accompanies are logged as their own events, so
+            // there's nothing further to replay here.
+            Event::IssueReported { .. } => {}
+        }
+    }
+
+    issues
+}
+
+/// Summary of a `jit merge` run.
+#[derive(Debug, Clone, Serialize)]
+pub struct MergeReport {
+    pub events_merged: usize,
+    pub issues_updated: usize,
+}
+
+impl<S: IssueStore> super::CommandExecutor<S> {
+    /// Merge two event logs (typically `%A`/`%B` from a git merge driver
+    /// invocation over `events.jsonl`), recompute derived issue state from
+    /// the merged log, and persist both the merged log and the recomputed
+    /// issues to local storage.
+    pub fn merge_event_files(&self, ours_path: &Path, theirs_path: &Path) -> Result<MergeReport> {
+        let ours = read_event_log(ours_path)?;
+        let theirs = read_event_log(theirs_path)?;
+        let merged = merge_event_logs(&ours, &theirs);
+
+        let local: HashSet<String> = self
+            .storage
+            .read_events()?
+            .iter()
+            .map(|e| e.get_id().to_string())
+            .collect();
+        for event in &merged {
+            if !local.contains(event.get_id()) {
+                self.storage.append_event(event)?;
+            }
+        }
+
+        let existing: HashMap<String, Issue> = self
+            .storage
+            .list_issues()?
+            .into_iter()
+            .map(|issue| (issue.id.clone(), issue))
+            .collect();
+        let recomputed = replay_all(&merged, &existing);
+        let mut issues_updated = 0;
+        for issue in recomputed.values() {
+            self.storage.save_issue(issue)?;
+            issues_updated += 1;
+        }
+
+        write_event_log(ours_path, &merged)?;
+
+        Ok(MergeReport {
+            events_merged: merged.len(),
+            issues_updated,
+        })
+    }
+}
+
+fn read_event_log(path: &Path) -> Result<Vec<Event>> {
+    let file = std::fs::File::open(path)
+        .with_context(|| format!("Failed to open event log at {}", path.display()))?;
+    let reader = BufReader::new(file);
+
+    let mut events = Vec::new();
+    for line in reader.lines() {
+        let line = line.context("Failed to read line from event log")?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        events.push(serde_json::from_str(&line).context("Failed to deserialize event")?);
+    }
+    Ok(events)
+}
+
+fn write_event_log(path: &Path, events: &[Event]) -> Result<()> {
+    let mut out = String::new();
+    for event in events {
+        out.push_str(&serde_json::to_string(event)?);
+        out.push('\n');
+    }
+    std::fs::write(path, out)
+        .with_context(|| format!("Failed to write merged event log to {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::Priority;
+    use chrono::{Duration, Utc};
+
+    fn created(id: &str, issue_id: &str, secs_offset: i64) -> Event {
+        Event::IssueCreated {
+            id: id.to_string(),
+            issue_id: issue_id.to_string(),
+            timestamp: Utc::now() + Duration::seconds(secs_offset),
+            title: "Test issue".to_string(),
+            priority: Priority::Normal,
+        }
+    }
+
+    fn state_changed(id: &str, issue_id: &str, to: State, secs_offset: i64) -> Event {
+        Event::IssueStateChanged {
+            id: id.to_string(),
+            issue_id: issue_id.to_string(),
+            timestamp: Utc::now() + Duration::seconds(secs_offset),
+            from: State::Backlog,
+            to,
+        }
+    }
+
+    #[test]
+    fn test_merge_dedupes_by_event_id() {
+        let shared = created("evt-1", "issue-1", 0);
+        let a = vec![shared.clone()];
+        let b = vec![shared];
+
+        let merged = merge_event_logs(&a, &b);
+        assert_eq!(merged.len(), 1);
+    }
+
+    #[test]
+    fn test_merge_sorts_by_timestamp_then_id() {
+        let a = vec![created("evt-2", "issue-1", 10)];
+        let b = vec![created("evt-1", "issue-1", 0)];
+
+        let merged = merge_event_logs(&a, &b);
+        assert_eq!(merged[0].get_id(), "evt-1");
+        assert_eq!(merged[1].get_id(), "evt-2");
+    }
+
+    #[test]
+    fn test_conflicting_terminal_transitions_resolve_by_id_tiebreak() {
+        let created_evt = created("evt-0", "issue-1", 0);
+        let to_done = state_changed("evt-b", "issue-1", State::Done, 5);
+        let to_rejected = state_changed("evt-a", "issue-1", State::Rejected, 5);
+
+        let merged = merge_event_logs(&[created_evt, to_done], &[to_rejected]);
+        let issues = replay_all(&merged, &HashMap::new());
+
+        // Equal timestamps tiebreak by id: "evt-a" < "evt-b", so evt-b
+        // (Done) is replayed last and wins.
+        assert_eq!(issues["issue-1"].state, State::Done);
+    }
+
+    #[test]
+    fn test_replay_is_idempotent_regardless_of_fetch_order() {
+        let created_evt = created("evt-0", "issue-1", 0);
+        let claimed = Event::IssueClaimed {
+            id: "evt-1".to_string(),
+            issue_id: "issue-1".to_string(),
+            timestamp: Utc::now() + Duration::seconds(5),
+            assignee: "agent:alice".to_string(),
+        };
+
+        let merged_ab = merge_event_logs(&[created_evt.clone()], &[claimed.clone()]);
+        let merged_ba = merge_event_logs(&[claimed], &[created_evt]);
+
+        assert_eq!(
+            replay_all(&merged_ab, &HashMap::new())["issue-1"],
+            replay_all(&merged_ba, &HashMap::new())["issue-1"]
+        );
+    }
+
+    #[test]
+    fn test_replay_preserves_fields_the_event_log_does_not_carry() {
+        let mut stored = Issue::new("Test issue".to_string(), "Full description".to_string());
+        stored.id = "issue-1".to_string();
+        stored.labels = vec!["epic:auth".to_string()];
+        stored.dependencies = vec!["issue-0".to_string()];
+        stored.gates_required = vec!["review".to_string()];
+
+        let mut existing = HashMap::new();
+        existing.insert(stored.id.clone(), stored.clone());
+
+        let merged = merge_event_logs(
+            &[
+                created("evt-0", "issue-1", 0),
+                state_changed("evt-1", "issue-1", State::Ready, 5),
+            ],
+            &[],
+        );
+        let issues = replay_all(&merged, &existing);
+
+        let replayed = &issues["issue-1"];
+        assert_eq!(replayed.state, State::Ready);
+        assert_eq!(replayed.description, stored.description);
+        assert_eq!(replayed.labels, stored.labels);
+        assert_eq!(replayed.dependencies, stored.dependencies);
+        assert_eq!(replayed.gates_required, stored.gates_required);
+    }
+}
@@ -0,0 +1,244 @@
+//! `jit metrics`: Prometheus-compatible aggregates for scraping.
+//!
+//! Surfaces issue counts by state/priority, blocked-vs-ready totals, gate
+//! pass/fail counts, and per-agent in-progress/completed counts so fleets
+//! of agents can be graphed and stalled queues spotted.
+
+use super::*;
+
+/// Aggregate counts used to render both the Prometheus and JSON outputs.
+#[derive(Debug, Serialize)]
+pub struct MetricsSnapshot {
+    pub issues_by_state: HashMap<String, usize>,
+    pub issues_by_priority: HashMap<String, usize>,
+    pub blocked: usize,
+    pub ready: usize,
+    pub gate_pass_counts: HashMap<String, usize>,
+    pub gate_fail_counts: HashMap<String, usize>,
+    pub agent_in_progress: HashMap<String, usize>,
+    pub agent_completed: HashMap<String, usize>,
+}
+
+fn state_label(state: State) -> &'static str {
+    match state {
+        State::Backlog => "backlog",
+        State::Ready => "ready",
+        State::InProgress => "in_progress",
+        State::Gated => "gated",
+        State::Done => "done",
+        State::Archived => "archived",
+        State::Overdue => "overdue",
+        State::Rejected => "rejected",
+    }
+}
+
+fn priority_label(priority: Priority) -> &'static str {
+    match priority {
+        Priority::Low => "low",
+        Priority::Normal => "normal",
+        Priority::High => "high",
+        Priority::Critical => "critical",
+    }
+}
+
+impl<S: IssueStore> CommandExecutor<S> {
+    /// Compute the current metrics snapshot from storage.
+    pub fn collect_metrics(&self) -> Result<MetricsSnapshot> {
+        let issues = self.storage.list_issues()?;
+        let issue_refs: Vec<&Issue> = issues.iter().collect();
+        let resolved: HashMap<String, &Issue> =
+            issue_refs.iter().map(|i| (i.id.clone(), *i)).collect();
+
+        let mut issues_by_state: HashMap<String, usize> = HashMap::new();
+        let mut issues_by_priority: HashMap<String, usize> = HashMap::new();
+        let mut gate_pass_counts: HashMap<String, usize> = HashMap::new();
+        let mut gate_fail_counts: HashMap<String, usize> = HashMap::new();
+        let mut agent_in_progress: HashMap<String, usize> = HashMap::new();
+        let mut agent_completed: HashMap<String, usize> = HashMap::new();
+        let mut blocked = 0;
+        let mut ready = 0;
+
+        for issue in &issues {
+            *issues_by_state
+                .entry(state_label(issue.state).to_string())
+                .or_insert(0) += 1;
+            *issues_by_priority
+                .entry(priority_label(issue.priority).to_string())
+                .or_insert(0) += 1;
+
+            if issue.is_blocked(&resolved) {
+                blocked += 1;
+            }
+            if issue.state == State::Ready {
+                ready += 1;
+            }
+
+            for (gate_key, gate_state) in &issue.gates_status {
+                match gate_state.status {
+                    GateStatus::Passed => {
+                        *gate_pass_counts.entry(gate_key.clone()).or_insert(0) += 1;
+                    }
+                    GateStatus::Failed => {
+                        *gate_fail_counts.entry(gate_key.clone()).or_insert(0) += 1;
+                    }
+                    GateStatus::Pending => {}
+                }
+            }
+
+            if let Some(assignee) = &issue.assignee {
+                match issue.state {
+                    State::InProgress => {
+                        *agent_in_progress.entry(assignee.clone()).or_insert(0) += 1;
+                    }
+                    State::Done => {
+                        *agent_completed.entry(assignee.clone()).or_insert(0) += 1;
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        Ok(MetricsSnapshot {
+            issues_by_state,
+            issues_by_priority,
+            blocked,
+            ready,
+            gate_pass_counts,
+            gate_fail_counts,
+            agent_in_progress,
+            agent_completed,
+        })
+    }
+}
+
+impl MetricsSnapshot {
+    /// Render as Prometheus text exposition format.
+    pub fn to_prometheus_text(&self) -> String {
+        let mut out = String::new();
+
+        out.push_str("# HELP jit_issues_by_state Number of issues in each lifecycle state\n");
+        out.push_str("# TYPE jit_issues_by_state gauge\n");
+        let mut states: Vec<_> = self.issues_by_state.iter().collect();
+        states.sort_by_key(|(k, _)| k.clone());
+        for (state, count) in states {
+            out.push_str(&format!(
+                "jit_issues_by_state{{state=\"{}\"}} {}\n",
+                state, count
+            ));
+        }
+
+        out.push_str("# HELP jit_issues_by_priority Number of issues at each priority\n");
+        out.push_str("# TYPE jit_issues_by_priority gauge\n");
+        let mut priorities: Vec<_> = self.issues_by_priority.iter().collect();
+        priorities.sort_by_key(|(k, _)| k.clone());
+        for (priority, count) in priorities {
+            out.push_str(&format!(
+                "jit_issues_by_priority{{priority=\"{}\"}} {}\n",
+                priority, count
+            ));
+        }
+
+        out.push_str("# HELP jit_issues_blocked Number of issues currently blocked\n");
+        out.push_str("# TYPE jit_issues_blocked gauge\n");
+        out.push_str(&format!("jit_issues_blocked {}\n", self.blocked));
+
+        out.push_str("# HELP jit_issues_ready Number of issues ready for work\n");
+        out.push_str("# TYPE jit_issues_ready gauge\n");
+        out.push_str(&format!("jit_issues_ready {}\n", self.ready));
+
+        out.push_str("# HELP jit_gate_pass_total Number of passed gate evaluations per gate\n");
+        out.push_str("# TYPE jit_gate_pass_total counter\n");
+        let mut gate_pass: Vec<_> = self.gate_pass_counts.iter().collect();
+        gate_pass.sort_by_key(|(k, _)| k.clone());
+        for (gate, count) in gate_pass {
+            out.push_str(&format!(
+                "jit_gate_pass_total{{gate=\"{}\"}} {}\n",
+                gate, count
+            ));
+        }
+
+        out.push_str("# HELP jit_gate_fail_total Number of failed gate evaluations per gate\n");
+        out.push_str("# TYPE jit_gate_fail_total counter\n");
+        let mut gate_fail: Vec<_> = self.gate_fail_counts.iter().collect();
+        gate_fail.sort_by_key(|(k, _)| k.clone());
+        for (gate, count) in gate_fail {
+            out.push_str(&format!(
+                "jit_gate_fail_total{{gate=\"{}\"}} {}\n",
+                gate, count
+            ));
+        }
+
+        out.push_str("# HELP jit_agent_in_progress Number of issues an agent currently holds in progress\n");
+        out.push_str("# TYPE jit_agent_in_progress gauge\n");
+        let mut in_progress: Vec<_> = self.agent_in_progress.iter().collect();
+        in_progress.sort_by_key(|(k, _)| k.clone());
+        for (agent, count) in in_progress {
+            out.push_str(&format!(
+                "jit_agent_in_progress{{agent=\"{}\"}} {}\n",
+                agent, count
+            ));
+        }
+
+        out.push_str("# HELP jit_agent_completed_total Number of issues an agent has completed\n");
+        out.push_str("# TYPE jit_agent_completed_total counter\n");
+        let mut completed: Vec<_> = self.agent_completed.iter().collect();
+        completed.sort_by_key(|(k, _)| k.clone());
+        for (agent, count) in completed {
+            out.push_str(&format!(
+                "jit_agent_completed_total{{agent=\"{}\"}} {}\n",
+                agent, count
+            ));
+        }
+
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::InMemoryStorage;
+
+    fn executor() -> CommandExecutor<InMemoryStorage> {
+        let storage = InMemoryStorage::new();
+        storage.init().unwrap();
+        CommandExecutor::new(storage)
+    }
+
+    #[test]
+    fn test_collect_metrics_counts_states() {
+        let executor = executor();
+        executor
+            .create_issue(
+                "A".to_string(),
+                "".to_string(),
+                Priority::High,
+                vec![],
+                vec![],
+            )
+            .unwrap();
+
+        let snapshot = executor.collect_metrics().unwrap();
+        assert_eq!(snapshot.issues_by_state.get("ready"), Some(&1));
+        assert_eq!(snapshot.issues_by_priority.get("high"), Some(&1));
+    }
+
+    #[test]
+    fn test_prometheus_text_has_help_and_type_headers() {
+        let executor = executor();
+        executor
+            .create_issue(
+                "A".to_string(),
+                "".to_string(),
+                Priority::Normal,
+                vec![],
+                vec![],
+            )
+            .unwrap();
+
+        let text = executor.collect_metrics().unwrap().to_prometheus_text();
+        assert!(text.contains("# HELP jit_issues_by_state"));
+        assert!(text.contains("# TYPE jit_issues_by_state gauge"));
+        assert!(text.contains("jit_issues_by_state{state=\"ready\"} 1"));
+    }
+}
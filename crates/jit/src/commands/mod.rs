@@ -4,10 +4,22 @@
 //! dependency manipulation, gate operations, and event logging.
 //!
 //! This module is organized into submodules by functional area:
+//! - `batch`: Atomic multi-operation batch application
+//! - `concurrency`: Bounded parallel-ready queue and completion join (`wait`)
+//! - `critical_path`: Dependency-graph critical-path (ES/EF/LS/LF/slack) analysis
 //! - `issue`: Issue CRUD operations and lifecycle management
-//! - `dependency`: Dependency graph operations  
+//! - `job_queue`: Persistent, resumable job queue for `--filter ... --async`
+//!   bulk updates
+//! - `dependency`: Dependency graph operations
 //! - `breakdown`: Issue breakdown operations
+//! - `bundle`: Portable issue bundle export/import (see [`crate::bundle`])
+//! - `bulk_update`: Filter-selected, multi-issue update/preview, plus
+//!   atomic multi-entry `--batch` application and `--async` job enqueueing
+//! - `claim`: Exclusive, TTL-bound lease coordination (`jit claim ...`),
+//!   distinct from the assignee-based claiming under `jit issue claim`
 //! - `gate`: Quality gate operations
+//! - `gate_verify`: Signature verification for signed gate approvals
+//! - `gate_runner`: Executes `auto` gates' commands for in-progress issues
 //! - `graph`: Graph visualization and traversal
 //! - `query`: Issue query operations
 //! - `validate`: Validation and status operations
@@ -15,24 +27,68 @@
 //! - `document`: Document reference operations
 //! - `events`: Event log operations
 //! - `search`: Issue search operations
+//! - `merge`: Deterministic CRDT-style merge of concurrent event logs
+//! - `notify`: Dispatches appended events to configured [`crate::notifier`] sinks
+//! - `suggest`: LLM-assisted breakdown suggestions (feature `llm-suggest`)
+//! - `sync`: Git-ref-backed event sync (`jit push`/`jit pull`)
+//! - `transition_hooks`: External command hooks run on state transitions
 
+mod batch;
 mod breakdown;
+mod bulk_update;
+mod bundle;
+mod claim;
+mod concurrency;
+mod critical_path;
 mod dependency;
 mod document;
+mod dump;
 mod events;
 mod gate;
 mod gate_check;
 mod gate_cli_tests;
+mod gate_runner;
+mod gate_verify;
 mod graph;
+mod hooks;
 mod issue;
+mod job_queue;
 mod labels;
+mod merge;
+mod metrics;
+mod notify;
 mod query;
 mod search;
+#[cfg(feature = "llm-suggest")]
+mod suggest;
+mod sweep;
+mod sync;
+mod transition_hooks;
 mod validate;
 
+pub use batch::{parse_batch_file, BatchOperation, BatchResult};
+pub use bulk_update::{
+    BatchUpdateEntry, BatchUpdateReport, BulkUpdateError, BulkUpdatePreview, BulkUpdateResult,
+    UpdateOperations,
+};
+pub use bundle::BundleImportReport;
+pub use claim::{execute_claim_acquire, execute_claim_heartbeat, execute_claim_release};
+pub use critical_path::{CriticalPathNode, CriticalPathReport};
+pub use dump::{DumpReport, RestoreReport};
+pub use gate_runner::GateRunOutcome;
+pub use gate_verify::{GateSignatureStatus, GateVerification, GateVerificationReport};
+pub use job_queue::{classify_job_error, BulkUpdateJob, JobStatus};
+pub use merge::{merge_event_logs, MergeReport};
+pub use metrics::MetricsSnapshot;
+#[cfg(feature = "llm-suggest")]
+pub use suggest::{BreakdownSuggestion, SuggestedSubtask};
+pub use sweep::{SweepAction, SweepActionKind, SweepReport};
+pub use sync::{PullReport, PushReport};
+pub use transition_hooks::HookRunResult;
+
 // Common imports used across modules
 use crate::config_manager::ConfigManager;
-use crate::domain::{Event, Gate, GateState, GateStatus, Issue, Priority, State};
+use crate::domain::{Event, Gate, GateState, GateStatus, Issue, Priority, ReportStatus, State};
 use crate::graph::DependencyGraph;
 use crate::labels as label_utils;
 use crate::storage::IssueStore;
@@ -128,10 +184,20 @@ pub fn parse_state(s: &str) -> Result<State> {
         "done" => Ok(State::Done),
         "rejected" => Ok(State::Rejected),
         "archived" => Ok(State::Archived),
+        "overdue" => Ok(State::Overdue),
         _ => Err(anyhow!("Invalid state: {}", s)),
     }
 }
 
+pub fn parse_report_status(s: &str) -> Result<ReportStatus> {
+    match s.to_lowercase().as_str() {
+        "done" => Ok(ReportStatus::Done),
+        "failed" => Ok(ReportStatus::Failed),
+        "progress" => Ok(ReportStatus::Progress),
+        _ => Err(anyhow!("Invalid report status: {}", s)),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
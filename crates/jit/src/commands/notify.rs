@@ -0,0 +1,80 @@
+//! Wires the [`crate::notifier`] subsystem into command execution.
+//!
+//! [`CommandExecutor::append_event`] is the single place every *live*
+//! event (as opposed to one replayed by [`super::merge`] or [`super::sync`],
+//! which must not re-fire notifications for events that already happened)
+//! passes through on its way into the event log. After persisting the
+//! event, it's matched against the sinks configured in `.jit/config.toml`
+//! and dispatched; every delivery attempt's outcome is appended to
+//! `.jit/notify_log.jsonl` so a failed or skipped delivery is never
+//! silently dropped.
+
+use super::*;
+use crate::notifier::{DeliveryOutcome, NotifierConfig};
+use std::io::Write;
+
+impl<S: IssueStore> CommandExecutor<S> {
+    /// Append `event` to the log, then dispatch it to any notifier sinks
+    /// that match. Notification failures never fail the calling command.
+    pub(crate) fn append_event(&self, event: &Event) -> Result<()> {
+        self.storage.append_event(event)?;
+        self.dispatch_notifications(event);
+        Ok(())
+    }
+
+    fn dispatch_notifications(&self, event: &Event) {
+        let Ok(config) = self.config_manager.load() else {
+            return;
+        };
+        let Ok(notifier_config) = NotifierConfig::from_toml(config.notifier.as_ref()) else {
+            return;
+        };
+        if notifier_config.sinks.is_empty() {
+            return;
+        }
+
+        let issue = self.storage.load_issue(event.get_issue_id()).ok();
+        let assignee = issue.as_ref().and_then(|i| i.assignee.as_deref());
+        let priority = issue.as_ref().map(|i| i.priority);
+        let labels = issue.as_ref().map(|i| i.labels.as_slice()).unwrap_or(&[]);
+
+        for sink in notifier_config.matching_sinks(event, assignee, priority, labels) {
+            let outcome = crate::notifier::deliver(sink, event, issue.as_ref(), self.config_manager.root());
+            self.record_delivery_outcome(&outcome);
+        }
+    }
+
+    fn record_delivery_outcome(&self, outcome: &DeliveryOutcome) {
+        let Ok(line) = serde_json::to_string(outcome) else {
+            return;
+        };
+        let path = self.config_manager.root().join("notify_log.jsonl");
+        if let Ok(mut file) = std::fs::OpenOptions::new().create(true).append(true).open(&path) {
+            let _ = writeln!(file, "{}", line);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::InMemoryStorage;
+
+    fn executor() -> CommandExecutor<InMemoryStorage> {
+        let storage = InMemoryStorage::new();
+        storage.init().unwrap();
+        CommandExecutor::new(storage)
+    }
+
+    #[test]
+    fn test_append_event_with_no_sinks_configured_is_a_noop() {
+        let executor = executor();
+        let issue = Issue::new("Test".to_string(), String::new());
+        let event = Event::new_issue_created(&issue);
+
+        executor.append_event(&event).unwrap();
+
+        let events = executor.storage().read_events().unwrap();
+        assert_eq!(events.len(), 1);
+    }
+}
@@ -0,0 +1,77 @@
+//! LLM-assisted task breakdown (feature-gated, requires `llm-suggest`).
+//!
+//! `jit suggest <id>` reads an issue's title and description through a
+//! locally embedded model (see [`crate::llm`]) and proposes draft subtasks
+//! with estimates and suggested dependency edges between them. The proposal
+//! is purely advisory: nothing is written to the store until it is
+//! explicitly materialized via [`CommandExecutor::accept_suggestion`], so
+//! this augments the existing issue/dependency model without requiring a
+//! network service.
+
+#![cfg(feature = "llm-suggest")]
+
+use super::*;
+
+/// One proposed subtask in a breakdown suggestion.
+#[derive(Debug, Clone, Serialize)]
+pub struct SuggestedSubtask {
+    pub title: String,
+    pub description: String,
+    pub estimated_duration_secs: Option<i64>,
+    /// Indices into the suggestion's `subtasks` list that this one depends on.
+    pub depends_on: Vec<usize>,
+}
+
+/// A reviewable, unmaterialized breakdown proposal for a parent issue.
+#[derive(Debug, Clone, Serialize)]
+pub struct BreakdownSuggestion {
+    pub parent_id: String,
+    pub subtasks: Vec<SuggestedSubtask>,
+}
+
+impl<S: IssueStore> CommandExecutor<S> {
+    /// Ask the embedded model to propose a breakdown of `parent_id` into
+    /// subtasks. Does not modify the store.
+    pub fn suggest_breakdown(&self, parent_id: &str) -> Result<BreakdownSuggestion> {
+        let full_id = self.storage.resolve_issue_id(parent_id)?;
+        let parent = self.storage.load_issue(&full_id)?;
+
+        let mut model = crate::llm::SuggestModel::load()?;
+        let subtasks = model.propose_subtasks(&parent.title, &parent.description)?;
+
+        Ok(BreakdownSuggestion {
+            parent_id: full_id,
+            subtasks,
+        })
+    }
+
+    /// Materialize a previously reviewed [`BreakdownSuggestion`] into real
+    /// subtask issues (preserving the suggested dependency edges and
+    /// estimates), via the existing [`Self::breakdown_issue`] machinery.
+    pub fn accept_suggestion(&self, suggestion: &BreakdownSuggestion) -> Result<Vec<String>> {
+        let subtasks = suggestion
+            .subtasks
+            .iter()
+            .map(|s| (s.title.clone(), s.description.clone()))
+            .collect();
+
+        let subtask_ids = self.breakdown_issue(&suggestion.parent_id, subtasks)?;
+
+        for (i, suggested) in suggestion.subtasks.iter().enumerate() {
+            if let Some(duration) = suggested.estimated_duration_secs {
+                let mut issue = self.storage.load_issue(&subtask_ids[i])?;
+                issue.estimated_duration_secs = Some(duration);
+                self.storage.save_issue(&issue)?;
+            }
+            for &dep_index in &suggested.depends_on {
+                if let Some(dep_id) = subtask_ids.get(dep_index) {
+                    if dep_id != &subtask_ids[i] {
+                        self.add_dependency(&subtask_ids[i], dep_id)?;
+                    }
+                }
+            }
+        }
+
+        Ok(subtask_ids)
+    }
+}
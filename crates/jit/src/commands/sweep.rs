@@ -0,0 +1,169 @@
+//! Deadline-driven automatic state transitions.
+//!
+//! Issues may carry an optional `due_date` and `sla_window_secs`. `jit sweep`
+//! walks every issue and, keyed purely on wall-clock time rather than
+//! dependency completion, auto-transitions unstarted issues whose deadline
+//! has passed into `Overdue` and flags issues entering their SLA window.
+
+use super::*;
+use chrono::{DateTime, Utc};
+
+/// One issue that was transitioned or flagged by a sweep.
+#[derive(Debug, Clone, Serialize)]
+pub struct SweepAction {
+    pub issue_id: String,
+    pub title: String,
+    pub kind: SweepActionKind,
+}
+
+/// What a sweep did for a given issue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SweepActionKind {
+    /// The issue passed its due date while unstarted and was moved to `Overdue`.
+    TransitionedOverdue,
+    /// The issue is inside its SLA window but not yet past due.
+    NearingDeadline,
+}
+
+/// Report produced by a single sweep pass.
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct SweepReport {
+    pub actions: Vec<SweepAction>,
+}
+
+/// States an issue must be in to be eligible for an overdue sweep: unstarted,
+/// actionable work that hasn't yet begun.
+fn is_unstarted(state: State) -> bool {
+    matches!(state, State::Backlog | State::Ready)
+}
+
+impl<S: IssueStore> CommandExecutor<S> {
+    /// Run one deadline sweep: transition past-due unstarted issues to
+    /// `Overdue` and flag issues entering their SLA window. Returns a report
+    /// of every action taken; does not error if no issue has a due date.
+    pub fn sweep_deadlines(&self) -> Result<SweepReport> {
+        self.sweep_deadlines_at(Utc::now())
+    }
+
+    /// Same as [`Self::sweep_deadlines`] but with an explicit "now", for
+    /// deterministic testing.
+    pub fn sweep_deadlines_at(&self, now: DateTime<Utc>) -> Result<SweepReport> {
+        let mut report = SweepReport::default();
+
+        for issue in self.storage.list_issues()? {
+            let Some(due_date) = issue.due_date else {
+                continue;
+            };
+
+            if is_unstarted(issue.state) && due_date <= now {
+                let old_state = issue.state;
+                let mut issue = issue;
+                issue.state = State::Overdue;
+                self.storage.save_issue(&issue)?;
+
+                let event =
+                    Event::new_issue_state_changed(issue.id.clone(), old_state, State::Overdue);
+                self.append_event(&event)?;
+
+                report.actions.push(SweepAction {
+                    issue_id: issue.id.clone(),
+                    title: issue.title.clone(),
+                    kind: SweepActionKind::TransitionedOverdue,
+                });
+                continue;
+            }
+
+            if let Some(sla_secs) = issue.sla_window_secs {
+                let warn_at = due_date - chrono::Duration::seconds(sla_secs);
+                if is_unstarted(issue.state) && now >= warn_at && now < due_date {
+                    report.actions.push(SweepAction {
+                        issue_id: issue.id.clone(),
+                        title: issue.title.clone(),
+                        kind: SweepActionKind::NearingDeadline,
+                    });
+                }
+            }
+        }
+
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::InMemoryStorage;
+    use chrono::Duration;
+
+    fn executor() -> CommandExecutor<InMemoryStorage> {
+        let storage = InMemoryStorage::new();
+        storage.init().unwrap();
+        CommandExecutor::new(storage)
+    }
+
+    #[test]
+    fn test_sweep_transitions_past_due_backlog_issue_to_overdue() {
+        let executor = executor();
+        let id = executor
+            .create_issue(
+                "Ship it".to_string(),
+                "".to_string(),
+                Priority::Normal,
+                vec![],
+                vec![],
+            )
+            .unwrap();
+
+        let mut issue = executor.storage().load_issue(&id).unwrap();
+        issue.due_date = Some(Utc::now() - Duration::days(1));
+        executor.storage().save_issue(&issue).unwrap();
+
+        let report = executor.sweep_deadlines().unwrap();
+        assert_eq!(report.actions.len(), 1);
+        assert_eq!(report.actions[0].kind, SweepActionKind::TransitionedOverdue);
+
+        let swept = executor.storage().load_issue(&id).unwrap();
+        assert_eq!(swept.state, State::Overdue);
+    }
+
+    #[test]
+    fn test_sweep_flags_issue_nearing_deadline() {
+        let executor = executor();
+        let id = executor
+            .create_issue(
+                "Ship it".to_string(),
+                "".to_string(),
+                Priority::Normal,
+                vec![],
+                vec![],
+            )
+            .unwrap();
+
+        let mut issue = executor.storage().load_issue(&id).unwrap();
+        issue.due_date = Some(Utc::now() + Duration::hours(1));
+        issue.sla_window_secs = Some(3600 * 4);
+        executor.storage().save_issue(&issue).unwrap();
+
+        let report = executor.sweep_deadlines().unwrap();
+        assert_eq!(report.actions.len(), 1);
+        assert_eq!(report.actions[0].kind, SweepActionKind::NearingDeadline);
+    }
+
+    #[test]
+    fn test_sweep_ignores_issues_without_due_date() {
+        let executor = executor();
+        executor
+            .create_issue(
+                "No deadline".to_string(),
+                "".to_string(),
+                Priority::Normal,
+                vec![],
+                vec![],
+            )
+            .unwrap();
+
+        let report = executor.sweep_deadlines().unwrap();
+        assert!(report.actions.is_empty());
+    }
+}
@@ -0,0 +1,326 @@
+//! Git-ref-backed sync of the event log, so issue history travels with a
+//! repository over any git remote instead of requiring a central server.
+//!
+//! Each `Event` already carries a stable `id` and `timestamp`, so every
+//! event is mirrored into its own git ref under `refs/jit/events/<id>`,
+//! pointing at a blob containing the event's JSON — one immutable object
+//! per event, the same way tools like `git notes` keep metadata in refs
+//! rather than tracked working-tree files. `jit push`/`jit pull` transport
+//! those refs alongside code; `pull` also replays any newly-fetched events
+//! into the local issue store so `index.json` stays in sync without manual
+//! merging.
+
+use super::hooks::find_git_dir;
+use crate::domain::{Event, GateState, GateStatus, Issue};
+use crate::storage::IssueStore;
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::collections::HashSet;
+use std::process::{Command, Stdio};
+
+const EVENT_REF_PREFIX: &str = "refs/jit/events/";
+
+/// Summary of a `jit push` run.
+#[derive(Debug, Clone, Serialize)]
+pub struct PushReport {
+    pub refs_created: usize,
+    pub refs_already_present: usize,
+}
+
+/// Summary of a `jit pull` run.
+#[derive(Debug, Clone, Serialize)]
+pub struct PullReport {
+    pub events_fetched: usize,
+    pub events_replayed: usize,
+}
+
+impl<S: IssueStore> super::CommandExecutor<S> {
+    /// Mirror every local event into its own `refs/jit/events/<id>` ref and
+    /// push those refs to `remote`.
+    pub fn push_events(&self, remote: &str) -> Result<PushReport> {
+        find_git_dir().context("jit push requires a git repository")?;
+        let events = self.storage.read_events()?;
+
+        let mut refs_created = 0;
+        let mut refs_already_present = 0;
+
+        for event in &events {
+            let ref_name = event_ref_name(event.get_id());
+            if ref_exists(&ref_name)? {
+                refs_already_present += 1;
+                continue;
+            }
+            let sha = hash_object(&serde_json::to_vec(event)?)?;
+            update_ref(&ref_name, &sha)?;
+            refs_created += 1;
+        }
+
+        if refs_created > 0 {
+            run_git(&[
+                "push",
+                remote,
+                &format!("{EVENT_REF_PREFIX}*:{EVENT_REF_PREFIX}*"),
+            ])?;
+        }
+
+        Ok(PushReport {
+            refs_created,
+            refs_already_present,
+        })
+    }
+
+    /// Fetch `refs/jit/events/*` from `remote`, append any events not yet
+    /// known locally, and replay them into the issue store.
+    pub fn pull_events(&self, remote: &str) -> Result<PullReport> {
+        find_git_dir().context("jit pull requires a git repository")?;
+        run_git(&[
+            "fetch",
+            remote,
+            &format!("{EVENT_REF_PREFIX}*:{EVENT_REF_PREFIX}*"),
+        ])?;
+
+        let known: HashSet<String> = self
+            .storage
+            .read_events()?
+            .iter()
+            .map(|e| e.get_id().to_string())
+            .collect();
+
+        let mut fetched: Vec<Event> = Vec::new();
+        for ref_name in list_event_refs()? {
+            let sha = run_git(&["rev-parse", &ref_name])?;
+            let content = run_git(&["cat-file", "-p", sha.trim()])?;
+            let event: Event = serde_json::from_str(&content)
+                .with_context(|| format!("Failed to parse event object for ref {ref_name}"))?;
+            if !known.contains(event.get_id()) {
+                fetched.push(event);
+            }
+        }
+
+        fetched.sort_by_key(|e| e.get_timestamp());
+
+        let mut events_replayed = 0;
+        for event in &fetched {
+            self.storage.append_event(event)?;
+            if self.replay_event(event).is_ok() {
+                events_replayed += 1;
+            }
+        }
+
+        Ok(PullReport {
+            events_fetched: fetched.len(),
+            events_replayed,
+        })
+    }
+
+    /// Apply a single event's effect to the local issue store. Used to bring
+    /// `index.json` in line with events pulled from a remote, since those
+    /// events were appended to a clone's log, not produced by this store.
+    fn replay_event(&self, event: &Event) -> Result<()> {
+        match event {
+            Event::IssueCreated {
+                issue_id,
+                title,
+                priority,
+                ..
+            } => {
+                if self.storage.load_issue(issue_id).is_err() {
+                    let mut issue = Issue::new(title.clone(), String::new());
+                    issue.id = issue_id.clone();
+                    issue.priority = *priority;
+                    self.storage.save_issue(&issue)?;
+                }
+            }
+            Event::IssueClaimed {
+                issue_id, assignee, ..
+            } => {
+                if let Ok(mut issue) = self.storage.load_issue(issue_id) {
+                    issue.assignee = Some(assignee.clone());
+                    self.storage.save_issue(&issue)?;
+                }
+            }
+            Event::IssueStateChanged { issue_id, to, .. } => {
+                if let Ok(mut issue) = self.storage.load_issue(issue_id) {
+                    issue.state = *to;
+                    self.storage.save_issue(&issue)?;
+                }
+            }
+            Event::GatePassed {
+                issue_id,
+                gate_key,
+                updated_by,
+                timestamp,
+                signature,
+            } => {
+                if let Ok(mut issue) = self.storage.load_issue(issue_id) {
+                    issue.gates_status.insert(
+                        gate_key.clone(),
+                        GateState {
+                            status: GateStatus::Passed,
+                            updated_by: updated_by.clone(),
+                            updated_at: *timestamp,
+                            signature: signature.clone(),
+                        },
+                    );
+                    self.storage.save_issue(&issue)?;
+                }
+            }
+            Event::GateFailed {
+                issue_id,
+                gate_key,
+                updated_by,
+                timestamp,
+                signature,
+            } => {
+                if let Ok(mut issue) = self.storage.load_issue(issue_id) {
+                    issue.gates_status.insert(
+                        gate_key.clone(),
+                        GateState {
+                            status: GateStatus::Failed,
+                            updated_by: updated_by.clone(),
+                            updated_at: *timestamp,
+                            signature: signature.clone(),
+                        },
+                    );
+                    self.storage.save_issue(&issue)?;
+                }
+            }
+            Event::IssueReleased { issue_id, .. } => {
+                if let Ok(mut issue) = self.storage.load_issue(issue_id) {
+                    issue.assignee = None;
+                    self.storage.save_issue(&issue)?;
+                }
+            }
+            // IssueCompleted carries no new information beyond the state
+            // change that accompanies it, so there's nothing to replay.
+            Event::IssueCompleted { .. } => {}
+            // Likewise IssueReported: it's an audit trail of what an agent
+            // said, not new state -- the state/context changes it triggers
+            // are logged as their own events.
+            Event::IssueReported { .. } => {}
+        }
+        Ok(())
+    }
+}
+
+fn event_ref_name(event_id: &str) -> String {
+    format!("{EVENT_REF_PREFIX}{event_id}")
+}
+
+fn ref_exists(ref_name: &str) -> Result<bool> {
+    let status = Command::new("git")
+        .args(["show-ref", "--verify", "--quiet", ref_name])
+        .status()
+        .with_context(|| format!("Failed to run `git show-ref {ref_name}`"))?;
+    Ok(status.success())
+}
+
+fn list_event_refs() -> Result<Vec<String>> {
+    let output = run_git(&["for-each-ref", "--format=%(refname)", EVENT_REF_PREFIX])?;
+    Ok(output.lines().map(|l| l.trim().to_string()).collect())
+}
+
+/// Write `content` as a git blob and return its SHA.
+fn hash_object(content: &[u8]) -> Result<String> {
+    use std::io::Write;
+
+    let mut child = Command::new("git")
+        .args(["hash-object", "-w", "--stdin"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .context("Failed to spawn `git hash-object`")?;
+
+    child
+        .stdin
+        .take()
+        .expect("stdin was piped")
+        .write_all(content)
+        .context("Failed to write event JSON to git hash-object")?;
+
+    let output = child
+        .wait_with_output()
+        .context("Failed to wait on `git hash-object`")?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "git hash-object failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+fn update_ref(ref_name: &str, sha: &str) -> Result<()> {
+    run_git(&["update-ref", ref_name, sha])?;
+    Ok(())
+}
+
+/// Run a git plumbing/porcelain command and return its trimmed stdout.
+fn run_git(args: &[&str]) -> Result<String> {
+    let output = Command::new("git")
+        .args(args)
+        .output()
+        .with_context(|| format!("Failed to run `git {}`", args.join(" ")))?;
+
+    if !output.status.success() {
+        anyhow::bail!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn init_repo() -> TempDir {
+        let temp = TempDir::new().unwrap();
+        Command::new("git")
+            .args(["init", "-q"])
+            .current_dir(temp.path())
+            .status()
+            .unwrap();
+        temp
+    }
+
+    #[test]
+    fn test_hash_object_and_update_ref_roundtrip() {
+        let temp = init_repo();
+        let original_dir = std::env::current_dir().unwrap();
+        std::env::set_current_dir(temp.path()).unwrap();
+
+        let sha = hash_object(b"{\"hello\":\"world\"}").unwrap();
+        update_ref(&event_ref_name("evt-1"), &sha).unwrap();
+
+        let refs = list_event_refs().unwrap();
+        std::env::set_current_dir(&original_dir).unwrap();
+
+        assert_eq!(refs, vec![event_ref_name("evt-1")]);
+    }
+
+    #[test]
+    fn test_ref_exists_false_for_unknown_ref() {
+        let temp = init_repo();
+        let original_dir = std::env::current_dir().unwrap();
+        std::env::set_current_dir(temp.path()).unwrap();
+
+        let exists = ref_exists(&event_ref_name("nonexistent")).unwrap();
+
+        std::env::set_current_dir(&original_dir).unwrap();
+        assert!(!exists);
+    }
+
+    #[test]
+    fn test_event_ref_name_format() {
+        assert_eq!(
+            event_ref_name("abc-123"),
+            "refs/jit/events/abc-123".to_string()
+        );
+    }
+}
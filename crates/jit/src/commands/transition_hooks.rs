@@ -0,0 +1,115 @@
+//! External command hooks fired on issue state transitions.
+//!
+//! Repositories can configure shell commands to run when an issue crosses a
+//! specific `from -> to` transition (see [`crate::config::TransitionHookToml`]
+//! and [`crate::workflow::WorkflowConfig::hooks_for`]). Each hook receives
+//! the issue's JSON serialization on stdin, following the same stdin-piping
+//! convention used elsewhere for spawning external checkers. A `blocking`
+//! hook that exits non-zero aborts the transition before it is persisted.
+
+use super::*;
+use anyhow::Context;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// Outcome of running a single configured hook.
+#[derive(Debug, Clone, Serialize)]
+pub struct HookRunResult {
+    pub command: String,
+    pub exit_code: Option<i32>,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+impl<S: IssueStore> CommandExecutor<S> {
+    /// Run every hook configured for `from -> to`, piping `issue`'s JSON to
+    /// each hook's stdin in configuration order.
+    ///
+    /// If a `blocking` hook exits non-zero, returns an error immediately and
+    /// does not run any hooks configured after it; the caller must not
+    /// persist the transition when this returns `Err`.
+    pub(super) fn run_transition_hooks(
+        &self,
+        from: State,
+        to: State,
+        issue: &Issue,
+    ) -> Result<Vec<HookRunResult>> {
+        let workflow = self.config_manager.get_workflow_config()?;
+        let hooks = workflow.hooks_for(from, to);
+        if hooks.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let payload = serde_json::to_vec(issue)?;
+        let mut results = Vec::with_capacity(hooks.len());
+
+        for hook in hooks {
+            let result = run_hook_command(&hook.command, &payload)?;
+            let failed = result.exit_code != Some(0);
+            results.push(result);
+
+            if hook.blocking && failed {
+                return Err(anyhow!(
+                    "Transition hook '{}' failed for {} -> {}: blocking hook exited non-zero, transition aborted",
+                    hook.command,
+                    from,
+                    to
+                ));
+            }
+        }
+
+        Ok(results)
+    }
+}
+
+/// Spawn `command` via the shell, write `stdin_payload` to its stdin, and
+/// capture its exit code and output.
+fn run_hook_command(command: &str, stdin_payload: &[u8]) -> Result<HookRunResult> {
+    let mut child = Command::new("sh")
+        .arg("-c")
+        .arg(command)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .with_context(|| format!("Failed to spawn transition hook: {}", command))?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin
+            .write_all(stdin_payload)
+            .with_context(|| format!("Failed to write issue JSON to transition hook: {}", command))?;
+    }
+
+    let output = child
+        .wait_with_output()
+        .with_context(|| format!("Failed to wait for transition hook: {}", command))?;
+
+    Ok(HookRunResult {
+        command: command.to_string(),
+        exit_code: output.status.code(),
+        stdout: String::from_utf8_lossy(&output.stdout).to_string(),
+        stderr: String::from_utf8_lossy(&output.stderr).to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::InMemoryStorage;
+
+    fn executor() -> CommandExecutor<InMemoryStorage> {
+        let storage = InMemoryStorage::new();
+        storage.init().unwrap();
+        CommandExecutor::new(storage)
+    }
+
+    #[test]
+    fn test_no_hooks_configured_is_a_noop() {
+        let executor = executor();
+        let issue = Issue::new("Test".to_string(), "".to_string());
+        let results = executor
+            .run_transition_hooks(State::Backlog, State::Ready, &issue)
+            .unwrap();
+        assert!(results.is_empty());
+    }
+}
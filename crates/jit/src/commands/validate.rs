@@ -2,8 +2,10 @@
 
 use super::*;
 use crate::type_hierarchy::{
-    detect_validation_issues, generate_fixes, ValidationFix, ValidationIssue,
+    detect_validation_issues, generate_fixes, run_custom_rules, validate_orphans,
+    validate_strategic_labels, HierarchyConfig, ValidationFix, ValidationIssue, ValidationWarning,
 };
+use std::path::Path;
 
 impl<S: IssueStore> CommandExecutor<S> {
     pub fn validate(&self) -> Result<()> {
@@ -70,18 +72,7 @@ impl<S: IssueStore> CommandExecutor<S> {
         let mut all_validation_issues = Vec::new();
 
         for issue in &issues {
-            // Build dependency map for this issue
-            let deps: Vec<(String, Vec<String>)> = issue
-                .dependencies
-                .iter()
-                .filter_map(|dep_id| {
-                    self.storage
-                        .load_issue(dep_id)
-                        .ok()
-                        .map(|dep| (dep.id.clone(), dep.labels.clone()))
-                })
-                .collect();
-
+            let deps = self.issue_dependency_labels(issue);
             let validation_issues =
                 detect_validation_issues(&config, &issue.id, &issue.labels, &deps);
             all_validation_issues.extend(validation_issues);
@@ -343,18 +334,7 @@ impl<S: IssueStore> CommandExecutor<S> {
         let config = get_hierarchy_config(&self.storage)?;
 
         for issue in issues {
-            // Build dependency map for this issue
-            let deps: Vec<(String, Vec<String>)> = issue
-                .dependencies
-                .iter()
-                .filter_map(|dep_id| {
-                    self.storage
-                        .load_issue(dep_id)
-                        .ok()
-                        .map(|dep| (dep.id.clone(), dep.labels.clone()))
-                })
-                .collect();
-
+            let deps = self.issue_dependency_labels(issue);
             let validation_issues =
                 detect_validation_issues(&config, &issue.id, &issue.labels, &deps);
 
@@ -402,6 +382,96 @@ impl<S: IssueStore> CommandExecutor<S> {
         Ok(())
     }
 
+    /// Dependency (id, labels) pairs for `issue`, looked up from storage.
+    /// Missing/unresolvable dependencies are silently dropped; they're
+    /// reported separately by `validate_silent`'s broken-reference check.
+    fn issue_dependency_labels(&self, issue: &Issue) -> Vec<(String, Vec<String>)> {
+        issue
+            .dependencies
+            .iter()
+            .filter_map(|dep_id| {
+                self.storage
+                    .load_issue(dep_id)
+                    .ok()
+                    .map(|dep| (dep.id.clone(), dep.labels.clone()))
+            })
+            .collect()
+    }
+
+    /// Resolves the repository's hierarchy config, layering in custom
+    /// rules from `rules_path` (the `--rules <path>` flag) when given.
+    fn hierarchy_config_with_rules(&self, rules_path: Option<&Path>) -> Result<HierarchyConfig> {
+        use crate::hierarchy_templates::get_hierarchy_config;
+
+        let config = get_hierarchy_config(&self.storage)?;
+        match rules_path {
+            Some(path) => {
+                let rules = crate::type_hierarchy::load_custom_rules(path)?;
+                Ok(config.with_custom_rules(rules))
+            }
+            None => Ok(config),
+        }
+    }
+
+    /// Every warning (strategic-label, orphan, and custom-rule) for a
+    /// single issue.
+    fn warnings_for_issue(&self, config: &HierarchyConfig, issue: &Issue) -> Vec<ValidationWarning> {
+        let deps = self.issue_dependency_labels(issue);
+        let mut warnings = validate_strategic_labels(config, issue);
+        warnings.extend(validate_orphans(config, issue));
+        warnings.extend(run_custom_rules(config, issue, &deps));
+        warnings
+    }
+
+    /// Warnings for a single issue (strategic label, orphan, and any
+    /// custom rules configured for the repository). Used after
+    /// `jit issue create` to nudge users toward strategic labels and
+    /// parent associations, without blocking creation.
+    pub fn check_warnings(&self, id: &str) -> Result<Vec<ValidationWarning>> {
+        self.check_warnings_with_rules(id, None)
+    }
+
+    /// As [`check_warnings`](Self::check_warnings), but also runs the
+    /// rules loaded from `rules_path` (`--rules <path>`).
+    pub fn check_warnings_with_rules(
+        &self,
+        id: &str,
+        rules_path: Option<&Path>,
+    ) -> Result<Vec<ValidationWarning>> {
+        let full_id = self.storage.resolve_issue_id(id)?;
+        let issue = self.storage.load_issue(&full_id)?;
+        let config = self.hierarchy_config_with_rules(rules_path)?;
+        Ok(self.warnings_for_issue(&config, &issue))
+    }
+
+    /// Warnings across every issue in the repository, keyed by issue id.
+    /// Used by `jit validate`'s non-fix path.
+    pub fn collect_all_warnings(&self) -> Result<Vec<(String, Vec<ValidationWarning>)>> {
+        self.collect_all_warnings_with_rules(None)
+    }
+
+    /// As [`collect_all_warnings`](Self::collect_all_warnings), but also
+    /// runs the rules loaded from `rules_path` (`--rules <path>`).
+    pub fn collect_all_warnings_with_rules(
+        &self,
+        rules_path: Option<&Path>,
+    ) -> Result<Vec<(String, Vec<ValidationWarning>)>> {
+        let config = self.hierarchy_config_with_rules(rules_path)?;
+        let issues = self.storage.list_issues()?;
+
+        Ok(issues
+            .iter()
+            .filter_map(|issue| {
+                let warnings = self.warnings_for_issue(&config, issue);
+                if warnings.is_empty() {
+                    None
+                } else {
+                    Some((issue.id.clone(), warnings))
+                }
+            })
+            .collect())
+    }
+
     fn validate_document_references(&self, issues: &[Issue]) -> Result<()> {
         use git2::Repository;
 
@@ -17,6 +17,113 @@ pub struct JitConfig {
     pub validation: Option<ValidationConfig>,
     /// Documentation lifecycle configuration (optional).
     pub documentation: Option<DocumentationConfig>,
+    /// User-definable workflow states and transition rules (optional).
+    pub workflow: Option<WorkflowConfigToml>,
+    /// Trusted public keys for gate-approval signature verification (optional).
+    pub signing: Option<SigningConfig>,
+    /// Event-notification sink configuration (optional).
+    pub notifier: Option<NotifierConfigToml>,
+}
+
+/// Event-driven notifier configuration from TOML. See
+/// [`crate::notifier::NotifierConfig`] for the resolved form and delivery
+/// logic.
+#[derive(Debug, Clone, Deserialize)]
+pub struct NotifierConfigToml {
+    /// Configured delivery sinks.
+    pub sinks: Vec<SinkToml>,
+}
+
+/// A single notification sink: which events it fires on, optional
+/// assignee/priority filters, and where it delivers to.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SinkToml {
+    /// Name used when recording delivery outcomes.
+    pub name: String,
+    /// Event type discriminants this sink fires on (matching
+    /// [`crate::domain::Event::get_type`], e.g. "gate_failed"). Empty means
+    /// every event type.
+    #[serde(default)]
+    pub event_types: Vec<String>,
+    /// Only fire for issues with this exact assignee.
+    pub assignee: Option<String>,
+    /// Only fire for issues at or above this priority ("low", "normal",
+    /// "high", "critical").
+    pub min_priority: Option<String>,
+    /// Only fire for `issue_state_changed` events landing on this state
+    /// (e.g. "in_progress", "done", "rejected"). Events with no target
+    /// state (most other event types) never match when this is set.
+    pub to_state: Option<String>,
+    /// Only fire for issues with a label matching this pattern, e.g.
+    /// `"epic:*"` or `"team:platform"` (see [`crate::labels::matches_pattern`]).
+    pub label_pattern: Option<String>,
+    /// Sink kind: "webhook", "command", or "watch".
+    pub kind: String,
+    /// URL to POST the event JSON to; required when `kind = "webhook"`.
+    pub url: Option<String>,
+    /// Shell command the event JSON is piped to; required when
+    /// `kind = "command"`.
+    pub command: Option<String>,
+}
+
+/// Trusted public keys for verifying signed gate approvals, keyed by the
+/// `updated_by` identity that signed them (e.g. "human:alice").
+#[derive(Debug, Clone, Deserialize)]
+pub struct SigningConfig {
+    /// Identity -> hex-encoded Ed25519 public key.
+    pub trusted_keys: HashMap<String, String>,
+    /// Require every required gate to carry a signature verifiable against
+    /// `trusted_keys` before a `Done` transition accepts it as passed (see
+    /// [`crate::domain::Issue::has_unpassed_gates_strict`]). Defaults to
+    /// `false`: an unsigned or tampered `Passed` gate still satisfies the
+    /// transition, matching behavior from before this flag existed.
+    #[serde(default)]
+    pub require_verified_gates: bool,
+}
+
+/// A single allowed transition between two states.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TransitionToml {
+    pub from: String,
+    pub to: String,
+}
+
+/// Workflow state machine configuration from TOML.
+///
+/// Lets a repository define its own ordered state list and transition table
+/// instead of relying solely on the built-in `Backlog -> Ready -> ...` chain.
+/// State names must still correspond to a variant of [`crate::domain::State`];
+/// this narrows the scope to reordering/renaming which transitions are legal
+/// and which states count as open vs closed, rather than inventing entirely
+/// new lifecycle states.
+#[derive(Debug, Clone, Deserialize)]
+pub struct WorkflowConfigToml {
+    /// Ordered list of state names (kebab-case, matching `State::Display`).
+    pub states: Option<Vec<String>>,
+    /// Explicit allowed transitions. If omitted, falls back to the built-in table.
+    pub transitions: Option<Vec<TransitionToml>>,
+    /// States considered "open" (actionable/outstanding).
+    pub open_states: Option<Vec<String>>,
+    /// States considered "closed" (terminal).
+    pub closed_states: Option<Vec<String>>,
+    /// State to auto-transition a backlog issue into once all its
+    /// dependencies are closed (defaults to "ready").
+    pub auto_ready_transition: Option<String>,
+    /// Shell commands to run when an issue crosses a given transition.
+    pub hooks: Option<Vec<TransitionHookToml>>,
+}
+
+/// A hook to run on a specific `from -> to` transition.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TransitionHookToml {
+    pub from: String,
+    pub to: String,
+    /// Shell command to execute; the issue's JSON is piped to its stdin.
+    pub command: String,
+    /// If true, a non-zero exit status blocks (rolls back) the transition.
+    /// Defaults to false (hook runs best-effort, e.g. for notifications).
+    #[serde(default)]
+    pub blocking: bool,
 }
 
 /// Type hierarchy configuration from TOML.
@@ -107,6 +214,9 @@ impl JitConfig {
                 type_hierarchy: None,
                 validation: None,
                 documentation: None,
+                workflow: None,
+                signing: None,
+                notifier: None,
             });
         }
 
@@ -44,6 +44,13 @@ impl ConfigManager {
         }
     }
 
+    /// The `.jit` repository root this manager resolves configuration
+    /// against, for callers (e.g. [`crate::notifier`]) that need to locate
+    /// files alongside `config.toml` rather than parse it.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
     /// Load the JIT configuration from config.toml.
     ///
     /// Returns an empty config (all fields None) if the file doesn't exist.
@@ -112,6 +119,63 @@ impl ConfigManager {
         }
     }
 
+    /// Get the resolved workflow state machine configuration.
+    ///
+    /// Builds a [`crate::workflow::WorkflowConfig`] from the `[workflow]`
+    /// section of `.jit/config.toml`, falling back to the built-in
+    /// transition table for any field left unset.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if config.toml exists but names an unknown state.
+    pub fn get_workflow_config(&self) -> Result<crate::workflow::WorkflowConfig> {
+        let config = self.load()?;
+        crate::workflow::WorkflowConfig::from_toml(config.workflow.as_ref())
+    }
+
+    /// Get the resolved event-notification sink configuration.
+    ///
+    /// Builds a [`crate::notifier::NotifierConfig`] from the `[notifier]`
+    /// section of `.jit/config.toml`; returns a config with no sinks if the
+    /// section is absent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a sink is malformed (unknown kind, missing
+    /// `url`/`command`, or an unrecognized `min_priority`).
+    pub fn get_notifier_config(&self) -> Result<crate::notifier::NotifierConfig> {
+        let config = self.load()?;
+        crate::notifier::NotifierConfig::from_toml(config.notifier.as_ref())
+    }
+
+    /// Get the trusted public keys for verifying signed gate approvals,
+    /// keyed by the `updated_by` identity that holds each key.
+    ///
+    /// Returns an empty map (trusting nothing) if the `[signing]` section
+    /// is absent.
+    pub fn get_trusted_gate_keys(&self) -> Result<HashMap<String, String>> {
+        let config = self.load()?;
+        Ok(config
+            .signing
+            .map(|s| s.trusted_keys)
+            .unwrap_or_default())
+    }
+
+    /// Whether `Done` transitions must verify gate-approval signatures
+    /// against `[signing] trusted_keys` before accepting a `Passed` gate as
+    /// satisfied, per `[signing] require_verified_gates` in
+    /// `.jit/config.toml`.
+    ///
+    /// Returns `false` (the backwards-compatible default) if `[signing]` is
+    /// absent or the flag isn't set.
+    pub fn require_verified_gates(&self) -> Result<bool> {
+        let config = self.load()?;
+        Ok(config
+            .signing
+            .map(|s| s.require_verified_gates)
+            .unwrap_or(false))
+    }
+
     /// Get resolved icons for the current hierarchy.
     ///
     /// Returns a map of type name to icon string. Icons are resolved using the
@@ -0,0 +1,170 @@
+//! Ed25519 signing and SHA-256 content hashing for gate approvals.
+//!
+//! `GatePassed`/`GateFailed` events can carry a detached signature over the
+//! canonical bytes of `(issue_id, gate_key, status, timestamp, updated_by)`,
+//! so a recorded approval can later be checked against a configured set of
+//! trusted public keys instead of being trusted as a free-form string. The
+//! signing key for the local committer is read from the `JIT_GATE_SIGNING_KEY`
+//! environment variable (a hex-encoded 32-byte Ed25519 seed); repositories
+//! without that variable set simply don't sign, and verification treats a
+//! missing signature as unsatisfied only in strict mode.
+
+use crate::domain::GateStatus;
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, Utc};
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use sha2::{Digest, Sha256};
+
+const SIGNING_KEY_ENV_VAR: &str = "JIT_GATE_SIGNING_KEY";
+
+/// Hash the canonical content of a gate approval/rejection.
+///
+/// This is the exact byte sequence that gets signed and later re-verified,
+/// so any change to field order or formatting here must be treated as a
+/// breaking change for previously-signed events.
+pub fn hash_gate_event(
+    issue_id: &str,
+    gate_key: &str,
+    status: GateStatus,
+    timestamp: DateTime<Utc>,
+    updated_by: &Option<String>,
+) -> [u8; 32] {
+    let canonical = format!(
+        "{}\0{}\0{:?}\0{}\0{}",
+        issue_id,
+        gate_key,
+        status,
+        timestamp.to_rfc3339(),
+        updated_by.as_deref().unwrap_or("")
+    );
+    let mut hasher = Sha256::new();
+    hasher.update(canonical.as_bytes());
+    hasher.finalize().into()
+}
+
+/// Sign a gate event hash with the key in `JIT_GATE_SIGNING_KEY`, if set.
+///
+/// Returns `Ok(None)` (not an error) when the environment variable is
+/// unset, since signing is opt-in: unsigned gates remain valid outside of
+/// strict mode.
+pub fn sign_with_env_key(hash: &[u8; 32]) -> Result<Option<String>> {
+    let Ok(seed_hex) = std::env::var(SIGNING_KEY_ENV_VAR) else {
+        return Ok(None);
+    };
+    let seed = decode_hex(&seed_hex).context("JIT_GATE_SIGNING_KEY is not valid hex")?;
+    let seed: [u8; 32] = seed
+        .try_into()
+        .map_err(|_| anyhow!("JIT_GATE_SIGNING_KEY must decode to exactly 32 bytes"))?;
+
+    let signing_key = SigningKey::from_bytes(&seed);
+    let signature = signing_key.sign(hash);
+    Ok(Some(encode_hex(&signature.to_bytes())))
+}
+
+/// Verify a gate event's signature against a trusted public key.
+///
+/// Returns `false` (rather than an error) for any malformed hex or
+/// signature, since a forged/corrupted signature should be reported as a
+/// failed verification, not a crash.
+pub fn verify_signature(hash: &[u8; 32], signature_hex: &str, public_key_hex: &str) -> bool {
+    let Ok(sig_bytes) = decode_hex(signature_hex) else {
+        return false;
+    };
+    let Ok(sig_bytes): Result<[u8; 64], _> = sig_bytes.try_into() else {
+        return false;
+    };
+    let Ok(key_bytes) = decode_hex(public_key_hex) else {
+        return false;
+    };
+    let Ok(key_bytes): Result<[u8; 32], _> = key_bytes.try_into() else {
+        return false;
+    };
+
+    let Ok(verifying_key) = VerifyingKey::from_bytes(&key_bytes) else {
+        return false;
+    };
+    let signature = Signature::from_bytes(&sig_bytes);
+
+    verifying_key.verify(hash, &signature).is_ok()
+}
+
+fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn decode_hex(s: &str) -> Result<Vec<u8>> {
+    if s.len() % 2 != 0 {
+        anyhow::bail!("hex string has odd length");
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).context("invalid hex digit"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_keypair() -> (String, String) {
+        let seed = [7u8; 32];
+        let signing_key = SigningKey::from_bytes(&seed);
+        (
+            encode_hex(&seed),
+            encode_hex(signing_key.verifying_key().as_bytes()),
+        )
+    }
+
+    #[test]
+    fn test_sign_and_verify_roundtrip() {
+        let (seed_hex, public_key_hex) = test_keypair();
+        std::env::set_var(SIGNING_KEY_ENV_VAR, &seed_hex);
+
+        let hash = hash_gate_event(
+            "issue-1",
+            "code-review",
+            GateStatus::Passed,
+            Utc::now(),
+            &Some("human:alice".to_string()),
+        );
+
+        let signature = sign_with_env_key(&hash).unwrap().unwrap();
+        std::env::remove_var(SIGNING_KEY_ENV_VAR);
+
+        assert!(verify_signature(&hash, &signature, &public_key_hex));
+    }
+
+    #[test]
+    fn test_verify_rejects_wrong_key() {
+        let (seed_hex, _) = test_keypair();
+        std::env::set_var(SIGNING_KEY_ENV_VAR, &seed_hex);
+
+        let hash = hash_gate_event(
+            "issue-1",
+            "code-review",
+            GateStatus::Passed,
+            Utc::now(),
+            &Some("human:alice".to_string()),
+        );
+        let signature = sign_with_env_key(&hash).unwrap().unwrap();
+        std::env::remove_var(SIGNING_KEY_ENV_VAR);
+
+        let (_, other_public_key) = {
+            let seed = [9u8; 32];
+            let signing_key = SigningKey::from_bytes(&seed);
+            (
+                encode_hex(&seed),
+                encode_hex(signing_key.verifying_key().as_bytes()),
+            )
+        };
+
+        assert!(!verify_signature(&hash, &signature, &other_public_key));
+    }
+
+    #[test]
+    fn test_no_signing_key_set_returns_none() {
+        std::env::remove_var(SIGNING_KEY_ENV_VAR);
+        let hash = hash_gate_event("issue-1", "gate", GateStatus::Passed, Utc::now(), &None);
+        assert!(sign_with_env_key(&hash).unwrap().is_none());
+    }
+}
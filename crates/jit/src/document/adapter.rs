@@ -7,6 +7,17 @@
 use std::collections::HashSet;
 use std::path::Path;
 
+pub use inventory;
+
+/// Constructor submitted by the `#[doc_adapter]` attribute macro
+///
+/// Wraps a function pointer that builds one boxed adapter instance.
+/// `AdapterRegistry::with_discovered()` collects every submission and
+/// registers the adapters it builds.
+pub struct DocAdapterFactory(pub fn() -> Box<dyn DocFormatAdapter>);
+
+inventory::collect!(DocAdapterFactory);
+
 /// Trait for document format adapters
 ///
 /// Adapters handle format-specific operations like asset scanning and link rewriting.
@@ -43,9 +54,19 @@ pub trait DocFormatAdapter {
 
     /// Rewrite links when documents or assets move
     ///
-    /// Updates links in content when files are moved.
-    /// Currently a stub for Phase 2 implementation.
-    fn rewrite_links(&self, _content: &str, _old_path: &str, _new_path: &str) -> String {
+    /// `doc_path` is the repo-relative path of the document that owns
+    /// `content` (used to resolve its relative links); `old_path` and
+    /// `new_path` are the repo-relative paths a file used to live at and now
+    /// lives at. Any link in `content` that resolves to `old_path` is
+    /// rewritten to a correct relative path to `new_path`; everything else
+    /// is left untouched.
+    fn rewrite_links(
+        &self,
+        _content: &str,
+        _doc_path: &str,
+        _old_path: &str,
+        _new_path: &str,
+    ) -> String {
         unimplemented!("Link rewriting not implemented in Phase 1")
     }
 }
@@ -82,6 +103,35 @@ impl AdapterRegistry {
     pub fn with_builtins() -> Self {
         let mut registry = Self::new();
         registry.register(Box::new(MarkdownAdapter));
+        registry.register(Box::new(AsciiDocAdapter));
+        registry.register(Box::new(RstAdapter));
+        registry
+    }
+
+    /// Create a registry from compile-time discovered adapters
+    ///
+    /// Collects every adapter submitted via the `#[doc_adapter]` attribute
+    /// (see the `jit-macros` crate) instead of requiring a manual
+    /// `register` call per adapter. Adapters are sorted by `id()` so
+    /// `resolve` order is deterministic regardless of link order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jit::document::AdapterRegistry;
+    ///
+    /// let registry = AdapterRegistry::with_discovered();
+    /// assert!(registry.resolve("readme.md", "# Hello").is_some());
+    /// ```
+    pub fn with_discovered() -> Self {
+        let mut factories: Vec<&DocAdapterFactory> =
+            inventory::iter::<DocAdapterFactory>.into_iter().collect();
+        factories.sort_by_key(|factory| (factory.0)().id().to_string());
+
+        let mut registry = Self::new();
+        for factory in factories {
+            registry.register((factory.0)());
+        }
         registry
     }
 
@@ -159,37 +209,284 @@ impl DocFormatAdapter for MarkdownAdapter {
     }
 
     fn scan_assets(&self, content: &str) -> HashSet<String> {
+        use pulldown_cmark::{Event, Parser, Tag};
+
         let mut assets = HashSet::new();
 
-        // Regex patterns for Markdown links and images
-        // Pattern: ![alt](path) or [text](path)
+        // Walking the real event stream (rather than regexing the source)
+        // means reference-style links/images are already resolved to their
+        // `[ref]: url` definition, and anything inside a code span or
+        // fenced/indented code block never surfaces as an event at all.
+        for event in Parser::new(content) {
+            match event {
+                Event::Start(Tag::Link { dest_url, .. })
+                | Event::Start(Tag::Image { dest_url, .. }) => {
+                    insert_asset(&mut assets, &dest_url);
+                }
+                Event::Html(html) | Event::InlineHtml(html) => {
+                    scan_html_asset_attrs(&html, &mut assets);
+                }
+                _ => {}
+            }
+        }
+
+        assets
+    }
+
+    fn rewrite_links(
+        &self,
+        content: &str,
+        doc_path: &str,
+        old_path: &str,
+        new_path: &str,
+    ) -> String {
+        // Same shape as `scan_assets`'s pattern, but captured so we can
+        // splice just the path span rather than rebuild the whole match.
         let link_pattern = regex::Regex::new(r"!?\[(?:[^\]]+)\]\(([^)]+)\)").unwrap();
+        let canonical_old = normalize_link_path(old_path.trim_start_matches('/'));
+        let base_dir = doc_dir_segments(doc_path);
+
+        let mut result = String::with_capacity(content.len());
+        let mut last_end = 0;
 
         for cap in link_pattern.captures_iter(content) {
+            let path_match = cap.get(1).unwrap();
+            let target = path_match.as_str();
+
+            result.push_str(&content[last_end..path_match.start()]);
+            last_end = path_match.end();
+
+            // External URLs, mailto links, and pure anchors are never
+            // touched -- only a real path can point at `old_path`.
+            if target.starts_with('#')
+                || target.starts_with("http://")
+                || target.starts_with("https://")
+                || target.starts_with("mailto:")
+            {
+                result.push_str(target);
+                continue;
+            }
+
+            let (path_part, anchor) = match target.find('#') {
+                Some(pos) => (&target[..pos], &target[pos..]),
+                None => (target, ""),
+            };
+
+            if resolve_repo_relative(path_part, &base_dir) != canonical_old {
+                result.push_str(target);
+                continue;
+            }
+
+            let relative = relative_path(&base_dir, new_path.trim_start_matches('/'));
+            if path_part.starts_with("./") && !relative.starts_with("..") {
+                result.push_str("./");
+            }
+            result.push_str(&relative);
+            result.push_str(anchor);
+        }
+
+        result.push_str(&content[last_end..]);
+        result
+    }
+}
+
+/// Normalize a `/`-joined repo-relative path by resolving `.` and `..`
+/// segments, independent of the host OS path separator.
+fn normalize_link_path(path: &str) -> String {
+    let mut segments: Vec<&str> = Vec::new();
+    for segment in path.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                segments.pop();
+            }
+            s => segments.push(s),
+        }
+    }
+    segments.join("/")
+}
+
+/// Repo-relative directory segments containing `doc_path` (empty for a
+/// document at the repo root).
+pub(crate) fn doc_dir_segments(doc_path: &str) -> Vec<&str> {
+    let mut segments: Vec<&str> = doc_path.split('/').filter(|s| !s.is_empty()).collect();
+    segments.pop();
+    segments
+}
+
+/// Resolve a link target to a canonical repo-relative path: root-relative
+/// (leading `/`) targets resolve from the repo root, everything else
+/// resolves relative to `base_dir`.
+pub(crate) fn resolve_repo_relative(target: &str, base_dir: &[&str]) -> String {
+    if let Some(root_relative) = target.strip_prefix('/') {
+        return normalize_link_path(root_relative);
+    }
+    let joined = if base_dir.is_empty() {
+        target.to_string()
+    } else {
+        format!("{}/{}", base_dir.join("/"), target)
+    };
+    normalize_link_path(&joined)
+}
+
+/// The `/`-joined relative path from `base_dir` (a document's directory
+/// segments) to `target` (a canonical repo-relative path), walking up with
+/// `..` for each part of `base_dir` not shared with `target`.
+fn relative_path(base_dir: &[&str], target: &str) -> String {
+    let target_segments: Vec<&str> = target.split('/').filter(|s| !s.is_empty()).collect();
+    let common = base_dir
+        .iter()
+        .zip(target_segments.iter())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let mut parts: Vec<String> = vec!["..".to_string(); base_dir.len() - common];
+    parts.extend(target_segments[common..].iter().map(|s| s.to_string()));
+    parts.join("/")
+}
+
+/// Apply the common asset-capture rules (trim, skip anchors/mailto, strip a
+/// trailing `#anchor`) and insert the result into `assets` if non-empty.
+fn insert_asset(assets: &mut HashSet<String>, raw: &str) {
+    let path = raw.trim();
+
+    if path.starts_with('#') || path.starts_with("mailto:") {
+        return;
+    }
+
+    let path_without_anchor = match path.find('#') {
+        Some(pos) => &path[..pos],
+        None => path,
+    };
+
+    if !path_without_anchor.is_empty() {
+        assets.insert(path_without_anchor.to_string());
+    }
+}
+
+/// Extract `src="..."`/`href="..."` (or single-quoted) targets from a raw
+/// HTML chunk emitted by the CommonMark parser (e.g. `<img src="...">`,
+/// `<a href="...">`) and feed each one through [`insert_asset`].
+fn scan_html_asset_attrs(html: &str, assets: &mut HashSet<String>) {
+    let attr_pattern = regex::Regex::new(r#"(?:src|href)\s*=\s*["']([^"']+)["']"#).unwrap();
+
+    for cap in attr_pattern.captures_iter(html) {
+        if let Some(path_match) = cap.get(1) {
+            insert_asset(assets, path_match.as_str());
+        }
+    }
+}
+
+/// AsciiDoc format adapter
+///
+/// Supports AsciiDoc files with `.adoc` and `.asciidoc` extensions.
+/// Extracts image, link, and include references from AsciiDoc macro syntax.
+///
+/// # Supported Syntax
+///
+/// - Block images: `image::path.png[Alt]`
+/// - Inline images: `image:path.png[Alt]`
+/// - Links: `link:path[text]`
+/// - Includes: `include::path.adoc[]`
+///
+/// # Excluded
+///
+/// - Anchor-only targets: `link:#section[Section]`
+/// - Mailto links: `link:mailto:user@example.com[Email]`
+pub struct AsciiDocAdapter;
+
+impl DocFormatAdapter for AsciiDocAdapter {
+    fn id(&self) -> &str {
+        "asciidoc"
+    }
+
+    fn supports_path(&self, path: &str) -> bool {
+        let path_obj = Path::new(path);
+        if let Some(ext) = path_obj.extension() {
+            let ext_str = ext.to_string_lossy().to_lowercase();
+            ext_str == "adoc" || ext_str == "asciidoc"
+        } else {
+            false
+        }
+    }
+
+    fn detect(&self, content: &str) -> bool {
+        content.lines().any(|line| line.starts_with("= ")) || content.lines().any(is_attribute_line)
+    }
+
+    fn scan_assets(&self, content: &str) -> HashSet<String> {
+        let mut assets = HashSet::new();
+
+        // image::path[] / image:path[] (block and inline), link:path[], and
+        // include::path[] macros all share the same "name, colons, path, [" shape.
+        let macro_pattern = regex::Regex::new(r"(?:image::?|link:|include::)([^\[\s]+)\[").unwrap();
+
+        for cap in macro_pattern.captures_iter(content) {
             if let Some(path_match) = cap.get(1) {
-                let path = path_match.as_str().trim();
+                insert_asset(&mut assets, path_match.as_str());
+            }
+        }
 
-                // Skip anchor-only links
-                if path.starts_with('#') {
-                    continue;
-                }
+        assets
+    }
+}
 
-                // Skip mailto links
-                if path.starts_with("mailto:") {
-                    continue;
-                }
+/// A `:name:` or `:name: value` AsciiDoc document attribute line.
+fn is_attribute_line(line: &str) -> bool {
+    let trimmed = line.trim();
+    trimmed.len() > 1 && trimmed.starts_with(':') && trimmed[1..].contains(':')
+}
+
+/// reStructuredText format adapter
+///
+/// Supports reStructuredText files with the `.rst` extension.
+/// Extracts image, figure, and hyperlink target references.
+///
+/// # Supported Syntax
+///
+/// - Image directives: `.. image:: path.png`
+/// - Figure directives: `.. figure:: path.png`
+/// - Inline hyperlink targets: `` `text <path>`_ ``
+///
+/// # Excluded
+///
+/// - Anchor-only targets: `` `Section <#section>`_ ``
+/// - Mailto links: `` `Email <mailto:user@example.com>`_ ``
+pub struct RstAdapter;
 
-                // Remove anchor fragments
-                let path_without_anchor = if let Some(pos) = path.find('#') {
-                    &path[..pos]
-                } else {
-                    path
-                };
+impl DocFormatAdapter for RstAdapter {
+    fn id(&self) -> &str {
+        "rst"
+    }
 
-                // Add non-empty paths (including external URLs)
-                if !path_without_anchor.is_empty() {
-                    assets.insert(path_without_anchor.to_string());
-                }
+    fn supports_path(&self, path: &str) -> bool {
+        let path_obj = Path::new(path);
+        if let Some(ext) = path_obj.extension() {
+            ext.to_string_lossy().to_lowercase() == "rst"
+        } else {
+            false
+        }
+    }
+
+    fn detect(&self, content: &str) -> bool {
+        content.lines().any(is_title_underline) || content.lines().any(is_directive_line)
+    }
+
+    fn scan_assets(&self, content: &str) -> HashSet<String> {
+        let mut assets = HashSet::new();
+
+        let directive_pattern =
+            regex::Regex::new(r"(?m)^\s*\.\.\s+(?:image|figure)::\s*(\S+)").unwrap();
+        for cap in directive_pattern.captures_iter(content) {
+            if let Some(path_match) = cap.get(1) {
+                insert_asset(&mut assets, path_match.as_str());
+            }
+        }
+
+        let target_pattern = regex::Regex::new(r"`[^`]*<([^>]+)>`_").unwrap();
+        for cap in target_pattern.captures_iter(content) {
+            if let Some(path_match) = cap.get(1) {
+                insert_asset(&mut assets, path_match.as_str());
             }
         }
 
@@ -197,6 +494,23 @@ impl DocFormatAdapter for MarkdownAdapter {
     }
 }
 
+/// A section title underline made entirely of `=` characters, e.g. the line
+/// under `My Title` in:
+/// ```text
+/// My Title
+/// ========
+/// ```
+fn is_title_underline(line: &str) -> bool {
+    let trimmed = line.trim();
+    trimmed.len() >= 3 && trimmed.chars().all(|c| c == '=')
+}
+
+/// A `.. directive::` block marker line (e.g. `.. note::`, `.. image::`).
+fn is_directive_line(line: &str) -> bool {
+    let trimmed = line.trim_start();
+    trimmed.starts_with(".. ") && trimmed.contains("::")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -322,6 +636,161 @@ Regular relative: ![Other](./local.png)
         assert!(assets.contains("./local.png"));
     }
 
+    #[test]
+    fn test_markdown_scan_assets_reference_style_links() {
+        let adapter = MarkdownAdapter;
+        let content = r#"
+See the [Guide][guide] and ![Logo][logo-ref].
+
+[guide]: ../docs/guide.md
+[logo-ref]: ./assets/logo.png
+        "#;
+
+        let assets = adapter.scan_assets(content);
+        assert_eq!(assets.len(), 2);
+        assert!(assets.contains("../docs/guide.md"));
+        assert!(assets.contains("./assets/logo.png"));
+    }
+
+    #[test]
+    fn test_markdown_scan_assets_shortcut_reference() {
+        let adapter = MarkdownAdapter;
+        let content = r#"
+Check the [guide] for details.
+
+[guide]: ../docs/guide.md
+        "#;
+
+        let assets = adapter.scan_assets(content);
+        assert_eq!(assets.len(), 1);
+        assert!(assets.contains("../docs/guide.md"));
+    }
+
+    #[test]
+    fn test_markdown_scan_assets_inline_html_image() {
+        let adapter = MarkdownAdapter;
+        let content = r#"
+<img src="./assets/banner.png" alt="Banner">
+<a href="other.md">Other</a>
+        "#;
+
+        let assets = adapter.scan_assets(content);
+        assert_eq!(assets.len(), 2);
+        assert!(assets.contains("./assets/banner.png"));
+        assert!(assets.contains("other.md"));
+    }
+
+    #[test]
+    fn test_markdown_scan_assets_excludes_fenced_code() {
+        let adapter = MarkdownAdapter;
+        let content = r#"
+Real link: [Doc](doc.md)
+
+```markdown
+Not a real link: [Fake](fake.md)
+```
+        "#;
+
+        let assets = adapter.scan_assets(content);
+        assert_eq!(assets.len(), 1);
+        assert!(assets.contains("doc.md"));
+    }
+
+    #[test]
+    fn test_markdown_scan_assets_excludes_code_span() {
+        let adapter = MarkdownAdapter;
+        let content = "Inline code `[Fake](fake.md)` but a real [Doc](doc.md)";
+
+        let assets = adapter.scan_assets(content);
+        assert_eq!(assets.len(), 1);
+        assert!(assets.contains("doc.md"));
+    }
+
+    #[test]
+    fn test_rewrite_links_same_directory() {
+        let adapter = MarkdownAdapter;
+        let content = "See [Guide](./logo.png) for details.";
+
+        let rewritten = adapter.rewrite_links(
+            content,
+            "docs/guide.md",
+            "docs/logo.png",
+            "docs/assets/logo.png",
+        );
+
+        assert_eq!(rewritten, "See [Guide](./assets/logo.png) for details.");
+    }
+
+    #[test]
+    fn test_rewrite_links_walks_up_shared_prefix() {
+        let adapter = MarkdownAdapter;
+        let content = "![Logo](../assets/logo.png)";
+
+        let rewritten =
+            adapter.rewrite_links(content, "docs/guide.md", "assets/logo.png", "media/logo.png");
+
+        assert_eq!(rewritten, "![Logo](../media/logo.png)");
+    }
+
+    #[test]
+    fn test_rewrite_links_preserves_anchor() {
+        let adapter = MarkdownAdapter;
+        let content = "[Section](./guide.md#install)";
+
+        let rewritten = adapter.rewrite_links(
+            content,
+            "docs/index.md",
+            "docs/guide.md",
+            "docs/setup/guide.md",
+        );
+
+        assert_eq!(rewritten, "[Section](./setup/guide.md#install)");
+    }
+
+    #[test]
+    fn test_rewrite_links_ignores_non_matching_path() {
+        let adapter = MarkdownAdapter;
+        let content = "[Other](other.md)";
+
+        let rewritten =
+            adapter.rewrite_links(content, "docs/index.md", "docs/guide.md", "docs/setup/guide.md");
+
+        assert_eq!(rewritten, content);
+    }
+
+    #[test]
+    fn test_rewrite_links_skips_external_and_mailto_and_anchors() {
+        let adapter = MarkdownAdapter;
+        let content = "[Site](https://example.com/guide.md) [Email](mailto:guide.md) [Top](#guide.md)";
+
+        let rewritten =
+            adapter.rewrite_links(content, "docs/index.md", "guide.md", "setup/guide.md");
+
+        assert_eq!(rewritten, content);
+    }
+
+    #[test]
+    fn test_rewrite_links_no_match_returns_content_unchanged() {
+        let adapter = MarkdownAdapter;
+        let content = "# Document\n\nNo links here.";
+
+        let rewritten =
+            adapter.rewrite_links(content, "docs/index.md", "docs/guide.md", "docs/setup/guide.md");
+
+        assert_eq!(rewritten, content);
+    }
+
+    #[test]
+    fn test_rewrite_links_without_leading_dot_slash_stays_bare() {
+        let adapter = MarkdownAdapter;
+        let content = "[Guide](guide.md)";
+
+        let rewritten =
+            adapter.rewrite_links(content, "docs/index.md", "docs/guide.md", "docs/setup/guide.md");
+
+        assert_eq!(rewritten, "[Guide](setup/guide.md)");
+    }
+
     #[test]
     fn test_registry_new_is_empty() {
         let registry = AdapterRegistry::new();
@@ -331,7 +800,7 @@ Regular relative: ![Other](./local.png)
     #[test]
     fn test_registry_with_builtins() {
         let registry = AdapterRegistry::with_builtins();
-        assert_eq!(registry.adapters.len(), 1);
+        assert_eq!(registry.adapters.len(), 3);
     }
 
     #[test]
@@ -366,8 +835,188 @@ Regular relative: ![Other](./local.png)
     #[test]
     fn test_registry_default() {
         let registry = AdapterRegistry::default();
-        assert_eq!(registry.adapters.len(), 1);
+        assert_eq!(registry.adapters.len(), 3);
         let adapter = registry.resolve("test.md", "").unwrap();
         assert_eq!(adapter.id(), "markdown");
     }
+
+    #[test]
+    fn test_asciidoc_adapter_id() {
+        let adapter = AsciiDocAdapter;
+        assert_eq!(adapter.id(), "asciidoc");
+    }
+
+    #[test]
+    fn test_asciidoc_supports_path() {
+        let adapter = AsciiDocAdapter;
+
+        assert!(adapter.supports_path("README.adoc"));
+        assert!(adapter.supports_path("guide.asciidoc"));
+        assert!(adapter.supports_path("FILE.ADOC"));
+
+        assert!(!adapter.supports_path("file.md"));
+        assert!(!adapter.supports_path("file.rst"));
+        assert!(!adapter.supports_path("file"));
+    }
+
+    #[test]
+    fn test_asciidoc_detect() {
+        let adapter = AsciiDocAdapter;
+
+        assert!(adapter.detect("= Document Title\n\nSome text"));
+        assert!(adapter.detect(":toc:\n:author: Jane Doe\n\nText"));
+
+        assert!(!adapter.detect("Plain text without asciidoc markers"));
+        assert!(!adapter.detect(""));
+    }
+
+    #[test]
+    fn test_asciidoc_scan_assets_images_and_links() {
+        let adapter = AsciiDocAdapter;
+        let content = r#"
+= Document
+
+image::assets/logo.png[Logo]
+image:inline-icon.png[Icon]
+link:../docs/guide.adoc[Guide]
+include::shared/intro.adoc[]
+        "#;
+
+        let assets = adapter.scan_assets(content);
+        assert_eq!(assets.len(), 4);
+        assert!(assets.contains("assets/logo.png"));
+        assert!(assets.contains("inline-icon.png"));
+        assert!(assets.contains("../docs/guide.adoc"));
+        assert!(assets.contains("shared/intro.adoc"));
+    }
+
+    #[test]
+    fn test_asciidoc_scan_assets_excludes_anchors_and_mailto() {
+        let adapter = AsciiDocAdapter;
+        let content = r#"
+link:#section[Section]
+link:mailto:user@example.com[Email]
+link:other.adoc[Other]
+        "#;
+
+        let assets = adapter.scan_assets(content);
+        assert_eq!(assets.len(), 1);
+        assert!(assets.contains("other.adoc"));
+    }
+
+    #[test]
+    fn test_rst_adapter_id() {
+        let adapter = RstAdapter;
+        assert_eq!(adapter.id(), "rst");
+    }
+
+    #[test]
+    fn test_rst_supports_path() {
+        let adapter = RstAdapter;
+
+        assert!(adapter.supports_path("README.rst"));
+        assert!(adapter.supports_path("FILE.RST"));
+
+        assert!(!adapter.supports_path("file.md"));
+        assert!(!adapter.supports_path("file.adoc"));
+        assert!(!adapter.supports_path("file"));
+    }
+
+    #[test]
+    fn test_rst_detect() {
+        let adapter = RstAdapter;
+
+        assert!(adapter.detect("My Title\n========\n\nSome text"));
+        assert!(adapter.detect(".. note::\n\n   Some note"));
+
+        assert!(!adapter.detect("Plain text without rst markers"));
+        assert!(!adapter.detect(""));
+    }
+
+    #[test]
+    fn test_rst_scan_assets_directives_and_targets() {
+        let adapter = RstAdapter;
+        let content = r#"
+My Title
+========
+
+.. image:: assets/logo.png
+.. figure:: diagrams/flow.png
+
+See the `guide <../docs/guide.rst>`_ for details.
+        "#;
+
+        let assets = adapter.scan_assets(content);
+        assert_eq!(assets.len(), 3);
+        assert!(assets.contains("assets/logo.png"));
+        assert!(assets.contains("diagrams/flow.png"));
+        assert!(assets.contains("../docs/guide.rst"));
+    }
+
+    #[test]
+    fn test_rst_scan_assets_excludes_anchors_and_mailto() {
+        let adapter = RstAdapter;
+        let content = r#"
+See `Section <#section>`_ or `Email <mailto:user@example.com>`_ or `Doc <other.rst>`_.
+        "#;
+
+        let assets = adapter.scan_assets(content);
+        assert_eq!(assets.len(), 1);
+        assert!(assets.contains("other.rst"));
+    }
+
+    #[test]
+    fn test_registry_resolves_asciidoc_and_rst_by_extension() {
+        let registry = AdapterRegistry::with_builtins();
+
+        let adapter = registry.resolve("guide.adoc", "Plain text").unwrap();
+        assert_eq!(adapter.id(), "asciidoc");
+
+        let adapter = registry.resolve("guide.rst", "Plain text").unwrap();
+        assert_eq!(adapter.id(), "rst");
+    }
+
+    #[test]
+    fn test_registry_resolves_asciidoc_and_rst_by_content() {
+        let registry = AdapterRegistry::with_builtins();
+
+        let adapter = registry
+            .resolve("README", "= Document Title\n\nText")
+            .unwrap();
+        assert_eq!(adapter.id(), "asciidoc");
+
+        let adapter = registry
+            .resolve("README", "Title\n=====\n\nText")
+            .unwrap();
+        assert_eq!(adapter.id(), "rst");
+    }
+
+    #[jit_macros::doc_adapter]
+    struct DummyDiscoveredAdapter;
+
+    impl DocFormatAdapter for DummyDiscoveredAdapter {
+        fn id(&self) -> &str {
+            "dummy-discovered"
+        }
+
+        fn supports_path(&self, path: &str) -> bool {
+            path.ends_with(".dummy")
+        }
+
+        fn detect(&self, _content: &str) -> bool {
+            false
+        }
+
+        fn scan_assets(&self, _content: &str) -> HashSet<String> {
+            HashSet::new()
+        }
+    }
+
+    #[test]
+    fn test_with_discovered_resolves_attribute_registered_adapter() {
+        let registry = AdapterRegistry::with_discovered();
+
+        let adapter = registry.resolve("notes.dummy", "").unwrap();
+        assert_eq!(adapter.id(), "dummy-discovered");
+    }
 }
@@ -8,6 +8,13 @@ mod adapter;
 mod assets;
 mod link_validator;
 
-pub use adapter::{AdapterRegistry, DocFormatAdapter, MarkdownAdapter};
+pub use adapter::{
+    inventory, AdapterRegistry, AsciiDocAdapter, DocAdapterFactory, DocFormatAdapter,
+    MarkdownAdapter, RstAdapter,
+};
+// Path-resolution internals shared with the query evaluator's `docs:*`
+// health checks, which need to resolve a scanned asset path the same way
+// `rewrite_links` does without duplicating the walk-up logic.
+pub(crate) use adapter::{doc_dir_segments, resolve_repo_relative};
 pub use assets::{Asset, AssetScanner, AssetType};
 pub use link_validator::{InternalLink, LinkType, LinkValidationResult, LinkValidator};
@@ -24,6 +24,26 @@ pub enum State {
     Done,
     /// No longer relevant
     Archived,
+    /// Unstarted work whose due date has passed
+    Overdue,
+    /// Closed without being completed (e.g. exhausted its retry budget)
+    Rejected,
+}
+
+impl std::fmt::Display for State {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            State::Backlog => "backlog",
+            State::Ready => "ready",
+            State::InProgress => "in-progress",
+            State::Gated => "gated",
+            State::Done => "done",
+            State::Archived => "archived",
+            State::Overdue => "overdue",
+            State::Rejected => "rejected",
+        };
+        write!(f, "{}", name)
+    }
 }
 
 /// Issue priority level
@@ -61,6 +81,11 @@ pub struct GateState {
     pub updated_by: Option<String>,
     /// When the gate was last updated
     pub updated_at: DateTime<Utc>,
+    /// Detached Ed25519 signature (hex) from the `GatePassed`/`GateFailed`
+    /// event that produced this state, if the repository signs approvals.
+    /// See [`Issue::has_unpassed_gates_strict`].
+    #[serde(default)]
+    pub signature: Option<String>,
 }
 
 /// An issue representing a unit of work
@@ -90,6 +115,18 @@ pub struct Issue {
     pub documents: Vec<DocumentReference>,
     /// Labels for categorization and hierarchy (format: "namespace:value")
     pub labels: Vec<String>,
+    /// Optional due date; past-due unstarted issues are swept to `Overdue`
+    #[serde(default)]
+    pub due_date: Option<DateTime<Utc>>,
+    /// Optional SLA window (seconds) before the due date at which the issue
+    /// should be flagged as nearing its deadline
+    #[serde(default)]
+    pub sla_window_secs: Option<i64>,
+    /// Estimated duration of the work itself, in seconds. Used by
+    /// `jit critical-path` for earliest/latest-start scheduling; issues
+    /// without an estimate are treated as zero-duration milestones.
+    #[serde(default)]
+    pub estimated_duration_secs: Option<i64>,
 }
 
 impl Issue {
@@ -108,6 +145,9 @@ impl Issue {
             context: HashMap::new(),
             documents: Vec::new(),
             labels: Vec::new(),
+            due_date: None,
+            sla_window_secs: None,
+            estimated_duration_secs: None,
         }
     }
 
@@ -132,6 +172,40 @@ impl Issue {
             .any(|gate_key| !matches!(self.gates_status.get(gate_key), Some(gate_state) if gate_state.status == GateStatus::Passed))
     }
 
+    /// Strict variant of [`Issue::has_unpassed_gates`] that additionally
+    /// requires a `Passed` gate to carry a signature verifiable against
+    /// `trusted_keys` (identity -> hex Ed25519 public key, see
+    /// [`crate::config::SigningConfig`]). A gate signed by an identity with
+    /// no entry in `trusted_keys`, or with a signature that fails to
+    /// verify, counts as unsatisfied — this closes the hole where anyone
+    /// can flip a gate to `Passed` by editing the event log directly.
+    pub fn has_unpassed_gates_strict(&self, trusted_keys: &HashMap<String, String>) -> bool {
+        self.gates_required.iter().any(|gate_key| {
+            let Some(gate_state) = self.gates_status.get(gate_key) else {
+                return true;
+            };
+            if gate_state.status != GateStatus::Passed {
+                return true;
+            }
+            let (Some(signature), Some(updated_by)) =
+                (&gate_state.signature, &gate_state.updated_by)
+            else {
+                return true;
+            };
+            let Some(public_key) = trusted_keys.get(updated_by) else {
+                return true;
+            };
+            let hash = crate::crypto::hash_gate_event(
+                &self.id,
+                gate_key,
+                gate_state.status,
+                gate_state.updated_at,
+                &gate_state.updated_by,
+            );
+            !crate::crypto::verify_signature(&hash, signature, public_key)
+        })
+    }
+
     /// Check if this issue should auto-transition to Ready state
     /// A Backlog issue transitions to Ready when it becomes unblocked
     pub fn should_auto_transition_to_ready(
@@ -226,6 +300,45 @@ pub struct Gate {
     pub auto: bool,
     /// Example of how to integrate with this gate
     pub example_integration: Option<String>,
+    /// Shell command `jit gate run` executes to evaluate this gate when
+    /// `auto` is set. Falls back to `example_integration` if unset, so
+    /// existing gate definitions that only documented an example command
+    /// keep working without edits.
+    #[serde(default)]
+    pub command: Option<String>,
+}
+
+impl Gate {
+    /// Resolve the command `jit gate run` should execute for this gate,
+    /// preferring the explicit `command` field over `example_integration`.
+    pub fn resolved_command(&self) -> Option<&str> {
+        self.command.as_deref().or(self.example_integration.as_deref())
+    }
+}
+
+/// Outcome an agent reports back for an issue it was working on, via
+/// `jit report`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReportStatus {
+    /// The work is finished.
+    Done,
+    /// The agent gave up; the issue is requeued or rejected depending on
+    /// how many attempts remain.
+    Failed,
+    /// The agent is still working; informational only.
+    Progress,
+}
+
+impl std::fmt::Display for ReportStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            ReportStatus::Done => "done",
+            ReportStatus::Failed => "failed",
+            ReportStatus::Progress => "progress",
+        };
+        write!(f, "{}", name)
+    }
 }
 
 /// System event types for audit log
@@ -281,6 +394,11 @@ pub enum Event {
         gate_key: String,
         /// Who marked it as passed
         updated_by: Option<String>,
+        /// Detached Ed25519 signature (hex) over the canonical approval
+        /// content, proving `updated_by` actually authored this approval.
+        /// `None` when the repository has no signing key configured.
+        #[serde(default)]
+        signature: Option<String>,
     },
     /// A quality gate failed
     GateFailed {
@@ -294,6 +412,9 @@ pub enum Event {
         gate_key: String,
         /// Who marked it as failed
         updated_by: Option<String>,
+        /// Detached Ed25519 signature (hex), see [`Event::GatePassed`].
+        #[serde(default)]
+        signature: Option<String>,
     },
     /// Issue was completed
     IssueCompleted {
@@ -317,6 +438,22 @@ pub enum Event {
         /// Reason for release
         reason: String,
     },
+    /// An agent reported an outcome for an issue it was working on
+    IssueReported {
+        /// Event ID
+        id: String,
+        /// Issue that was reported on
+        issue_id: String,
+        /// When this occurred
+        timestamp: DateTime<Utc>,
+        /// Reported outcome
+        status: ReportStatus,
+        /// Optional free-form detail (e.g. an error message)
+        message: Option<String>,
+        /// Which attempt this is, starting at 1; only meaningful for
+        /// `ReportStatus::Failed`
+        attempt: u32,
+    },
 }
 
 impl Event {
@@ -353,24 +490,36 @@ impl Event {
     }
 
     /// Create a gate passed event
-    pub fn new_gate_passed(issue_id: String, gate_key: String, updated_by: Option<String>) -> Self {
+    pub fn new_gate_passed(
+        issue_id: String,
+        gate_key: String,
+        updated_by: Option<String>,
+        signature: Option<String>,
+    ) -> Self {
         Event::GatePassed {
             id: Uuid::new_v4().to_string(),
             issue_id,
             timestamp: Utc::now(),
             gate_key,
             updated_by,
+            signature,
         }
     }
 
     /// Create a gate failed event
-    pub fn new_gate_failed(issue_id: String, gate_key: String, updated_by: Option<String>) -> Self {
+    pub fn new_gate_failed(
+        issue_id: String,
+        gate_key: String,
+        updated_by: Option<String>,
+        signature: Option<String>,
+    ) -> Self {
         Event::GateFailed {
             id: Uuid::new_v4().to_string(),
             issue_id,
             timestamp: Utc::now(),
             gate_key,
             updated_by,
+            signature,
         }
     }
 
@@ -394,6 +543,51 @@ impl Event {
         }
     }
 
+    /// Create an issue reported event
+    pub fn new_issue_reported(
+        issue_id: String,
+        status: ReportStatus,
+        message: Option<String>,
+        attempt: u32,
+    ) -> Self {
+        Event::IssueReported {
+            id: Uuid::new_v4().to_string(),
+            issue_id,
+            timestamp: Utc::now(),
+            status,
+            message,
+            attempt,
+        }
+    }
+
+    /// Get this event's own ID (stable across replays and git-notes sync).
+    pub fn get_id(&self) -> &str {
+        match self {
+            Event::IssueCreated { id, .. } => id,
+            Event::IssueClaimed { id, .. } => id,
+            Event::IssueStateChanged { id, .. } => id,
+            Event::GatePassed { id, .. } => id,
+            Event::GateFailed { id, .. } => id,
+            Event::IssueCompleted { id, .. } => id,
+            Event::IssueReleased { id, .. } => id,
+            Event::IssueReported { id, .. } => id,
+        }
+    }
+
+    /// Get the timestamp this event occurred at.
+    pub fn get_timestamp(&self) -> DateTime<Utc> {
+        match self {
+            Event::IssueCreated { timestamp, .. } => *timestamp,
+            Event::IssueClaimed { timestamp, .. } => *timestamp,
+            Event::IssueStateChanged { timestamp, .. } => *timestamp,
+            Event::GatePassed { timestamp, .. } => *timestamp,
+            Event::GateFailed { timestamp, .. } => *timestamp,
+            Event::IssueCompleted { timestamp, .. } => *timestamp,
+            Event::IssueReleased { timestamp, .. } => *timestamp,
+            Event::IssueReported { timestamp, .. } => *timestamp,
+        }
+    }
+
     /// Get the issue ID associated with this event
     pub fn get_issue_id(&self) -> &str {
         match self {
@@ -404,6 +598,7 @@ impl Event {
             Event::GateFailed { issue_id, .. } => issue_id,
             Event::IssueCompleted { issue_id, .. } => issue_id,
             Event::IssueReleased { issue_id, .. } => issue_id,
+            Event::IssueReported { issue_id, .. } => issue_id,
         }
     }
 
@@ -417,6 +612,7 @@ impl Event {
             Event::GateFailed { .. } => "gate_failed",
             Event::IssueCompleted { .. } => "issue_completed",
             Event::IssueReleased { .. } => "issue_released",
+            Event::IssueReported { .. } => "issue_reported",
         }
     }
 }
@@ -499,6 +695,7 @@ mod tests {
                 status: GateStatus::Pending,
                 updated_by: None,
                 updated_at: Utc::now(),
+            signature: None,
             },
         );
 
@@ -520,6 +717,7 @@ mod tests {
                 status: GateStatus::Failed,
                 updated_by: Some("human:reviewer".to_string()),
                 updated_at: Utc::now(),
+            signature: None,
             },
         );
 
@@ -541,6 +739,7 @@ mod tests {
                 status: GateStatus::Passed,
                 updated_by: Some("human:reviewer".to_string()),
                 updated_at: Utc::now(),
+            signature: None,
             },
         );
 
@@ -655,6 +854,7 @@ mod tests {
                 status: GateStatus::Passed,
                 updated_by: Some("human:reviewer".to_string()),
                 updated_at: Utc::now(),
+            signature: None,
             },
         );
 
@@ -672,6 +872,7 @@ mod tests {
                 status: GateStatus::Pending,
                 updated_by: None,
                 updated_at: Utc::now(),
+            signature: None,
             },
         );
 
@@ -689,6 +890,7 @@ mod tests {
                 status: GateStatus::Failed,
                 updated_by: Some("ci:tests".to_string()),
                 updated_at: Utc::now(),
+            signature: None,
             },
         );
 
@@ -7,6 +7,7 @@
 //! - Result storage for audit trail
 
 use crate::domain::{GateChecker, GateRunResult, GateRunStatus, GateStage};
+use crate::problem_matcher::{Diagnostic, DiagnosticSeverity, ProblemMatcher};
 use anyhow::{Context, Result};
 use std::path::Path;
 use std::process::{Command, Stdio};
@@ -15,12 +16,17 @@ use std::time::{Duration, Instant};
 /// Execute a gate checker and return the result
 ///
 /// This function runs the specified checker and captures all execution details
-/// including exit code, output, timing, and git context if available.
+/// including exit code, output, timing, and git context if available. If
+/// `matchers` is non-empty, the combined stdout/stderr is fed through each
+/// matcher and any `error`-severity [`Diagnostic`] forces the gate to fail
+/// even when the command itself exited `0` (e.g. a linter that only warns
+/// via exit code but still emits `error:`-prefixed output).
 pub fn execute_gate_checker(
     gate_key: &str,
     issue_id: &str,
     stage: GateStage,
     checker: &GateChecker,
+    matchers: &[ProblemMatcher],
     working_dir: &Path,
 ) -> Result<GateRunResult> {
     let start_time = Instant::now();
@@ -42,8 +48,19 @@ pub fn execute_gate_checker(
     let duration = start_time.elapsed();
     let completed_at = chrono::Utc::now();
 
-    // Determine status from exit code
+    let diagnostics = run_problem_matchers(
+        matchers,
+        &execution_result.stdout,
+        &execution_result.stderr,
+    )?;
+    let has_error_diagnostics = diagnostics
+        .iter()
+        .any(|d| d.severity == DiagnosticSeverity::Error);
+
+    // Determine status from exit code, then let a matcher-reported error
+    // downgrade an otherwise-passing run.
     let status = match execution_result.exit_code {
+        Some(0) if has_error_diagnostics => GateRunStatus::Failed,
         Some(0) => GateRunStatus::Passed,
         Some(_) => GateRunStatus::Failed,
         None => GateRunStatus::Error, // Timeout or signal
@@ -67,9 +84,25 @@ pub fn execute_gate_checker(
         command: execution_result.command,
         by: Some("auto:executor".to_string()),
         message: None,
+        diagnostics,
     })
 }
 
+/// Run every matcher over the checker's combined stdout and stderr,
+/// concatenating whatever diagnostics each one extracts.
+fn run_problem_matchers(
+    matchers: &[ProblemMatcher],
+    stdout: &str,
+    stderr: &str,
+) -> Result<Vec<Diagnostic>> {
+    let combined = format!("{}\n{}", stdout, stderr);
+    let mut diagnostics = Vec::new();
+    for matcher in matchers {
+        diagnostics.extend(matcher.run(&combined)?);
+    }
+    Ok(diagnostics)
+}
+
 /// Result of command execution
 struct CommandExecutionResult {
     exit_code: Option<i32>,
@@ -224,6 +257,7 @@ mod tests {
             "test-issue",
             GateStage::Postcheck,
             &checker,
+            &[],
             &temp_dir,
         );
 
@@ -249,6 +283,7 @@ mod tests {
             "test-issue",
             GateStage::Postcheck,
             &checker,
+            &[],
             &temp_dir,
         );
 
@@ -273,6 +308,7 @@ mod tests {
             "test-issue",
             GateStage::Postcheck,
             &checker,
+            &[],
             &temp_dir,
         );
 
@@ -316,6 +352,7 @@ mod tests {
             "test-issue",
             GateStage::Postcheck,
             &checker,
+            &[],
             &temp_dir,
         );
 
@@ -324,4 +361,73 @@ mod tests {
         assert_eq!(result.status, GateRunStatus::Passed);
         assert!(result.stdout.contains("test_value"));
     }
+
+    #[test]
+    fn test_matcher_error_diagnostic_fails_gate_despite_zero_exit() {
+        use crate::problem_matcher::MatcherPattern;
+
+        // A linter that prints "error: ..." but (mis)reports success via
+        // its exit code -- the matcher should still fail the gate.
+        let checker = GateChecker::Exec {
+            command: "echo 'error: something is wrong'; exit 0".to_string(),
+            timeout_seconds: 10,
+            working_dir: None,
+            env: HashMap::new(),
+        };
+        let matcher = ProblemMatcher {
+            owner: "demo-linter".to_string(),
+            severity: DiagnosticSeverity::Error,
+            pattern: vec![MatcherPattern {
+                regexp: r"^error: (.+)$".to_string(),
+                message: Some(1),
+                file: None,
+                line: None,
+                column: None,
+                severity: None,
+                code: None,
+            }],
+        };
+
+        let temp_dir = std::env::temp_dir();
+        let result = execute_gate_checker(
+            "test-gate",
+            "test-issue",
+            GateStage::Postcheck,
+            &checker,
+            &[matcher],
+            &temp_dir,
+        )
+        .unwrap();
+
+        assert_eq!(result.status, GateRunStatus::Failed);
+        assert_eq!(result.exit_code, Some(0));
+        assert_eq!(result.diagnostics.len(), 1);
+        assert_eq!(
+            result.diagnostics[0].message.as_deref(),
+            Some("something is wrong")
+        );
+    }
+
+    #[test]
+    fn test_no_matchers_leaves_diagnostics_empty() {
+        let checker = GateChecker::Exec {
+            command: "true".to_string(),
+            timeout_seconds: 10,
+            working_dir: None,
+            env: HashMap::new(),
+        };
+
+        let temp_dir = std::env::temp_dir();
+        let result = execute_gate_checker(
+            "test-gate",
+            "test-issue",
+            GateStage::Postcheck,
+            &checker,
+            &[],
+            &temp_dir,
+        )
+        .unwrap();
+
+        assert!(result.diagnostics.is_empty());
+    }
 }
@@ -3,6 +3,10 @@
 //! This module provides functionality for managing gate presets - pre-configured
 //! bundles of quality gates that can be applied to issues. Presets encode best
 //! practices for common workflows (e.g., rust-tdd, minimal) and reduce setup time.
+//!
+//! A custom preset can also `extends` another preset instead of redefining
+//! every gate from scratch, optionally dropping inherited gates via
+//! `unset_gates` -- see [`PresetManager`] for how the chain is resolved.
 
 mod builtin;
 mod manager;
@@ -11,10 +15,18 @@ pub use builtin::BuiltinPresets;
 pub use manager::PresetManager;
 
 use crate::domain::{Gate, GateChecker, GateMode, GateStage};
+use crate::problem_matcher::ProblemMatcher;
 use anyhow::{anyhow, Result};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+/// Key under which a [`GateTemplate`]'s problem matchers are stashed in the
+/// converted [`Gate`]'s `reserved` bucket -- `Gate` doesn't carry a
+/// first-class `matchers` field, so this rides along in the same
+/// forward-compatibility pocket reserved for schema fields not yet
+/// promoted onto `Gate` itself.
+const RESERVED_MATCHERS_KEY: &str = "matchers";
+
 /// A gate template within a preset
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct GateTemplate {
@@ -31,11 +43,26 @@ pub struct GateTemplate {
     /// Checker configuration for automated gates
     #[serde(skip_serializing_if = "Option::is_none")]
     pub checker: Option<GateChecker>,
+    /// Problem matchers that turn this gate's checker output into
+    /// structured [`Diagnostic`](crate::problem_matcher::Diagnostic)s. The
+    /// checker fails the gate if any `error`-severity diagnostic is
+    /// produced, regardless of the command's exit code.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub matchers: Vec<ProblemMatcher>,
 }
 
 impl GateTemplate {
     /// Convert template to full Gate definition
     pub fn to_gate(&self) -> Gate {
+        let mut reserved = HashMap::new();
+        if !self.matchers.is_empty() {
+            reserved.insert(
+                RESERVED_MATCHERS_KEY.to_string(),
+                serde_json::to_value(&self.matchers)
+                    .expect("ProblemMatcher is always JSON-serializable"),
+            );
+        }
+
         Gate {
             version: 1,
             key: self.key.clone(),
@@ -44,15 +71,26 @@ impl GateTemplate {
             stage: self.stage,
             mode: self.mode,
             checker: self.checker.clone(),
-            reserved: HashMap::new(),
+            reserved,
             auto: self.mode == GateMode::Auto,
             example_integration: None,
         }
     }
 }
 
+/// Read the problem matchers a preset attached to a [`Gate`] via
+/// [`GateTemplate::to_gate`]'s `reserved` bucket. Returns an empty list (not
+/// an error) if the gate carries none or the stored value doesn't parse, so
+/// a gate with no/garbled matchers just runs without diagnostic parsing.
+pub fn matchers_for_gate(gate: &Gate) -> Vec<ProblemMatcher> {
+    gate.reserved
+        .get(RESERVED_MATCHERS_KEY)
+        .and_then(|value| serde_json::from_value(value.clone()).ok())
+        .unwrap_or_default()
+}
+
 /// A preset definition containing multiple gate templates
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct GatePresetDefinition {
     /// Unique preset name
     pub name: String,
@@ -60,6 +98,17 @@ pub struct GatePresetDefinition {
     pub description: String,
     /// Gates included in this preset
     pub gates: Vec<GateTemplate>,
+    /// Name of a parent preset to build on, Mercurial-config-style: the
+    /// parent's gates are loaded first, then [`unset_gates`](Self::unset_gates)
+    /// removes inherited keys, then this preset's own `gates` overlay on top
+    /// (matching on [`GateTemplate::key`], replacing existing entries and
+    /// appending new ones).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extends: Option<String>,
+    /// Gate keys to drop from the inherited (`extends`) gate list before
+    /// this preset's own `gates` are overlaid.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub unset_gates: Vec<String>,
 }
 
 impl GatePresetDefinition {
@@ -73,7 +122,11 @@ impl GatePresetDefinition {
             return Err(anyhow!("Preset description cannot be empty"));
         }
 
-        if self.gates.is_empty() {
+        // A preset that `extends` another may legitimately contribute no
+        // gates of its own (e.g. one that only `unset_gates` an inherited
+        // key) -- the empty-gates check only applies to a preset meant to
+        // stand alone.
+        if self.gates.is_empty() && self.extends.is_none() {
             return Err(anyhow!("Preset must contain at least one gate"));
         }
 
@@ -138,6 +191,7 @@ mod tests {
                 working_dir: None,
                 env: HashMap::new(),
             }),
+            matchers: Vec::new(),
         };
 
         let gate = template.to_gate();
@@ -160,7 +214,9 @@ mod tests {
                 stage: GateStage::Postcheck,
                 mode: GateMode::Manual,
                 checker: None,
+                matchers: Vec::new(),
             }],
+            ..Default::default()
         };
 
         assert!(preset.validate().is_ok());
@@ -172,6 +228,7 @@ mod tests {
             name: "".to_string(),
             description: "A test preset".to_string(),
             gates: vec![],
+            ..Default::default()
         };
 
         assert!(preset.validate().is_err());
@@ -183,6 +240,7 @@ mod tests {
             name: "test".to_string(),
             description: "".to_string(),
             gates: vec![],
+            ..Default::default()
         };
 
         assert!(preset.validate().is_err());
@@ -194,6 +252,7 @@ mod tests {
             name: "test".to_string(),
             description: "A test preset".to_string(),
             gates: vec![],
+            ..Default::default()
         };
 
         assert!(preset.validate().is_err());
@@ -211,7 +270,9 @@ mod tests {
                 stage: GateStage::Postcheck,
                 mode: GateMode::Auto,
                 checker: None, // Missing checker
+                matchers: Vec::new(),
             }],
+            ..Default::default()
         };
 
         let result = preset.validate();
@@ -235,6 +296,7 @@ mod tests {
                     stage: GateStage::Postcheck,
                     mode: GateMode::Manual,
                     checker: None,
+                    matchers: Vec::new(),
                 },
                 GateTemplate {
                     key: "gate1".to_string(), // Duplicate
@@ -243,8 +305,10 @@ mod tests {
                     stage: GateStage::Postcheck,
                     mode: GateMode::Manual,
                     checker: None,
+                    matchers: Vec::new(),
                 },
             ],
+            ..Default::default()
         };
 
         let result = preset.validate();
@@ -281,4 +345,58 @@ mod tests {
         assert_eq!(preset.gates.len(), 1);
         assert_eq!(preset.gates[0].key, "tests");
     }
+
+    #[test]
+    fn test_to_gate_round_trips_matchers_through_reserved() {
+        use crate::problem_matcher::{DiagnosticSeverity, MatcherPattern};
+
+        let template = GateTemplate {
+            key: "clippy".to_string(),
+            title: "Clippy lints pass".to_string(),
+            description: "No clippy warnings allowed".to_string(),
+            stage: GateStage::Postcheck,
+            mode: GateMode::Auto,
+            checker: Some(GateChecker::Exec {
+                command: "cargo clippy --all-targets -- -D warnings".to_string(),
+                timeout_seconds: 120,
+                working_dir: None,
+                env: HashMap::new(),
+            }),
+            matchers: vec![ProblemMatcher {
+                owner: "clippy".to_string(),
+                severity: DiagnosticSeverity::Error,
+                pattern: vec![MatcherPattern {
+                    regexp: r"^error: (.+)$".to_string(),
+                    message: Some(1),
+                    file: None,
+                    line: None,
+                    column: None,
+                    severity: None,
+                    code: None,
+                }],
+            }],
+        };
+
+        let gate = template.to_gate();
+        let matchers = matchers_for_gate(&gate);
+
+        assert_eq!(matchers.len(), 1);
+        assert_eq!(matchers[0].owner, "clippy");
+    }
+
+    #[test]
+    fn test_matchers_for_gate_defaults_to_empty_when_absent() {
+        let gate = GateTemplate {
+            key: "fmt".to_string(),
+            title: "Formatted".to_string(),
+            description: "cargo fmt --check".to_string(),
+            stage: GateStage::Postcheck,
+            mode: GateMode::Manual,
+            checker: None,
+            matchers: Vec::new(),
+        }
+        .to_gate();
+
+        assert!(matchers_for_gate(&gate).is_empty());
+    }
 }
@@ -26,6 +26,7 @@ impl BuiltinPresets {
                     stage: GateStage::Precheck,
                     mode: GateMode::Manual,
                     checker: None,
+                    matchers: Vec::new(),
                 },
                 GateTemplate {
                     key: "tests".to_string(),
@@ -39,6 +40,7 @@ impl BuiltinPresets {
                         working_dir: None,
                         env: HashMap::new(),
                     }),
+                    matchers: Vec::new(),
                 },
                 GateTemplate {
                     key: "clippy".to_string(),
@@ -52,6 +54,7 @@ impl BuiltinPresets {
                         working_dir: None,
                         env: HashMap::new(),
                     }),
+                    matchers: Vec::new(),
                 },
                 GateTemplate {
                     key: "fmt".to_string(),
@@ -65,6 +68,7 @@ impl BuiltinPresets {
                         working_dir: None,
                         env: HashMap::new(),
                     }),
+                    matchers: Vec::new(),
                 },
                 GateTemplate {
                     key: "code-review".to_string(),
@@ -73,8 +77,10 @@ impl BuiltinPresets {
                     stage: GateStage::Postcheck,
                     mode: GateMode::Manual,
                     checker: None,
+                    matchers: Vec::new(),
                 },
             ],
+            ..Default::default()
         };
 
         // minimal preset
@@ -88,7 +94,9 @@ impl BuiltinPresets {
                 stage: GateStage::Postcheck,
                 mode: GateMode::Manual,
                 checker: None,
+                matchers: Vec::new(),
             }],
+            ..Default::default()
         };
 
         // Validate presets
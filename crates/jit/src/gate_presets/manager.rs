@@ -1,6 +1,6 @@
 //! Preset manager for loading and managing gate presets
 
-use super::{BuiltinPresets, GatePresetDefinition, PresetInfo};
+use super::{BuiltinPresets, GatePresetDefinition, GateTemplate, PresetInfo};
 use anyhow::{anyhow, Context, Result};
 use std::collections::HashMap;
 use std::fs;
@@ -23,9 +23,96 @@ impl PresetManager {
             presets.insert(name, preset);
         }
 
+        // Resolve `extends`/`unset_gates` chains once all files (builtin and
+        // custom) are loaded, so a custom preset can extend another custom
+        // preset regardless of which file defined it.
+        let presets = Self::resolve_extends(presets)?;
+
         Ok(Self { jit_root, presets })
     }
 
+    /// Flatten every preset's `extends`/`unset_gates` chain into a
+    /// self-contained gate list, Mercurial-config-style: load the parent's
+    /// gates, drop the keys in `unset_gates`, then overlay this preset's own
+    /// `gates` (replacing by [`GateTemplate::key`], appending anything new).
+    fn resolve_extends(
+        presets: HashMap<String, GatePresetDefinition>,
+    ) -> Result<HashMap<String, GatePresetDefinition>> {
+        let mut resolved = HashMap::new();
+        for name in presets.keys().cloned().collect::<Vec<_>>() {
+            Self::resolve_one(&name, &presets, &mut resolved, &mut Vec::new())?;
+        }
+        Ok(resolved)
+    }
+
+    /// Resolve a single preset's chain, memoizing into `resolved` and
+    /// tracking the in-progress `chain` of names to detect `extends` cycles.
+    fn resolve_one(
+        name: &str,
+        source: &HashMap<String, GatePresetDefinition>,
+        resolved: &mut HashMap<String, GatePresetDefinition>,
+        chain: &mut Vec<String>,
+    ) -> Result<GatePresetDefinition> {
+        if let Some(existing) = resolved.get(name) {
+            return Ok(existing.clone());
+        }
+
+        if chain.contains(&name.to_string()) {
+            let mut cycle = chain.clone();
+            cycle.push(name.to_string());
+            return Err(anyhow!(
+                "Cycle detected in preset 'extends' chain: {}",
+                cycle.join(" -> ")
+            ));
+        }
+
+        let preset = source
+            .get(name)
+            .ok_or_else(|| anyhow!("Preset not found: {}", name))?
+            .clone();
+
+        let merged = match &preset.extends {
+            None => preset,
+            Some(parent_name) => {
+                if !source.contains_key(parent_name) {
+                    return Err(anyhow!(
+                        "Preset '{}' extends unknown preset '{}'",
+                        name,
+                        parent_name
+                    ));
+                }
+
+                chain.push(name.to_string());
+                let parent = Self::resolve_one(parent_name, source, resolved, chain)?;
+                chain.pop();
+
+                let mut gates: Vec<GateTemplate> = parent
+                    .gates
+                    .into_iter()
+                    .filter(|gate| !preset.unset_gates.contains(&gate.key))
+                    .collect();
+
+                for gate in preset.gates {
+                    match gates.iter_mut().find(|existing| existing.key == gate.key) {
+                        Some(existing) => *existing = gate,
+                        None => gates.push(gate),
+                    }
+                }
+
+                GatePresetDefinition {
+                    name: preset.name,
+                    description: preset.description,
+                    gates,
+                    extends: None,
+                    unset_gates: Vec::new(),
+                }
+            }
+        };
+
+        resolved.insert(name.to_string(), merged.clone());
+        Ok(merged)
+    }
+
     /// Load custom presets from .jit/config/gate-presets/
     fn load_custom_presets(jit_root: &Path) -> Result<HashMap<String, GatePresetDefinition>> {
         let presets_dir = jit_root.join("config").join("gate-presets");
@@ -118,7 +205,9 @@ mod tests {
                 stage: GateStage::Postcheck,
                 mode: GateMode::Manual,
                 checker: None,
+                matchers: Vec::new(),
             }],
+            ..Default::default()
         };
 
         let json = serde_json::to_string_pretty(&preset)?;
@@ -127,6 +216,24 @@ mod tests {
         Ok(())
     }
 
+    fn write_preset_file(dir: &Path, preset: &GatePresetDefinition) -> Result<()> {
+        let json = serde_json::to_string_pretty(preset)?;
+        fs::write(dir.join(format!("{}.json", preset.name)), json)?;
+        Ok(())
+    }
+
+    fn test_gate(key: &str) -> GateTemplate {
+        GateTemplate {
+            key: key.to_string(),
+            title: key.to_string(),
+            description: format!("Gate {}", key),
+            stage: GateStage::Postcheck,
+            mode: GateMode::Manual,
+            checker: None,
+            matchers: Vec::new(),
+        }
+    }
+
     #[test]
     fn test_load_builtin_only() {
         let temp_dir = TempDir::new().unwrap();
@@ -242,4 +349,171 @@ mod tests {
         let manager = PresetManager::new(temp_dir.path().to_path_buf()).unwrap();
         assert_eq!(manager.presets.len(), 2); // Only builtins
     }
+
+    #[test]
+    fn test_extends_overrides_and_adds_gates() {
+        let temp_dir = TempDir::new().unwrap();
+        let presets_dir = temp_dir.path().join("config").join("gate-presets");
+        fs::create_dir_all(&presets_dir).unwrap();
+
+        write_preset_file(
+            &presets_dir,
+            &GatePresetDefinition {
+                name: "child".to_string(),
+                description: "Thin delta on rust-tdd".to_string(),
+                gates: vec![
+                    test_gate("tests"), // overrides the inherited "tests" gate
+                    test_gate("extra"), // new gate not in the parent
+                ],
+                extends: Some("rust-tdd".to_string()),
+                unset_gates: vec![],
+            },
+        )
+        .unwrap();
+
+        let manager = PresetManager::new(temp_dir.path().to_path_buf()).unwrap();
+        let child = manager.get_preset("child").unwrap();
+
+        // 5 inherited keys, "tests" overridden in place, "extra" appended.
+        assert_eq!(child.gates.len(), 6);
+        let tests_gate = child.gates.iter().find(|g| g.key == "tests").unwrap();
+        assert_eq!(tests_gate.mode, GateMode::Manual); // overridden value, not the auto original
+        assert!(child.gates.iter().any(|g| g.key == "extra"));
+    }
+
+    #[test]
+    fn test_extends_unset_removes_inherited_gate() {
+        let temp_dir = TempDir::new().unwrap();
+        let presets_dir = temp_dir.path().join("config").join("gate-presets");
+        fs::create_dir_all(&presets_dir).unwrap();
+
+        write_preset_file(
+            &presets_dir,
+            &GatePresetDefinition {
+                name: "lean-tdd".to_string(),
+                description: "rust-tdd without the fmt gate".to_string(),
+                gates: vec![],
+                extends: Some("rust-tdd".to_string()),
+                unset_gates: vec!["fmt".to_string()],
+            },
+        )
+        .unwrap();
+
+        let manager = PresetManager::new(temp_dir.path().to_path_buf()).unwrap();
+        let lean = manager.get_preset("lean-tdd").unwrap();
+
+        assert_eq!(lean.gates.len(), 4);
+        assert!(!lean.gates.iter().any(|g| g.key == "fmt"));
+    }
+
+    #[test]
+    fn test_extends_multi_level_chain() {
+        let temp_dir = TempDir::new().unwrap();
+        let presets_dir = temp_dir.path().join("config").join("gate-presets");
+        fs::create_dir_all(&presets_dir).unwrap();
+
+        write_preset_file(
+            &presets_dir,
+            &GatePresetDefinition {
+                name: "base".to_string(),
+                description: "Base layer".to_string(),
+                gates: vec![test_gate("a"), test_gate("b")],
+                extends: None,
+                unset_gates: vec![],
+            },
+        )
+        .unwrap();
+        write_preset_file(
+            &presets_dir,
+            &GatePresetDefinition {
+                name: "middle".to_string(),
+                description: "Middle layer".to_string(),
+                gates: vec![test_gate("c")],
+                extends: Some("base".to_string()),
+                unset_gates: vec!["a".to_string()],
+            },
+        )
+        .unwrap();
+        write_preset_file(
+            &presets_dir,
+            &GatePresetDefinition {
+                name: "leaf".to_string(),
+                description: "Leaf layer".to_string(),
+                gates: vec![test_gate("d")],
+                extends: Some("middle".to_string()),
+                unset_gates: vec![],
+            },
+        )
+        .unwrap();
+
+        let manager = PresetManager::new(temp_dir.path().to_path_buf()).unwrap();
+        let leaf = manager.get_preset("leaf").unwrap();
+
+        let keys: std::collections::HashSet<_> =
+            leaf.gates.iter().map(|g| g.key.as_str()).collect();
+        assert_eq!(keys, ["b", "c", "d"].into_iter().collect());
+    }
+
+    #[test]
+    fn test_extends_missing_parent_errors() {
+        let temp_dir = TempDir::new().unwrap();
+        let presets_dir = temp_dir.path().join("config").join("gate-presets");
+        fs::create_dir_all(&presets_dir).unwrap();
+
+        write_preset_file(
+            &presets_dir,
+            &GatePresetDefinition {
+                name: "orphan".to_string(),
+                description: "Extends a preset that doesn't exist".to_string(),
+                gates: vec![],
+                extends: Some("does-not-exist".to_string()),
+                unset_gates: vec![],
+            },
+        )
+        .unwrap();
+
+        let result = PresetManager::new(temp_dir.path().to_path_buf());
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("extends unknown preset"));
+    }
+
+    #[test]
+    fn test_extends_two_node_cycle_errors() {
+        let temp_dir = TempDir::new().unwrap();
+        let presets_dir = temp_dir.path().join("config").join("gate-presets");
+        fs::create_dir_all(&presets_dir).unwrap();
+
+        write_preset_file(
+            &presets_dir,
+            &GatePresetDefinition {
+                name: "first".to_string(),
+                description: "Extends second".to_string(),
+                gates: vec![],
+                extends: Some("second".to_string()),
+                unset_gates: vec![],
+            },
+        )
+        .unwrap();
+        write_preset_file(
+            &presets_dir,
+            &GatePresetDefinition {
+                name: "second".to_string(),
+                description: "Extends first".to_string(),
+                gates: vec![],
+                extends: Some("first".to_string()),
+                unset_gates: vec![],
+            },
+        )
+        .unwrap();
+
+        let result = PresetManager::new(temp_dir.path().to_path_buf());
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Cycle detected"));
+    }
 }
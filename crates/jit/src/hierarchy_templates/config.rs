@@ -0,0 +1,327 @@
+//! Include-able, split hierarchy/label configuration files.
+//!
+//! [`get_hierarchy_config`](super::get_hierarchy_config) reads the entire
+//! type hierarchy and label associations from a single namespaces file. That
+//! doesn't scale once a taxonomy is large enough to want a shared base file
+//! plus small per-repo overrides, so this module adds a Mercurial-config-style
+//! `includes` directive: the namespaces file may list other JSON config files
+//! (resolved relative to `.jit/config/`) whose `type_hierarchy` and
+//! `label_associations` are merged in, earlier includes first, with the
+//! namespaces file's own keys applied last so it always wins. A companion
+//! `unset` list lets a layer remove specific label associations an earlier
+//! layer contributed, mirroring [`GatePresetDefinition::unset_gates`](crate::gate_presets::GatePresetDefinition).
+
+use crate::type_hierarchy::HierarchyConfig;
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// On-disk shape of the namespaces file and any file it `includes`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct NamespacesFile {
+    /// Type name to hierarchy rank.
+    #[serde(default)]
+    type_hierarchy: HashMap<String, u8>,
+    /// Type name to membership label namespace.
+    #[serde(default)]
+    label_associations: HashMap<String, String>,
+    /// Other config files to merge in first, named relative to
+    /// `.jit/config/` and applied in list order.
+    #[serde(default)]
+    includes: Vec<String>,
+    /// Label-association type names to drop after this file's own keys
+    /// (and its includes) have been merged in.
+    #[serde(default)]
+    unset: Vec<String>,
+}
+
+/// Tracks which file contributed each merged key, keyed the same way as
+/// [`HierarchyConfig`]'s maps. Exposed for a future `jit config origin`
+/// query; unused today beyond that.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConfigOrigin {
+    pub type_hierarchy: HashMap<String, PathBuf>,
+    pub label_associations: HashMap<String, PathBuf>,
+}
+
+#[derive(Debug, Default)]
+struct Merged {
+    type_hierarchy: HashMap<String, u8>,
+    label_associations: HashMap<String, String>,
+    origin: ConfigOrigin,
+}
+
+/// Load the hierarchy configuration starting from `namespaces_path`,
+/// resolving its `includes` (relative to `config_dir`) before applying its
+/// own keys. Falls back to [`HierarchyConfig::default()`] if
+/// `namespaces_path` doesn't exist.
+pub fn load_hierarchy_config_with_includes(
+    namespaces_path: &Path,
+    config_dir: &Path,
+) -> Result<(HierarchyConfig, ConfigOrigin)> {
+    if !namespaces_path.exists() {
+        return Ok((HierarchyConfig::default(), ConfigOrigin::default()));
+    }
+
+    let mut merged = Merged::default();
+    let mut chain = Vec::new();
+    merge_file(namespaces_path, config_dir, &mut merged, &mut chain)?;
+
+    let config = HierarchyConfig::new(merged.type_hierarchy, merged.label_associations)
+        .map_err(|e| anyhow!("Invalid hierarchy config: {}", e))?;
+    Ok((config, merged.origin))
+}
+
+/// Merge `path` into `merged`: its `includes` first (in order), then its own
+/// `type_hierarchy`/`label_associations` on top, then its `unset` list.
+/// `chain` holds the canonicalized paths currently being resolved, so a
+/// cycle back to a file already on the chain is rejected instead of
+/// recursing forever.
+fn merge_file(
+    path: &Path,
+    config_dir: &Path,
+    merged: &mut Merged,
+    chain: &mut Vec<PathBuf>,
+) -> Result<()> {
+    if !path.exists() {
+        return Err(anyhow!("Config file not found: {:?}", path));
+    }
+
+    let canonical = path
+        .canonicalize()
+        .with_context(|| format!("Failed to resolve config file: {:?}", path))?;
+    if chain.contains(&canonical) {
+        let mut cycle: Vec<String> = chain.iter().map(|p| format!("{:?}", p)).collect();
+        cycle.push(format!("{:?}", canonical));
+        return Err(anyhow!(
+            "Cycle detected in config 'includes' chain: {}",
+            cycle.join(" -> ")
+        ));
+    }
+
+    let content =
+        fs::read_to_string(path).with_context(|| format!("Failed to read config file: {:?}", path))?;
+    let file: NamespacesFile = serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse config file: {:?}", path))?;
+
+    chain.push(canonical);
+    for include in &file.includes {
+        let include_path = config_dir.join(include);
+        merge_file(&include_path, config_dir, merged, chain)?;
+    }
+    chain.pop();
+
+    for (type_name, rank) in file.type_hierarchy {
+        merged.type_hierarchy.insert(type_name.clone(), rank);
+        merged
+            .origin
+            .type_hierarchy
+            .insert(type_name, path.to_path_buf());
+    }
+    for (type_name, namespace) in file.label_associations {
+        merged
+            .label_associations
+            .insert(type_name.clone(), namespace);
+        merged
+            .origin
+            .label_associations
+            .insert(type_name, path.to_path_buf());
+    }
+
+    for type_name in &file.unset {
+        merged.label_associations.remove(type_name);
+        merged.origin.label_associations.remove(type_name);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write_json(path: &Path, value: &serde_json::Value) {
+        fs::write(path, serde_json::to_string_pretty(value).unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_missing_namespaces_file_falls_back_to_default() {
+        let temp = TempDir::new().unwrap();
+        let namespaces_path = temp.path().join("label-namespaces.json");
+        let config_dir = temp.path().join("config");
+
+        let (config, origin) =
+            load_hierarchy_config_with_includes(&namespaces_path, &config_dir).unwrap();
+
+        assert_eq!(config, HierarchyConfig::default());
+        assert!(origin.type_hierarchy.is_empty());
+    }
+
+    #[test]
+    fn test_namespaces_file_without_includes() {
+        let temp = TempDir::new().unwrap();
+        let namespaces_path = temp.path().join("label-namespaces.json");
+        let config_dir = temp.path().join("config");
+        fs::create_dir_all(&config_dir).unwrap();
+
+        write_json(
+            &namespaces_path,
+            &serde_json::json!({
+                "type_hierarchy": {"epic": 1, "task": 2},
+                "label_associations": {"epic": "epic"}
+            }),
+        );
+
+        let (config, origin) =
+            load_hierarchy_config_with_includes(&namespaces_path, &config_dir).unwrap();
+
+        assert_eq!(config.get_level("task"), Some(2));
+        assert_eq!(
+            origin.type_hierarchy.get("task"),
+            Some(&namespaces_path)
+        );
+    }
+
+    #[test]
+    fn test_include_is_merged_and_overridden_by_own_keys() {
+        let temp = TempDir::new().unwrap();
+        let namespaces_path = temp.path().join("label-namespaces.json");
+        let config_dir = temp.path().join("config");
+        fs::create_dir_all(&config_dir).unwrap();
+
+        write_json(
+            &config_dir.join("base.json"),
+            &serde_json::json!({
+                "type_hierarchy": {"epic": 1, "task": 2},
+                "label_associations": {"epic": "epic"}
+            }),
+        );
+        write_json(
+            &namespaces_path,
+            &serde_json::json!({
+                "includes": ["base.json"],
+                "type_hierarchy": {"task": 2, "subtask": 3},
+                "label_associations": {}
+            }),
+        );
+
+        let (config, origin) =
+            load_hierarchy_config_with_includes(&namespaces_path, &config_dir).unwrap();
+
+        assert_eq!(config.get_level("epic"), Some(1));
+        assert_eq!(config.get_level("subtask"), Some(3));
+        assert_eq!(
+            origin.type_hierarchy.get("epic"),
+            Some(&config_dir.join("base.json"))
+        );
+        assert_eq!(
+            origin.type_hierarchy.get("task"),
+            Some(&namespaces_path) // overridden, so attributed to the namespaces file
+        );
+    }
+
+    #[test]
+    fn test_later_include_overrides_earlier_include() {
+        let temp = TempDir::new().unwrap();
+        let namespaces_path = temp.path().join("label-namespaces.json");
+        let config_dir = temp.path().join("config");
+        fs::create_dir_all(&config_dir).unwrap();
+
+        write_json(
+            &config_dir.join("a.json"),
+            &serde_json::json!({"label_associations": {"epic": "from-a"}}),
+        );
+        write_json(
+            &config_dir.join("b.json"),
+            &serde_json::json!({"label_associations": {"epic": "from-b"}}),
+        );
+        write_json(
+            &namespaces_path,
+            &serde_json::json!({
+                "includes": ["a.json", "b.json"],
+                "type_hierarchy": {"epic": 1, "task": 2}
+            }),
+        );
+
+        let (_config, origin) =
+            load_hierarchy_config_with_includes(&namespaces_path, &config_dir).unwrap();
+
+        assert_eq!(
+            origin.label_associations.get("epic"),
+            Some(&config_dir.join("b.json"))
+        );
+    }
+
+    #[test]
+    fn test_unset_removes_inherited_label_association() {
+        let temp = TempDir::new().unwrap();
+        let namespaces_path = temp.path().join("label-namespaces.json");
+        let config_dir = temp.path().join("config");
+        fs::create_dir_all(&config_dir).unwrap();
+
+        write_json(
+            &config_dir.join("base.json"),
+            &serde_json::json!({
+                "type_hierarchy": {"epic": 1, "task": 2},
+                "label_associations": {"epic": "epic"}
+            }),
+        );
+        write_json(
+            &namespaces_path,
+            &serde_json::json!({
+                "includes": ["base.json"],
+                "unset": ["epic"]
+            }),
+        );
+
+        let (config, origin) =
+            load_hierarchy_config_with_includes(&namespaces_path, &config_dir).unwrap();
+
+        assert_eq!(config.get_membership_namespace("epic"), None);
+        assert!(!origin.label_associations.contains_key("epic"));
+    }
+
+    #[test]
+    fn test_missing_include_errors_with_file_name() {
+        let temp = TempDir::new().unwrap();
+        let namespaces_path = temp.path().join("label-namespaces.json");
+        let config_dir = temp.path().join("config");
+        fs::create_dir_all(&config_dir).unwrap();
+
+        write_json(
+            &namespaces_path,
+            &serde_json::json!({"includes": ["does-not-exist.json"]}),
+        );
+
+        let result = load_hierarchy_config_with_includes(&namespaces_path, &config_dir);
+        assert!(result.is_err());
+        let message = result.unwrap_err().to_string();
+        assert!(message.contains("not found"));
+        assert!(message.contains("does-not-exist.json"));
+    }
+
+    #[test]
+    fn test_include_cycle_errors() {
+        let temp = TempDir::new().unwrap();
+        let namespaces_path = temp.path().join("label-namespaces.json");
+        let config_dir = temp.path().join("config");
+        fs::create_dir_all(&config_dir).unwrap();
+
+        write_json(
+            &config_dir.join("a.json"),
+            &serde_json::json!({"includes": ["b.json"]}),
+        );
+        write_json(
+            &config_dir.join("b.json"),
+            &serde_json::json!({"includes": ["a.json"]}),
+        );
+        write_json(&namespaces_path, &serde_json::json!({"includes": ["a.json"]}));
+
+        let result = load_hierarchy_config_with_includes(&namespaces_path, &config_dir);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("Cycle detected"));
+    }
+}
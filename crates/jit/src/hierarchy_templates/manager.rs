@@ -0,0 +1,291 @@
+//! Hierarchy template manager for loading and managing hierarchy templates
+
+use super::{HierarchyTemplate, HierarchyTemplateInfo};
+use anyhow::{anyhow, Context, Result};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Manages hierarchy templates from builtin and custom sources
+pub struct HierarchyTemplateManager {
+    jit_root: PathBuf,
+    templates: HashMap<String, HierarchyTemplate>,
+}
+
+impl HierarchyTemplateManager {
+    /// Create a new hierarchy template manager
+    pub fn new(jit_root: PathBuf) -> Result<Self> {
+        let mut templates: HashMap<String, HierarchyTemplate> = HierarchyTemplate::all()
+            .into_iter()
+            .map(|template| (template.name.clone(), template))
+            .collect();
+
+        // Load custom templates and override builtin with same name
+        let custom_templates = Self::load_custom_templates(&jit_root)?;
+        for (name, template) in custom_templates {
+            templates.insert(name, template);
+        }
+
+        Ok(Self {
+            jit_root,
+            templates,
+        })
+    }
+
+    /// Load custom templates from .jit/config/hierarchy-templates/
+    fn load_custom_templates(jit_root: &Path) -> Result<HashMap<String, HierarchyTemplate>> {
+        let templates_dir = jit_root.join("config").join("hierarchy-templates");
+        let mut templates = HashMap::new();
+
+        // If directory doesn't exist, return empty map (not an error)
+        if !templates_dir.exists() {
+            return Ok(templates);
+        }
+
+        // Read all JSON files in the directory
+        let entries = fs::read_dir(&templates_dir).with_context(|| {
+            format!(
+                "Failed to read hierarchy templates directory: {:?}",
+                templates_dir
+            )
+        })?;
+
+        for entry in entries {
+            let entry = entry?;
+            let path = entry.path();
+
+            // Skip non-JSON files
+            if path.extension() != Some(std::ffi::OsStr::new("json")) {
+                continue;
+            }
+
+            // Load and parse template
+            let content = fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read hierarchy template file: {:?}", path))?;
+
+            let template: HierarchyTemplate = serde_json::from_str(&content)
+                .with_context(|| format!("Failed to parse hierarchy template file: {:?}", path))?;
+
+            // Validate template
+            template
+                .validate()
+                .with_context(|| format!("Invalid hierarchy template in file: {:?}", path))?;
+
+            templates.insert(template.name.clone(), template);
+        }
+
+        Ok(templates)
+    }
+
+    /// Get a template by name
+    pub fn get(&self, name: &str) -> Result<&HierarchyTemplate> {
+        self.templates
+            .get(name)
+            .ok_or_else(|| anyhow!("Hierarchy template not found: {}", name))
+    }
+
+    /// List all available templates
+    pub fn list(&self) -> Vec<HierarchyTemplateInfo> {
+        let builtin_names: Vec<String> = HierarchyTemplate::all()
+            .into_iter()
+            .map(|template| template.name)
+            .collect();
+
+        self.templates
+            .values()
+            .map(|template| HierarchyTemplateInfo {
+                name: template.name.clone(),
+                description: template.description.clone(),
+                level_count: template.hierarchy.len(),
+                builtin: builtin_names.contains(&template.name),
+            })
+            .collect()
+    }
+
+    /// Check if a template exists
+    pub fn has(&self, name: &str) -> bool {
+        self.templates.contains_key(name)
+    }
+
+    /// Get custom templates directory path
+    pub fn custom_templates_dir(&self) -> PathBuf {
+        self.jit_root.join("config").join("hierarchy-templates")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap as StdHashMap;
+    use tempfile::TempDir;
+
+    fn write_template_file(dir: &Path, template: &HierarchyTemplate) -> Result<()> {
+        let json = serde_json::to_string_pretty(template)?;
+        fs::write(dir.join(format!("{}.json", template.name)), json)?;
+        Ok(())
+    }
+
+    fn custom_template(name: &str) -> HierarchyTemplate {
+        let mut hierarchy = StdHashMap::new();
+        hierarchy.insert("epic".to_string(), 1);
+        hierarchy.insert("task".to_string(), 2);
+
+        let mut label_associations = StdHashMap::new();
+        label_associations.insert("epic".to_string(), "epic".to_string());
+
+        HierarchyTemplate {
+            name: name.to_string(),
+            description: format!("Custom template {}", name),
+            hierarchy,
+            label_associations,
+        }
+    }
+
+    #[test]
+    fn test_load_builtin_only() {
+        let temp_dir = TempDir::new().unwrap();
+        let manager = HierarchyTemplateManager::new(temp_dir.path().to_path_buf()).unwrap();
+
+        assert!(manager.has("default"));
+        assert!(manager.has("extended"));
+        assert!(manager.has("agile"));
+        assert!(manager.has("minimal"));
+        assert_eq!(manager.templates.len(), 4);
+    }
+
+    #[test]
+    fn test_get_template() {
+        let temp_dir = TempDir::new().unwrap();
+        let manager = HierarchyTemplateManager::new(temp_dir.path().to_path_buf()).unwrap();
+
+        let template = manager.get("default").unwrap();
+        assert_eq!(template.name, "default");
+        assert_eq!(template.hierarchy.len(), 4);
+    }
+
+    #[test]
+    fn test_get_nonexistent_template() {
+        let temp_dir = TempDir::new().unwrap();
+        let manager = HierarchyTemplateManager::new(temp_dir.path().to_path_buf()).unwrap();
+
+        let result = manager.get("nonexistent");
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("not found"));
+    }
+
+    #[test]
+    fn test_load_custom_template() {
+        let temp_dir = TempDir::new().unwrap();
+        let templates_dir = temp_dir.path().join("config").join("hierarchy-templates");
+        fs::create_dir_all(&templates_dir).unwrap();
+
+        write_template_file(&templates_dir, &custom_template("two-level")).unwrap();
+
+        let manager = HierarchyTemplateManager::new(temp_dir.path().to_path_buf()).unwrap();
+
+        assert!(manager.has("two-level"));
+        let template = manager.get("two-level").unwrap();
+        assert_eq!(template.hierarchy.len(), 2);
+    }
+
+    #[test]
+    fn test_custom_template_overrides_builtin() {
+        let temp_dir = TempDir::new().unwrap();
+        let templates_dir = temp_dir.path().join("config").join("hierarchy-templates");
+        fs::create_dir_all(&templates_dir).unwrap();
+
+        write_template_file(&templates_dir, &custom_template("minimal")).unwrap();
+
+        let manager = HierarchyTemplateManager::new(temp_dir.path().to_path_buf()).unwrap();
+
+        let template = manager.get("minimal").unwrap();
+        assert_eq!(template.description, "Custom template minimal");
+    }
+
+    #[test]
+    fn test_list_templates() {
+        let temp_dir = TempDir::new().unwrap();
+        let manager = HierarchyTemplateManager::new(temp_dir.path().to_path_buf()).unwrap();
+
+        let list = manager.list();
+        assert_eq!(list.len(), 4);
+
+        let default = list.iter().find(|t| t.name == "default").unwrap();
+        assert_eq!(default.level_count, 4);
+        assert!(default.builtin);
+    }
+
+    #[test]
+    fn test_list_includes_custom_templates() {
+        let temp_dir = TempDir::new().unwrap();
+        let templates_dir = temp_dir.path().join("config").join("hierarchy-templates");
+        fs::create_dir_all(&templates_dir).unwrap();
+
+        write_template_file(&templates_dir, &custom_template("two-level")).unwrap();
+
+        let manager = HierarchyTemplateManager::new(temp_dir.path().to_path_buf()).unwrap();
+        let list = manager.list();
+
+        assert_eq!(list.len(), 5);
+        let custom = list.iter().find(|t| t.name == "two-level").unwrap();
+        assert!(!custom.builtin);
+    }
+
+    #[test]
+    fn test_invalid_template_file_returns_error() {
+        let temp_dir = TempDir::new().unwrap();
+        let templates_dir = temp_dir.path().join("config").join("hierarchy-templates");
+        fs::create_dir_all(&templates_dir).unwrap();
+
+        fs::write(templates_dir.join("bad.json"), "{ invalid json }").unwrap();
+
+        let result = HierarchyTemplateManager::new(temp_dir.path().to_path_buf());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_invalid_template_structure_returns_error() {
+        let temp_dir = TempDir::new().unwrap();
+        let templates_dir = temp_dir.path().join("config").join("hierarchy-templates");
+        fs::create_dir_all(&templates_dir).unwrap();
+
+        let mut hierarchy = StdHashMap::new();
+        hierarchy.insert("epic".to_string(), 1);
+        hierarchy.insert("task".to_string(), 3); // gap
+
+        let bad = HierarchyTemplate {
+            name: "gappy".to_string(),
+            description: "Has a gap".to_string(),
+            hierarchy,
+            label_associations: StdHashMap::new(),
+        };
+        write_template_file(&templates_dir, &bad).unwrap();
+
+        let result = HierarchyTemplateManager::new(temp_dir.path().to_path_buf());
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Invalid hierarchy template in file"));
+    }
+
+    #[test]
+    fn test_missing_templates_dir_is_ok() {
+        let temp_dir = TempDir::new().unwrap();
+        // Don't create the templates directory
+
+        let manager = HierarchyTemplateManager::new(temp_dir.path().to_path_buf()).unwrap();
+        assert_eq!(manager.templates.len(), 4); // Only builtins
+    }
+
+    #[test]
+    fn test_custom_templates_dir() {
+        let temp_dir = TempDir::new().unwrap();
+        let manager = HierarchyTemplateManager::new(temp_dir.path().to_path_buf()).unwrap();
+
+        assert_eq!(
+            manager.custom_templates_dir(),
+            temp_dir.path().join("config").join("hierarchy-templates")
+        );
+    }
+}
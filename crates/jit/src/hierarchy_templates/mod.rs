@@ -1,9 +1,17 @@
 //! Type hierarchy templates for different workflows
 
-use std::collections::HashMap;
+mod config;
+mod manager;
+
+pub use config::{load_hierarchy_config_with_includes, ConfigOrigin};
+pub use manager::HierarchyTemplateManager;
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 
 /// Available hierarchy templates
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HierarchyTemplate {
     pub name: String,
     pub description: String,
@@ -27,6 +35,68 @@ impl HierarchyTemplate {
         Self::all().into_iter().find(|t| t.name == name)
     }
 
+    /// Validate template structure.
+    ///
+    /// A well-formed template must have:
+    /// - Ranks in `hierarchy` that are contiguous starting at 1, with no
+    ///   duplicates (so every level from the root down to the leaf is
+    ///   represented exactly once).
+    /// - Every `label_associations` value naming a type that's actually
+    ///   present in `hierarchy` (a membership namespace must point at a real
+    ///   level, not a typo or a dangling reference).
+    /// - No label association on the deepest (leaf) type -- the leaf is the
+    ///   unit of work, not something other issues are organized under.
+    pub fn validate(&self) -> Result<()> {
+        let mut ranks: Vec<u8> = self.hierarchy.values().copied().collect();
+        ranks.sort_unstable();
+
+        let mut seen = HashSet::new();
+        for rank in &ranks {
+            if !seen.insert(*rank) {
+                return Err(anyhow!(
+                    "Template '{}' has duplicate hierarchy rank {}",
+                    self.name,
+                    rank
+                ));
+            }
+        }
+        for (index, rank) in ranks.iter().enumerate() {
+            if *rank as usize != index + 1 {
+                return Err(anyhow!(
+                    "Template '{}' hierarchy ranks must be contiguous starting at 1, found {:?}",
+                    self.name,
+                    ranks
+                ));
+            }
+        }
+
+        for (type_name, namespace) in &self.label_associations {
+            if !self.hierarchy.contains_key(namespace) {
+                return Err(anyhow!(
+                    "Template '{}' label_associations['{}'] = '{}' does not reference a type in the hierarchy",
+                    self.name,
+                    type_name,
+                    namespace
+                ));
+            }
+        }
+
+        if let Some(&deepest_rank) = ranks.last() {
+            for (type_name, &rank) in &self.hierarchy {
+                if rank == deepest_rank && self.label_associations.contains_key(type_name) {
+                    return Err(anyhow!(
+                        "Template '{}' leaf type '{}' (deepest rank {}) must not have a label association",
+                        self.name,
+                        type_name,
+                        deepest_rank
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// Default 4-level hierarchy: milestone → epic → story → task
     ///
     /// Note: This is a factory method for the "default template", not the Default trait.
@@ -117,10 +187,25 @@ impl HierarchyTemplate {
     }
 }
 
+/// Template metadata for listing
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HierarchyTemplateInfo {
+    /// Template name
+    pub name: String,
+    /// Description
+    pub description: String,
+    /// Number of levels in the hierarchy
+    pub level_count: usize,
+    /// Whether this is a builtin template
+    pub builtin: bool,
+}
+
 /// Load hierarchy configuration from storage.
 ///
 /// Reads the type_hierarchy and label_associations from .jit/labels.json
-/// or returns the default config.
+/// or returns the default config. For a namespaces file split across an
+/// `includes` chain of `.jit/config/` files, see
+/// [`load_hierarchy_config_with_includes`] instead.
 pub fn get_hierarchy_config<S: crate::storage::IssueStore>(
     storage: &S,
 ) -> anyhow::Result<crate::type_hierarchy::HierarchyConfig> {
@@ -183,4 +268,102 @@ mod tests {
     fn test_get_nonexistent_template() {
         assert!(HierarchyTemplate::get("nonexistent").is_none());
     }
+
+    #[test]
+    fn test_builtin_templates_are_valid() {
+        for template in HierarchyTemplate::all() {
+            assert!(
+                template.validate().is_ok(),
+                "Template {} is invalid",
+                template.name
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_rejects_non_contiguous_ranks() {
+        let mut hierarchy = HashMap::new();
+        hierarchy.insert("epic".to_string(), 1);
+        hierarchy.insert("task".to_string(), 3); // gap: no rank 2
+
+        let template = HierarchyTemplate {
+            name: "gappy".to_string(),
+            description: "Has a gap".to_string(),
+            hierarchy,
+            label_associations: HashMap::new(),
+        };
+
+        let result = template.validate();
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("contiguous starting at 1"));
+    }
+
+    #[test]
+    fn test_validate_rejects_duplicate_ranks() {
+        let mut hierarchy = HashMap::new();
+        hierarchy.insert("epic".to_string(), 1);
+        hierarchy.insert("task".to_string(), 1);
+
+        let template = HierarchyTemplate {
+            name: "duped".to_string(),
+            description: "Has a duplicate rank".to_string(),
+            hierarchy,
+            label_associations: HashMap::new(),
+        };
+
+        let result = template.validate();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("duplicate"));
+    }
+
+    #[test]
+    fn test_validate_rejects_label_association_for_unknown_type() {
+        let mut hierarchy = HashMap::new();
+        hierarchy.insert("epic".to_string(), 1);
+        hierarchy.insert("task".to_string(), 2);
+
+        let mut label_associations = HashMap::new();
+        label_associations.insert("epic".to_string(), "feature".to_string()); // not in hierarchy
+
+        let template = HierarchyTemplate {
+            name: "dangling".to_string(),
+            description: "References an unknown namespace".to_string(),
+            hierarchy,
+            label_associations,
+        };
+
+        let result = template.validate();
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("does not reference a type"));
+    }
+
+    #[test]
+    fn test_validate_rejects_label_association_on_leaf_type() {
+        let mut hierarchy = HashMap::new();
+        hierarchy.insert("epic".to_string(), 1);
+        hierarchy.insert("task".to_string(), 2);
+
+        let mut label_associations = HashMap::new();
+        label_associations.insert("task".to_string(), "task".to_string()); // leaf can't be a namespace
+
+        let template = HierarchyTemplate {
+            name: "leafy".to_string(),
+            description: "Leaf has a label association".to_string(),
+            hierarchy,
+            label_associations,
+        };
+
+        let result = template.validate();
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("must not have a label association"));
+    }
 }
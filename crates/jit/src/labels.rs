@@ -82,6 +82,39 @@ pub fn parse_label(label: &str) -> Result<(String, String)> {
     Ok((parts[0].to_string(), parts[1].to_string()))
 }
 
+/// Check whether a single label matches a query pattern.
+///
+/// A pattern is `namespace:value`, where `value` may be the literal `*` to
+/// match any value in that namespace (e.g. `epic:*` matches `epic:auth` and
+/// `epic:billing` but not `milestone:v1.0`).
+///
+/// # Examples
+///
+/// ```
+/// use jit::labels::label_matches;
+///
+/// assert!(label_matches("epic:auth", "epic:*"));
+/// assert!(label_matches("epic:auth", "epic:auth"));
+/// assert!(!label_matches("epic:auth", "epic:billing"));
+/// assert!(!label_matches("epic:auth", "milestone:*"));
+/// ```
+pub fn label_matches(label: &str, pattern: &str) -> bool {
+    let Some((pattern_ns, pattern_val)) = pattern.split_once(':') else {
+        return false;
+    };
+    let Some((label_ns, label_val)) = label.split_once(':') else {
+        return false;
+    };
+
+    label_ns == pattern_ns && (pattern_val == "*" || label_val == pattern_val)
+}
+
+/// Check whether any label in `labels` matches `pattern`. See
+/// [`label_matches`] for the pattern format.
+pub fn matches_pattern(labels: &[String], pattern: &str) -> bool {
+    labels.iter().any(|label| label_matches(label, pattern))
+}
+
 /// Suggest a corrected label format for common mistakes
 fn suggest_label_fix(label: &str) -> Option<String> {
     // No colon: suggest adding namespace
@@ -212,6 +245,27 @@ mod tests {
         assert!(suggestion.unwrap().contains("milestone:v1.0"));
     }
 
+    #[test]
+    fn test_label_matches_wildcard() {
+        assert!(label_matches("epic:auth", "epic:*"));
+        assert!(label_matches("epic:billing", "epic:*"));
+        assert!(!label_matches("milestone:v1.0", "epic:*"));
+    }
+
+    #[test]
+    fn test_label_matches_exact() {
+        assert!(label_matches("epic:auth", "epic:auth"));
+        assert!(!label_matches("epic:auth", "epic:billing"));
+    }
+
+    #[test]
+    fn test_matches_pattern_checks_any_label() {
+        let labels = vec!["type:task".to_string(), "epic:auth".to_string()];
+        assert!(matches_pattern(&labels, "epic:*"));
+        assert!(matches_pattern(&labels, "type:task"));
+        assert!(!matches_pattern(&labels, "milestone:*"));
+    }
+
     #[test]
     fn test_suggest_label_fix_multiple_colons() {
         let suggestion = suggest_label_fix("ns:val:extra");
@@ -3,13 +3,32 @@
 //! This library provides the core functionality for JIT issue tracking.
 //! It is primarily used for testing, but can also be embedded in other applications.
 
+// Lets the `#[doc_adapter]` attribute macro (in `jit-macros`) always refer to
+// `::jit::document::...`, whether it's invoked from inside this crate (e.g.
+// its own tests) or from a downstream crate depending on `jit` normally.
+extern crate self as jit;
+
+pub mod agent_heartbeat;
+pub mod bundle;
 pub mod commands;
+pub mod crypto;
+pub mod document;
 pub mod domain;
 pub mod graph;
+pub mod labels;
+#[cfg(feature = "llm-suggest")]
+pub mod llm;
+pub mod notifier;
 pub mod output;
+pub mod problem_matcher;
+pub mod query;
+pub mod remote_client;
 pub mod schema;
 pub mod storage;
+#[cfg(test)]
+pub mod test_utils;
 pub mod visualization;
+pub mod workflow;
 
 // Re-export commonly used types
 pub use commands::CommandExecutor;
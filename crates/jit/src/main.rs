@@ -14,13 +14,13 @@
 // Binary-specific module (not in library)
 mod output_macros;
 
-use anyhow::{anyhow, Result};
+use anyhow::{anyhow, Context, Result};
 use clap::Parser;
 use jit::cli::{
-    Cli, Commands, DepCommands, DocCommands, EventCommands, GateCommands, GraphCommands,
-    IssueCommands, RegistryCommands,
+    Cli, ClaimCommands, Commands, DepCommands, DocCommands, EventCommands, GateCommands,
+    GraphCommands, IssueCommands, JobCommands, RegistryCommands,
 };
-use jit::commands::{parse_priority, parse_state, CommandExecutor};
+use jit::commands::{parse_priority, parse_report_status, parse_state, CommandExecutor};
 use jit::output::ExitCode;
 use jit::storage::{IssueStore, JsonFileStorage};
 use std::env;
@@ -97,6 +97,13 @@ fn run() -> Result<()> {
         .command
         .ok_or_else(|| anyhow::anyhow!("No command provided. Use --help for usage."))?;
 
+    if let Some(remote_url) = cli.remote {
+        let token = cli
+            .token
+            .ok_or_else(|| anyhow!("--remote requires --token (or JIT_REMOTE_TOKEN)"))?;
+        return run_remote(jit::remote_client::RemoteClient::new(remote_url, token), command);
+    }
+
     let current_dir = env::current_dir()?;
 
     // Determine jit data directory: JIT_DATA_DIR env var or default to .jit/
@@ -151,6 +158,7 @@ fn run() -> Result<()> {
                     label,
                     force,
                     orphan,
+                    rules,
                     json,
                 } => {
                     let prio = parse_priority(&priority)?;
@@ -166,7 +174,8 @@ fn run() -> Result<()> {
                         if !force {
                             use jit::type_hierarchy::ValidationWarning;
 
-                            let warnings = executor.check_warnings(&id)?;
+                            let rules_path = rules.as_ref().map(std::path::Path::new);
+                            let warnings = executor.check_warnings_with_rules(&id, rules_path)?;
 
                             // Filter orphan warnings if --orphan flag is set
                             let warnings_to_display: Vec<_> = if orphan {
@@ -198,11 +207,14 @@ fn run() -> Result<()> {
                                     }
                                     ValidationWarning::OrphanedLeaf { type_name, .. } => {
                                         eprintln!("\n⚠ Warning: Orphaned leaf issue");
-                                        eprintln!("  {} {} has no parent association (epic or milestone).", 
+                                        eprintln!("  {} {} has no parent association (epic or milestone).",
                                              type_name.to_uppercase(), id);
                                         eprintln!("  Consider adding: --label \"epic:value\" or --label \"milestone:value\"");
                                         eprintln!("  Or use --orphan flag to acknowledge intentional orphan.");
                                     }
+                                    ValidationWarning::Custom { code, message, .. } => {
+                                        eprintln!("\n⚠ Warning ({}): {}", code, message);
+                                    }
                                 }
                             }
                         }
@@ -283,6 +295,7 @@ fn run() -> Result<()> {
                 }
                 IssueCommands::Show { id, json } => match executor.show_issue(&id) {
                     Ok(issue) => {
+                        let slack = executor.issue_slack(&issue.id).ok().flatten();
                         output_data!(json, issue, {
                             println!("ID: {}", issue.id);
                             println!("Title: {}", issue.title);
@@ -293,6 +306,13 @@ fn run() -> Result<()> {
                             println!("Dependencies: {:?}", issue.dependencies);
                             println!("Gates Required: {:?}", issue.gates_required);
                             println!("Gates Status: {:?}", issue.gates_status);
+                            if let Some(node) = &slack {
+                                println!(
+                                    "Slack: {}s{}",
+                                    node.slack,
+                                    if node.critical { " (on critical path)" } else { "" }
+                                );
+                            }
                             if !issue.documents.is_empty() {
                                 println!("Documents:");
                                 for doc in &issue.documents {
@@ -317,16 +337,147 @@ fn run() -> Result<()> {
                 IssueCommands::Update {
                     id,
                     title,
-                    desc,
+                    description,
                     priority,
                     state,
                     label,
                     remove_label,
+                    assignee,
+                    filter,
+                    r#async,
+                    batch,
+                    dry_run,
+                    atomic,
                     json,
                 } => {
+                    if let Some(filter_str) = filter {
+                        let prio = priority.map(|p| parse_priority(&p)).transpose()?;
+                        let st = state.map(|s| parse_state(&s)).transpose()?;
+                        let ops = jit::commands::UpdateOperations {
+                            state: st,
+                            add_labels: label,
+                            remove_labels: remove_label,
+                            assignee,
+                            unassign: false,
+                            priority: prio,
+                        };
+
+                        if r#async {
+                            match executor.enqueue_bulk_update_job(&filter_str, ops) {
+                                Ok(job) => {
+                                    if json {
+                                        use jit::output::JsonOutput;
+                                        let output = JsonOutput::success(&job);
+                                        println!("{}", output.to_json_string()?);
+                                    } else {
+                                        println!(
+                                            "Enqueued job {} ({} matched)",
+                                            job.id, job.result.summary.total_matched
+                                        );
+                                    }
+                                }
+                                Err(e) => {
+                                    if json {
+                                        use jit::output::{ErrorCode, JsonError};
+                                        let json_error =
+                                            JsonError::new(ErrorCode::QUERY_PARSE_ERROR, e.to_string());
+                                        println!("{}", json_error.to_json_string()?);
+                                        std::process::exit(json_error.exit_code().code());
+                                    } else {
+                                        return Err(e);
+                                    }
+                                }
+                            }
+                            return Ok(());
+                        }
+
+                        let parsed_filter = match jit::query::QueryFilter::parse(&filter_str) {
+                            Ok(f) => f,
+                            Err(e) => {
+                                if json {
+                                    use jit::output::{ErrorCode, JsonError};
+                                    let json_error =
+                                        JsonError::new(ErrorCode::QUERY_PARSE_ERROR, e.to_string());
+                                    println!("{}", json_error.to_json_string()?);
+                                    std::process::exit(json_error.exit_code().code());
+                                } else {
+                                    return Err(e);
+                                }
+                            }
+                        };
+                        let result = executor.apply_bulk_update(&parsed_filter, &ops)?;
+
+                        if json {
+                            use jit::output::JsonOutput;
+                            let output = JsonOutput::success(&result);
+                            println!("{}", output.to_json_string()?);
+                        } else {
+                            println!(
+                                "{} matched, {} modified, {} errors",
+                                result.summary.total_matched,
+                                result.summary.total_modified,
+                                result.summary.total_errors
+                            );
+                        }
+                        return Ok(());
+                    }
+
+                    if let Some(batch_file) = batch {
+                        let contents = std::fs::read_to_string(&batch_file)
+                            .with_context(|| format!("Failed to read batch file {}", batch_file))?;
+                        let entries: Vec<jit::commands::BatchUpdateEntry> =
+                            serde_json::from_str(&contents).with_context(|| {
+                                format!("Failed to parse batch file {}", batch_file)
+                            })?;
+
+                        match executor.apply_batch_update(&entries, dry_run, atomic) {
+                            Ok(report) => {
+                                if json {
+                                    use jit::output::JsonOutput;
+                                    let output = JsonOutput::success(&report);
+                                    println!("{}", output.to_json_string()?);
+                                } else {
+                                    println!(
+                                        "Batch: {} matched, {} modified, {} errors{}",
+                                        report.summary.total_matched,
+                                        report.summary.total_modified,
+                                        report.summary.total_errors,
+                                        if report.rolled_back { " (rolled back)" } else { "" }
+                                    );
+                                }
+                            }
+                            Err(e) => {
+                                if json {
+                                    use jit::output::JsonError;
+                                    let json_error = JsonError::new("BATCH_UPDATE_FAILED", e.to_string());
+                                    println!("{}", json_error.to_json_string()?);
+                                    std::process::exit(json_error.exit_code().code());
+                                } else {
+                                    return Err(e);
+                                }
+                            }
+                        }
+                        return Ok(());
+                    }
+
+                    let id = match id {
+                        Some(id) => id,
+                        None => {
+                            let err =
+                                anyhow!("ID is required unless --filter or --batch is given");
+                            if json {
+                                use jit::output::{ErrorCode, JsonError};
+                                let json_error =
+                                    JsonError::new(ErrorCode::MISSING_ID_OR_FILTER, err.to_string());
+                                println!("{}", json_error.to_json_string()?);
+                                std::process::exit(json_error.exit_code().code());
+                            }
+                            return Err(err);
+                        }
+                    };
                     let prio = priority.map(|p| parse_priority(&p)).transpose()?;
                     let st = state.map(|s| parse_state(&s)).transpose()?;
-                    executor.update_issue(&id, title, desc, prio, st, label, remove_label)?;
+                    executor.update_issue(&id, title, description, prio, st, label, remove_label)?;
 
                     if json {
                         let issue = storage.load_issue(&id)?;
@@ -348,6 +499,66 @@ fn run() -> Result<()> {
                         println!("Deleted issue: {}", id);
                     }
                 }
+                IssueCommands::Transition { id, to, json } => {
+                    let target = parse_state(&to)?;
+                    match executor.transition_issue(&id, target) {
+                        Ok(()) => {
+                            if json {
+                                let result = serde_json::json!({
+                                    "id": id,
+                                    "state": target,
+                                });
+                                println!("{}", serde_json::to_string_pretty(&result)?);
+                            } else {
+                                println!("Transitioned {} -> {}", id, target);
+                            }
+                        }
+                        Err(e) => {
+                            if json {
+                                use jit::output::{ErrorCode, JsonError};
+                                let json_error =
+                                    JsonError::new(ErrorCode::INVALID_STATE_TRANSITION, e.to_string());
+                                println!("{}", json_error.to_json_string()?);
+                                std::process::exit(json_error.exit_code().code());
+                            } else {
+                                return Err(e);
+                            }
+                        }
+                    }
+                }
+                #[cfg(feature = "llm-suggest")]
+                IssueCommands::Suggest { id, accept, json } => {
+                    let suggestion = executor.suggest_breakdown(&id)?;
+
+                    if accept {
+                        let subtask_ids = executor.accept_suggestion(&suggestion)?;
+                        if json {
+                            use jit::output::JsonOutput;
+                            let response = serde_json::json!({
+                                "parent_id": suggestion.parent_id,
+                                "subtask_ids": subtask_ids,
+                            });
+                            let output = JsonOutput::success(response);
+                            println!("{}", output.to_json_string()?);
+                        } else {
+                            println!(
+                                "Materialized {} suggested subtask(s) for {}",
+                                subtask_ids.len(),
+                                suggestion.parent_id
+                            );
+                        }
+                    } else if json {
+                        use jit::output::JsonOutput;
+                        let output = JsonOutput::success(&suggestion);
+                        println!("{}", output.to_json_string()?);
+                    } else {
+                        println!("Suggested breakdown for {}:", suggestion.parent_id);
+                        for (i, subtask) in suggestion.subtasks.iter().enumerate() {
+                            println!("  [{}] {} - {}", i, subtask.title, subtask.description);
+                        }
+                        println!("Re-run with --accept to materialize these subtasks.");
+                    }
+                }
                 IssueCommands::Breakdown {
                     parent_id,
                     subtask_titles,
@@ -429,6 +640,21 @@ fn run() -> Result<()> {
                         println!("Released issue: {} (reason: {})", id, reason);
                     }
                 }
+                IssueCommands::SetContext {
+                    id,
+                    key,
+                    value,
+                    json,
+                } => {
+                    executor.set_issue_context(&id, &key, &value)?;
+
+                    if json {
+                        let issue = storage.load_issue(&id)?;
+                        println!("{}", serde_json::to_string_pretty(&issue)?);
+                    } else {
+                        println!("Set context {}={} on issue: {}", key, value, id);
+                    }
+                }
                 IssueCommands::ClaimNext { assignee, filter } => {
                     let id = executor.claim_next(assignee, filter)?;
                     println!("Claimed issue: {}", id);
@@ -547,6 +773,73 @@ fn run() -> Result<()> {
                 }
             },
         },
+        Commands::Claim(claim_cmd) => match claim_cmd {
+            ClaimCommands::Acquire {
+                id,
+                ttl_secs,
+                agent_id,
+                reason,
+                watch,
+                json,
+            } => {
+                let (lease_id, daemon) = jit::commands::execute_claim_acquire(
+                    &storage,
+                    &id,
+                    ttl_secs,
+                    agent_id.as_deref(),
+                    reason.as_deref(),
+                    watch,
+                )?;
+
+                if json {
+                    use jit::output::JsonOutput;
+                    let response = serde_json::json!({
+                        "lease_id": lease_id,
+                        "heartbeat": daemon.is_some(),
+                    });
+                    let output = JsonOutput::success(response);
+                    println!("{}", output.to_json_string()?);
+                } else {
+                    println!("Acquired lease: {}", lease_id);
+                }
+
+                if let Some(_daemon) = daemon {
+                    if !json {
+                        println!(
+                            "auto_heartbeat enabled; keeping this lease renewed until interrupted (Ctrl-C)..."
+                        );
+                    }
+                    // Mirrors `jit gate run --watch`: stay in the foreground
+                    // so the daemon (and the lease it's renewing) lives for
+                    // as long as this process does.
+                    loop {
+                        std::thread::sleep(std::time::Duration::from_secs(3600));
+                    }
+                }
+            }
+            ClaimCommands::Heartbeat { lease_id, json } => {
+                jit::commands::execute_claim_heartbeat(&lease_id)?;
+                if json {
+                    use jit::output::JsonOutput;
+                    let response = serde_json::json!({ "lease_id": lease_id });
+                    let output = JsonOutput::success(response);
+                    println!("{}", output.to_json_string()?);
+                } else {
+                    println!("Heartbeat sent for lease: {}", lease_id);
+                }
+            }
+            ClaimCommands::Release { lease_id, json } => {
+                jit::commands::execute_claim_release(&lease_id)?;
+                if json {
+                    use jit::output::JsonOutput;
+                    let response = serde_json::json!({ "lease_id": lease_id });
+                    let output = JsonOutput::success(response);
+                    println!("{}", output.to_json_string()?);
+                } else {
+                    println!("Released lease: {}", lease_id);
+                }
+            }
+        },
         Commands::Gate(gate_cmd) => match gate_cmd {
             GateCommands::Add { id, gate_key, json } => {
                 match executor.add_gate(&id, gate_key.clone()) {
@@ -650,6 +943,61 @@ fn run() -> Result<()> {
                     }
                 }
             },
+            GateCommands::Verify { id, json } => {
+                let report = executor.verify_gate_signatures(&id)?;
+                if json {
+                    use jit::output::JsonOutput;
+                    let output = JsonOutput::success(&report);
+                    println!("{}", output.to_json_string()?);
+                } else {
+                    println!("Gate signature verification for {}:", report.issue_id);
+                    for gate in &report.gates {
+                        println!(
+                            "  {} [{:?}] updated_by={}",
+                            gate.gate_key,
+                            gate.status,
+                            gate.updated_by.as_deref().unwrap_or("-")
+                        );
+                    }
+                }
+            }
+            GateCommands::Run {
+                id,
+                watch,
+                interval,
+                json,
+            } => {
+                let print_outcomes = |outcomes: &[jit::commands::GateRunOutcome]| -> Result<()> {
+                    if json {
+                        use jit::output::JsonOutput;
+                        let output = JsonOutput::success(outcomes);
+                        println!("{}", output.to_json_string()?);
+                    } else if outcomes.is_empty() {
+                        println!("No pending auto gates to run.");
+                    } else {
+                        for outcome in outcomes {
+                            println!(
+                                "  {} [{}] {}",
+                                outcome.issue_id,
+                                outcome.gate_key,
+                                if outcome.passed { "PASSED" } else { "FAILED" }
+                            );
+                        }
+                    }
+                    Ok(())
+                };
+
+                if watch {
+                    loop {
+                        let outcomes = executor.run_auto_gates(id.as_deref())?;
+                        print_outcomes(&outcomes)?;
+                        std::thread::sleep(std::time::Duration::from_secs(interval));
+                    }
+                } else {
+                    let outcomes = executor.run_auto_gates(id.as_deref())?;
+                    print_outcomes(&outcomes)?;
+                }
+            }
         },
         Commands::Graph(graph_cmd) => match graph_cmd {
             GraphCommands::Show { id, json } => {
@@ -876,6 +1224,53 @@ fn run() -> Result<()> {
                 executor.document_diff(&id, &path, &from, to.as_deref())?;
             }
         },
+        Commands::Bundle(bundle_cmd) => match bundle_cmd {
+            jit::cli::BundleCommands::Export {
+                issues,
+                output,
+                json,
+            } => {
+                let manifest = executor.export_bundle(&issues, std::path::Path::new(&output))?;
+                if json {
+                    use jit::output::JsonOutput;
+                    let response = JsonOutput::success(&manifest);
+                    println!("{}", response.to_json_string()?);
+                } else {
+                    println!(
+                        "Wrote bundle with {} issue(s), {} event(s) to {}",
+                        manifest.issue_ids.len(),
+                        manifest.event_count,
+                        output
+                    );
+                    println!("  Content hash: {}", manifest.content_hash);
+                    if manifest.signature.is_some() {
+                        println!("  Signed: yes");
+                    }
+                }
+            }
+            jit::cli::BundleCommands::Import {
+                path,
+                insecure,
+                json,
+            } => {
+                let report =
+                    executor.import_bundle(std::path::Path::new(&path), insecure)?;
+                if json {
+                    use jit::output::JsonOutput;
+                    let response = JsonOutput::success(&report);
+                    println!("{}", response.to_json_string()?);
+                } else {
+                    println!(
+                        "Imported bundle: {} issue(s) described, {} new event(s) (merged {} total), {} issue(s) updated, {} document(s) written",
+                        report.issues_in_bundle,
+                        report.events_new,
+                        report.events_merged,
+                        report.issues_updated,
+                        report.documents_written
+                    );
+                }
+            }
+        },
         Commands::Query(query_cmd) => match query_cmd {
             jit::cli::QueryCommands::Ready { json } => {
                 let issues = executor.query_ready()?;
@@ -1055,9 +1450,12 @@ fn run() -> Result<()> {
                     }
                     Err(e) => {
                         if json {
-                            use jit::output::JsonError;
-                            let json_error = JsonError::new("INVALID_LABEL_PATTERN", e.to_string())
-                                .with_suggestion("Use 'namespace:value' for exact match or 'namespace:*' for wildcard");
+                            use jit::output::{ErrorCode, JsonError};
+                            let json_error =
+                                JsonError::new(ErrorCode::INVALID_LABEL_PATTERN, e.to_string())
+                                    .with_suggestion(
+                                        "Use 'namespace:value' for exact match or 'namespace:*' for wildcard",
+                                    );
                             println!("{}", json_error.to_json_string()?);
                             std::process::exit(json_error.exit_code().code());
                         } else {
@@ -1303,11 +1701,17 @@ fn run() -> Result<()> {
                 executor.status()?;
             }
         }
-        Commands::Validate { json, fix, dry_run } => {
+        Commands::Validate {
+            json,
+            fix,
+            dry_run,
+            rules,
+        } => {
             // Validate dry_run requires fix
             if dry_run && !fix {
                 return Err(anyhow!("--dry-run requires --fix to be specified"));
             }
+            let rules_path = rules.as_ref().map(std::path::Path::new);
 
             if fix {
                 // Use auto-fix mode (pass quiet=true if json mode)
@@ -1334,7 +1738,7 @@ fn run() -> Result<()> {
             } else {
                 // Standard validation with warnings
                 executor.validate_silent()?;
-                let warnings = executor.collect_all_warnings()?;
+                let warnings = executor.collect_all_warnings_with_rules(rules_path)?;
 
                 if json {
                     use jit::output::JsonOutput;
@@ -1366,6 +1770,14 @@ fn run() -> Result<()> {
                                         "suggestion": "Add label: epic:* or milestone:*"
                                     })
                                 }
+                                ValidationWarning::Custom { code, message, .. } => {
+                                    json!({
+                                        "type": "custom",
+                                        "issue_id": issue_id,
+                                        "code": code,
+                                        "message": message
+                                    })
+                                }
                             })
                         })
                         .collect();
@@ -1411,9 +1823,12 @@ fn run() -> Result<()> {
                                             "⚠ Issue {} (type:{}): Orphaned leaf issue",
                                             issue_id, type_name
                                         );
-                                        println!("  Suggested: jit issue update {} --label \"epic:value\"", 
+                                        println!("  Suggested: jit issue update {} --label \"epic:value\"",
                                                 issue_id);
                                     }
+                                    ValidationWarning::Custom { code, message, .. } => {
+                                        println!("⚠ Issue {} ({}): {}", issue_id, code, message);
+                                    }
                                 }
                                 println!();
                             }
@@ -1422,7 +1837,453 @@ fn run() -> Result<()> {
                 }
             }
         }
+
+        Commands::Batch { file, json } => {
+            let contents = std::fs::read_to_string(&file)
+                .with_context(|| format!("Failed to read batch file {}", file))?;
+            let ops = jit::commands::parse_batch_file(&contents)?;
+
+            match executor.apply_batch(&ops) {
+                Ok(result) => {
+                    if json {
+                        use jit::output::JsonOutput;
+                        let output = JsonOutput::success(&result);
+                        println!("{}", output.to_json_string()?);
+                    } else {
+                        println!("Applied {} operation(s)", result.applied);
+                        for (alias, id) in &result.created {
+                            println!("  {} -> {}", alias, id);
+                        }
+                    }
+                }
+                Err(e) => {
+                    if json {
+                        use jit::output::JsonError;
+                        let json_error = JsonError::new("BATCH_FAILED", e.to_string());
+                        println!("{}", json_error.to_json_string()?);
+                        std::process::exit(json_error.exit_code().code());
+                    } else {
+                        return Err(e);
+                    }
+                }
+            }
+        }
+
+        Commands::Watch { from_start, json } => {
+            use std::io::{BufRead, BufReader, Seek, SeekFrom};
+
+            let watch_path = jit_dir.join("watch.jsonl");
+            let print_line = |line: &str| -> Result<()> {
+                let event: jit::domain::Event = serde_json::from_str(line)?;
+                if json {
+                    println!("{}", line);
+                } else {
+                    println!("{} {} ({})", event.get_timestamp(), event.get_type(), event.get_issue_id());
+                }
+                Ok(())
+            };
+
+            let mut reader = BufReader::new(
+                std::fs::OpenOptions::new()
+                    .read(true)
+                    .create(true)
+                    .write(true)
+                    .open(&watch_path)
+                    .context("Failed to open watch feed")?,
+            );
+            if !from_start {
+                reader.seek(SeekFrom::End(0))?;
+            }
+
+            let mut line = String::new();
+            loop {
+                line.clear();
+                let bytes_read = reader.read_line(&mut line)?;
+                if bytes_read == 0 {
+                    std::thread::sleep(std::time::Duration::from_millis(500));
+                    continue;
+                }
+                let trimmed = line.trim_end();
+                if !trimmed.is_empty() {
+                    print_line(trimmed)?;
+                }
+            }
+        }
+
+        Commands::Sweep { json } => {
+            let report = executor.sweep_deadlines()?;
+            if json {
+                use jit::output::JsonOutput;
+                let output = JsonOutput::success(&report);
+                println!("{}", output.to_json_string()?);
+            } else if report.actions.is_empty() {
+                println!("No overdue or nearing-deadline issues");
+            } else {
+                for action in &report.actions {
+                    match action.kind {
+                        jit::commands::SweepActionKind::TransitionedOverdue => {
+                            println!("! {} ({}) -> overdue", action.issue_id, action.title);
+                        }
+                        jit::commands::SweepActionKind::NearingDeadline => {
+                            println!("~ {} ({}) nearing deadline", action.issue_id, action.title);
+                        }
+                    }
+                }
+            }
+        }
+
+        Commands::Metrics { format } => {
+            let snapshot = executor.collect_metrics()?;
+            match format.as_str() {
+                "json" => {
+                    use jit::output::JsonOutput;
+                    let output = JsonOutput::success(&snapshot);
+                    println!("{}", output.to_json_string()?);
+                }
+                "prometheus" => {
+                    print!("{}", snapshot.to_prometheus_text());
+                }
+                other => {
+                    return Err(anyhow!("Unknown metrics format: {} (expected prometheus or json)", other));
+                }
+            }
+        }
+
+        Commands::CriticalPath { json } => {
+            let report = executor.critical_path()?;
+            if json {
+                use jit::output::JsonOutput;
+                let output = JsonOutput::success(&report);
+                println!("{}", output.to_json_string()?);
+            } else {
+                println!(
+                    "Total project duration: {}s",
+                    report.total_duration_secs
+                );
+                println!("Critical path:");
+                for id in &report.critical_path {
+                    let node = report.nodes.iter().find(|n| &n.issue_id == id).unwrap();
+                    println!("  {} ({}) [{}s]", node.issue_id, node.title, node.duration_secs);
+                }
+                println!();
+                for node in &report.nodes {
+                    println!(
+                        "{} slack={}s{}",
+                        node.issue_id,
+                        node.slack,
+                        if node.critical { " *critical*" } else { "" }
+                    );
+                }
+            }
+        }
+
+        Commands::Ready { parallel, json } => {
+            let issues = executor.query_ready_parallel(parallel)?;
+            if json {
+                use jit::output::JsonOutput;
+                let output = JsonOutput::success(&issues);
+                println!("{}", output.to_json_string()?);
+            } else if issues.is_empty() {
+                println!("No ready issues");
+            } else {
+                for issue in &issues {
+                    println!("{} [{:?}] {}", issue.id, issue.priority, issue.title);
+                }
+            }
+        }
+
+        Commands::Wait { id, interval_ms, json } => {
+            let issue =
+                executor.wait_for_terminal(&id, std::time::Duration::from_millis(interval_ms))?;
+            if json {
+                use jit::output::JsonOutput;
+                let output = JsonOutput::success(&issue);
+                println!("{}", output.to_json_string()?);
+            } else {
+                println!("{} reached terminal state {:?}", issue.id, issue.state);
+            }
+        }
+
+        Commands::Report {
+            id,
+            status,
+            message,
+            json,
+        } => {
+            let status = parse_report_status(&status)?;
+            executor.report_issue(&id, status, message)?;
+
+            if json {
+                let issue = storage.load_issue(&id)?;
+                println!("{}", serde_json::to_string_pretty(&issue)?);
+            } else {
+                println!("Reported {} on issue: {}", status, id);
+            }
+        }
+
+        Commands::Push { remote, json } => {
+            let report = executor.push_events(&remote)?;
+            if json {
+                use jit::output::JsonOutput;
+                let output = JsonOutput::success(&report);
+                println!("{}", output.to_json_string()?);
+            } else {
+                println!(
+                    "Pushed {} new event ref(s) to {} ({} already present)",
+                    report.refs_created, remote, report.refs_already_present
+                );
+            }
+        }
+
+        Commands::Pull { remote, json } => {
+            let report = executor.pull_events(&remote)?;
+            if json {
+                use jit::output::JsonOutput;
+                let output = JsonOutput::success(&report);
+                println!("{}", output.to_json_string()?);
+            } else {
+                println!(
+                    "Fetched {} new event(s) from {}, replayed {}",
+                    report.events_fetched, remote, report.events_replayed
+                );
+            }
+        }
+
+        Commands::Merge {
+            ancestor: _,
+            ours,
+            theirs,
+            json,
+        } => {
+            let report =
+                executor.merge_event_files(std::path::Path::new(&ours), std::path::Path::new(&theirs))?;
+            if json {
+                use jit::output::JsonOutput;
+                let output = JsonOutput::success(&report);
+                println!("{}", output.to_json_string()?);
+            } else {
+                println!(
+                    "Merged {} event(s), recomputed {} issue(s)",
+                    report.events_merged, report.issues_updated
+                );
+            }
+        }
+
+        Commands::Dump { out, json } => {
+            let report = match &out {
+                Some(path) => {
+                    let file = std::fs::File::create(path)
+                        .with_context(|| format!("Failed to create dump file: {}", path))?;
+                    executor.dump(file)?
+                }
+                None => executor.dump(std::io::stdout())?,
+            };
+
+            if json {
+                use jit::output::JsonOutput;
+                let output = JsonOutput::success(&report);
+                println!("{}", output.to_json_string()?);
+            } else if out.is_some() {
+                eprintln!(
+                    "Dumped {} issue(s), {} gate(s)",
+                    report.issue_count, report.gate_count
+                );
+            }
+        }
+
+        Commands::Restore { file, json } => {
+            let reader = std::fs::File::open(&file)
+                .with_context(|| format!("Failed to open dump archive: {}", file))?;
+            let report = executor.restore(reader)?;
+
+            if json {
+                use jit::output::JsonOutput;
+                let output = JsonOutput::success(&report);
+                println!("{}", output.to_json_string()?);
+            } else {
+                println!(
+                    "Restored {} issue(s), {} gate(s)",
+                    report.issues_restored, report.gates_restored
+                );
+            }
+        }
+
+        Commands::Job(job_cmd) => match job_cmd {
+            JobCommands::Status { id, json } => match executor.job_status(&id) {
+                Ok(job) => {
+                    if json {
+                        use jit::output::JsonOutput;
+                        let output = JsonOutput::success(&job);
+                        println!("{}", output.to_json_string()?);
+                    } else {
+                        println!(
+                            "{} [{:?}] {} matched, {} modified, {} errors (cursor {}/{})",
+                            job.id,
+                            job.status,
+                            job.result.summary.total_matched,
+                            job.result.summary.total_modified,
+                            job.result.summary.total_errors,
+                            job.cursor,
+                            job.result.summary.total_matched
+                        );
+                    }
+                }
+                Err(e) => {
+                    if json {
+                        use jit::output::JsonError;
+                        let json_error =
+                            JsonError::new(jit::commands::classify_job_error(&e), e.to_string());
+                        println!("{}", json_error.to_json_string()?);
+                        std::process::exit(json_error.exit_code().code());
+                    } else {
+                        return Err(e);
+                    }
+                }
+            },
+            JobCommands::List { json } => {
+                let jobs = executor.list_jobs()?;
+                if json {
+                    use jit::output::JsonOutput;
+                    let output = JsonOutput::success(&jobs);
+                    println!("{}", output.to_json_string()?);
+                } else if jobs.is_empty() {
+                    println!("No jobs");
+                } else {
+                    for job in &jobs {
+                        println!(
+                            "{} [{:?}] {} matched, {} modified, {} errors",
+                            job.id,
+                            job.status,
+                            job.result.summary.total_matched,
+                            job.result.summary.total_modified,
+                            job.result.summary.total_errors
+                        );
+                    }
+                }
+            }
+            JobCommands::Run { id, json } => match executor.run_job(&id) {
+                Ok(job) => {
+                    if json {
+                        use jit::output::JsonOutput;
+                        let output = JsonOutput::success(&job);
+                        println!("{}", output.to_json_string()?);
+                    } else {
+                        println!(
+                            "{} [{:?}] {} matched, {} modified, {} errors",
+                            job.id,
+                            job.status,
+                            job.result.summary.total_matched,
+                            job.result.summary.total_modified,
+                            job.result.summary.total_errors
+                        );
+                    }
+                }
+                Err(e) => {
+                    if json {
+                        use jit::output::JsonError;
+                        let json_error =
+                            JsonError::new(jit::commands::classify_job_error(&e), e.to_string());
+                        println!("{}", json_error.to_json_string()?);
+                        std::process::exit(json_error.exit_code().code());
+                    } else {
+                        return Err(e);
+                    }
+                }
+            },
+        },
     }
 
     Ok(())
 }
+
+/// Handle the subset of commands supported against `--remote`: the
+/// query/claim/report surface `jit-server` exposes over HTTP. Every other
+/// command requires a local `.jit` directory and errors out here instead
+/// of silently falling back to one.
+fn run_remote(client: jit::remote_client::RemoteClient, command: Commands) -> Result<()> {
+    match command {
+        Commands::Query(query_cmd) => match query_cmd {
+            jit::cli::QueryCommands::Ready { json } => {
+                let issues = client.query_ready()?;
+                print_remote_issues("Ready issues (unassigned, unblocked):", &issues, json)
+            }
+            jit::cli::QueryCommands::Blocked { json } => {
+                let blocked = client.query_blocked()?;
+                if json {
+                    println!("{}", serde_json::to_string_pretty(&blocked)?);
+                } else {
+                    println!("Blocked issues:");
+                    for (issue, reasons) in &blocked {
+                        println!("  {} | {} | {:?}", issue.id, issue.title, issue.priority);
+                        for reason in reasons {
+                            println!("    - {}", reason);
+                        }
+                    }
+                    println!("\nTotal: {}", blocked.len());
+                }
+                Ok(())
+            }
+            jit::cli::QueryCommands::Assignee { assignee, json } => {
+                let issues = client.query_by_assignee(&assignee)?;
+                print_remote_issues(&format!("Issues assigned to {}:", assignee), &issues, json)
+            }
+            jit::cli::QueryCommands::State { state, json } => {
+                let parsed_state = parse_state(&state)?;
+                let issues = client.query_by_state(parsed_state)?;
+                print_remote_issues(&format!("Issues with state '{}':", state), &issues, json)
+            }
+            jit::cli::QueryCommands::Label { pattern, json } => {
+                let issues = client.query_by_label(&pattern)?;
+                print_remote_issues(&format!("Issues matching label '{}':", pattern), &issues, json)
+            }
+            _ => Err(anyhow!(
+                "that query is not supported in --remote mode; only ready/blocked/assignee/state/label queries are served remotely"
+            )),
+        },
+        Commands::Issue(issue_cmd) => match issue_cmd {
+            IssueCommands::Claim { id, assignee, json } => {
+                let issue = client.claim_issue(&id, &assignee)?;
+                if json {
+                    println!("{}", serde_json::to_string_pretty(&issue)?);
+                } else {
+                    println!("Claimed issue: {}", id);
+                }
+                Ok(())
+            }
+            _ => Err(anyhow!(
+                "only 'jit issue claim' is supported in --remote mode; other issue commands require a local repository"
+            )),
+        },
+        Commands::Report {
+            id,
+            status,
+            message,
+            json,
+        } => {
+            let status = parse_report_status(&status)?;
+            client.report_issue(&id, status, message)?;
+            if json {
+                println!("{}", serde_json::json!({"id": id, "status": status.to_string()}));
+            } else {
+                println!("Reported {} on issue: {}", status, id);
+            }
+            Ok(())
+        }
+        _ => Err(anyhow!(
+            "that command is not supported in --remote mode; only query/claim/report commands are served remotely"
+        )),
+    }
+}
+
+fn print_remote_issues(heading: &str, issues: &[jit::Issue], json: bool) -> Result<()> {
+    if json {
+        println!("{}", serde_json::to_string_pretty(issues)?);
+    } else {
+        println!("{}", heading);
+        for issue in issues {
+            println!("  {} | {} | {:?}", issue.id, issue.title, issue.priority);
+        }
+        println!("\nTotal: {}", issues.len());
+    }
+    Ok(())
+}
@@ -0,0 +1,595 @@
+//! Event-driven notifier with pluggable delivery sinks.
+//!
+//! Every [`Event`] appended to the log during normal command execution is
+//! matched against the sinks configured in the `[notifier]` section of
+//! `.jit/config.toml` (see [`crate::config::NotifierConfigToml`]). A sink
+//! names the event types it cares about (matching [`Event::get_type`]) and
+//! may optionally narrow to a specific assignee or a minimum [`Priority`],
+//! so a team can, for example, wire a webhook to fire only on
+//! `gate_failed` events for `critical` issues.
+//!
+//! Three sink kinds are supported:
+//! - `webhook`: POST the event's JSON to a URL, retrying with exponential
+//!   backoff on failure.
+//! - `command`: pipe the event's JSON to a shell command's stdin,
+//!   mirroring [`crate::commands::transition_hooks`]'s stdin-piping
+//!   convention.
+//! - `watch`: append to `.jit/watch.jsonl`, the feed `jit watch` tails.
+//!
+//! Deliveries to a given sink are debounced so a burst of events from one
+//! command (a `jit batch` run, a gate sweep) doesn't fire the same webhook
+//! or shell command once per event. Wiring this subsystem into command
+//! execution (deciding which appended events to dispatch, and recording
+//! delivery outcomes so a failure is never silently dropped) lives in
+//! [`crate::commands::notify`].
+
+use crate::config::{NotifierConfigToml, SinkToml};
+use crate::domain::{Event, Issue, Priority, State};
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// How a sink delivers a matched event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SinkKind {
+    /// POST the event's JSON serialization to `url`.
+    Webhook { url: String },
+    /// Pipe the event's JSON serialization to a shell command's stdin.
+    Command { command: String },
+    /// Append to the local `jit watch` feed.
+    Watch,
+}
+
+/// A configured notification sink, resolved from [`SinkToml`].
+#[derive(Debug, Clone)]
+pub struct SinkRule {
+    pub name: String,
+    pub event_types: Vec<String>,
+    pub assignee: Option<String>,
+    pub min_priority: Option<Priority>,
+    /// Only fire for `issue_state_changed` events landing on this state.
+    pub to_state: Option<State>,
+    /// Only fire for issues with a label matching this pattern (e.g.
+    /// `epic:*`), using the same wildcard rules as `jit query --label`.
+    pub label_pattern: Option<String>,
+    pub kind: SinkKind,
+}
+
+/// Resolved `[notifier]` configuration.
+#[derive(Debug, Clone, Default)]
+pub struct NotifierConfig {
+    pub sinks: Vec<SinkRule>,
+}
+
+fn priority_rank(priority: Priority) -> u8 {
+    match priority {
+        Priority::Critical => 0,
+        Priority::High => 1,
+        Priority::Normal => 2,
+        Priority::Low => 3,
+    }
+}
+
+fn parse_priority_name(name: &str) -> Result<Priority> {
+    match name.to_lowercase().as_str() {
+        "low" => Ok(Priority::Low),
+        "normal" => Ok(Priority::Normal),
+        "high" => Ok(Priority::High),
+        "critical" => Ok(Priority::Critical),
+        other => Err(anyhow!(
+            "Unknown notifier min_priority '{}': must be one of low, normal, high, critical",
+            other
+        )),
+    }
+}
+
+fn parse_state_name(name: &str) -> Result<State> {
+    match name.to_lowercase().as_str() {
+        "backlog" => Ok(State::Backlog),
+        "ready" => Ok(State::Ready),
+        "in_progress" | "inprogress" => Ok(State::InProgress),
+        "gated" => Ok(State::Gated),
+        "done" => Ok(State::Done),
+        "rejected" => Ok(State::Rejected),
+        "archived" => Ok(State::Archived),
+        "overdue" => Ok(State::Overdue),
+        other => Err(anyhow!("Unknown notifier to_state '{}'", other)),
+    }
+}
+
+/// The state an event's transition lands on, if any. Only
+/// `IssueStateChanged` carries a target state; every other event type
+/// (including the stall-recovery `IssueReleased` emitted by
+/// `jit-dispatch`'s stall sweep) has none, so a `to_state` filter never
+/// matches them directly -- pair it with `event_types = ["issue_state_changed"]`
+/// to watch a specific recovery transition, e.g. back to `ready`.
+fn event_to_state(event: &Event) -> Option<State> {
+    match event {
+        Event::IssueStateChanged { to, .. } => Some(*to),
+        _ => None,
+    }
+}
+
+impl NotifierConfig {
+    /// Resolve a [`NotifierConfigToml`] (as loaded from `.jit/config.toml`)
+    /// into a validated `NotifierConfig`. Returns an empty config (no
+    /// sinks) if the `[notifier]` section is absent.
+    pub fn from_toml(toml: Option<&NotifierConfigToml>) -> Result<Self> {
+        let Some(toml) = toml else {
+            return Ok(Self::default());
+        };
+
+        let sinks = toml
+            .sinks
+            .iter()
+            .map(SinkRule::from_toml)
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self { sinks })
+    }
+
+    /// Sinks configured for `event`, given the associated issue's current
+    /// assignee, priority, and labels (`None`/empty when the event's issue
+    /// no longer resolves, e.g. after deletion).
+    pub fn matching_sinks(
+        &self,
+        event: &Event,
+        issue_assignee: Option<&str>,
+        issue_priority: Option<Priority>,
+        issue_labels: &[String],
+    ) -> Vec<&SinkRule> {
+        self.sinks
+            .iter()
+            .filter(|sink| sink.matches(event, issue_assignee, issue_priority, issue_labels))
+            .collect()
+    }
+}
+
+impl SinkRule {
+    fn from_toml(toml: &SinkToml) -> Result<Self> {
+        let kind = match toml.kind.as_str() {
+            "webhook" => SinkKind::Webhook {
+                url: toml.url.clone().ok_or_else(|| {
+                    anyhow!("sink '{}': webhook sink requires a 'url'", toml.name)
+                })?,
+            },
+            "command" => SinkKind::Command {
+                command: toml.command.clone().ok_or_else(|| {
+                    anyhow!("sink '{}': command sink requires a 'command'", toml.name)
+                })?,
+            },
+            "watch" => SinkKind::Watch,
+            other => {
+                return Err(anyhow!(
+                    "sink '{}': unknown kind '{}', must be one of webhook, command, watch",
+                    toml.name,
+                    other
+                ))
+            }
+        };
+
+        let min_priority = toml
+            .min_priority
+            .as_deref()
+            .map(parse_priority_name)
+            .transpose()?;
+
+        let to_state = toml.to_state.as_deref().map(parse_state_name).transpose()?;
+
+        Ok(Self {
+            name: toml.name.clone(),
+            event_types: toml.event_types.clone(),
+            assignee: toml.assignee.clone(),
+            min_priority,
+            to_state,
+            label_pattern: toml.label_pattern.clone(),
+            kind,
+        })
+    }
+
+    fn matches(
+        &self,
+        event: &Event,
+        issue_assignee: Option<&str>,
+        issue_priority: Option<Priority>,
+        issue_labels: &[String],
+    ) -> bool {
+        if !self.event_types.is_empty() && !self.event_types.iter().any(|t| t == event.get_type()) {
+            return false;
+        }
+
+        if let Some(want) = &self.assignee {
+            if issue_assignee != Some(want.as_str()) {
+                return false;
+            }
+        }
+
+        if let Some(min) = self.min_priority {
+            match issue_priority {
+                Some(p) if priority_rank(p) <= priority_rank(min) => {}
+                _ => return false,
+            }
+        }
+
+        if let Some(want) = self.to_state {
+            if event_to_state(event) != Some(want) {
+                return false;
+            }
+        }
+
+        if let Some(pattern) = &self.label_pattern {
+            if !crate::labels::matches_pattern(issue_labels, pattern) {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+/// The structured JSON a sink actually receives, decoupled from
+/// [`Event`]'s per-variant shape so every sink sees the same fields
+/// regardless of which event triggered delivery.
+#[derive(Debug, Clone, Serialize)]
+pub struct NotificationPayload {
+    pub event_type: String,
+    pub event_id: String,
+    pub issue_id: String,
+    pub title: Option<String>,
+    pub from_state: Option<State>,
+    pub to_state: Option<State>,
+    pub assignee: Option<String>,
+    pub timestamp: DateTime<Utc>,
+}
+
+fn build_payload(event: &Event, issue: Option<&Issue>) -> NotificationPayload {
+    let (from_state, to_state) = match event {
+        Event::IssueStateChanged { from, to, .. } => (Some(*from), Some(*to)),
+        _ => (None, None),
+    };
+
+    NotificationPayload {
+        event_type: event.get_type().to_string(),
+        event_id: event.get_id().to_string(),
+        issue_id: event.get_issue_id().to_string(),
+        title: issue.map(|i| i.title.clone()),
+        from_state,
+        to_state,
+        assignee: issue.and_then(|i| i.assignee.clone()),
+        timestamp: event.get_timestamp(),
+    }
+}
+
+/// Outcome of one delivery attempt, recorded so a failed or skipped
+/// delivery is never silently dropped.
+#[derive(Debug, Clone, Serialize)]
+pub struct DeliveryOutcome {
+    pub sink: String,
+    pub event_id: String,
+    pub event_type: String,
+    pub delivered: bool,
+    pub attempts: u32,
+    pub debounced: bool,
+    pub error: Option<String>,
+}
+
+/// Minimum spacing between deliveries to the same sink.
+const DEBOUNCE_WINDOW: Duration = Duration::from_millis(250);
+
+/// Maximum attempts for a webhook delivery before giving up.
+const MAX_WEBHOOK_ATTEMPTS: u32 = 3;
+
+/// Base delay for webhook retry backoff; doubles after each failed attempt.
+const WEBHOOK_RETRY_BASE_DELAY: Duration = Duration::from_millis(200);
+
+/// Per-process last-delivery timestamps, keyed by sink name, used to
+/// debounce rapid-fire deliveries within a single command invocation (e.g.
+/// a `jit batch` run appending many events back to back).
+static LAST_DELIVERY: Mutex<Option<HashMap<String, Instant>>> = Mutex::new(None);
+
+fn should_debounce(sink_name: &str) -> bool {
+    let mut guard = LAST_DELIVERY.lock().unwrap();
+    let map = guard.get_or_insert_with(HashMap::new);
+    let now = Instant::now();
+    if let Some(last) = map.get(sink_name) {
+        if now.duration_since(*last) < DEBOUNCE_WINDOW {
+            return true;
+        }
+    }
+    map.insert(sink_name.to_string(), now);
+    false
+}
+
+/// Deliver `event` to `sink`, retrying webhook failures with exponential
+/// backoff. Never panics or returns an error to the caller -- a sink being
+/// unreachable must not abort the command that triggered the
+/// notification; the returned [`DeliveryOutcome`] is how failures surface.
+/// `issue` supplies the title/assignee enrichment in the delivered
+/// [`NotificationPayload`]; pass `None` if the issue no longer resolves
+/// (e.g. after deletion).
+pub fn deliver(sink: &SinkRule, event: &Event, issue: Option<&Issue>, jit_root: &Path) -> DeliveryOutcome {
+    let event_id = event.get_id().to_string();
+    let event_type = event.get_type().to_string();
+
+    if should_debounce(&sink.name) {
+        return DeliveryOutcome {
+            sink: sink.name.clone(),
+            event_id,
+            event_type,
+            delivered: false,
+            attempts: 0,
+            debounced: true,
+            error: None,
+        };
+    }
+
+    let payload = match serde_json::to_vec(&build_payload(event, issue)) {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            return DeliveryOutcome {
+                sink: sink.name.clone(),
+                event_id,
+                event_type,
+                delivered: false,
+                attempts: 0,
+                debounced: false,
+                error: Some(format!("failed to serialize event: {}", e)),
+            }
+        }
+    };
+
+    let (delivered, attempts, error) = match &sink.kind {
+        SinkKind::Webhook { url } => deliver_webhook_with_retry(url, &payload),
+        SinkKind::Command { command } => {
+            let result = deliver_command(command, &payload);
+            (result.is_ok(), 1, result.err())
+        }
+        SinkKind::Watch => {
+            let result = deliver_watch(jit_root, &payload);
+            (result.is_ok(), 1, result.err())
+        }
+    };
+
+    DeliveryOutcome {
+        sink: sink.name.clone(),
+        event_id,
+        event_type,
+        delivered,
+        attempts,
+        debounced: false,
+        error,
+    }
+}
+
+fn deliver_webhook_with_retry(url: &str, payload: &[u8]) -> (bool, u32, Option<String>) {
+    let mut last_error = None;
+    for attempt in 1..=MAX_WEBHOOK_ATTEMPTS {
+        match post_webhook(url, payload) {
+            Ok(()) => return (true, attempt, None),
+            Err(e) => {
+                last_error = Some(e);
+                if attempt < MAX_WEBHOOK_ATTEMPTS {
+                    std::thread::sleep(WEBHOOK_RETRY_BASE_DELAY * 2u32.pow(attempt - 1));
+                }
+            }
+        }
+    }
+    (false, MAX_WEBHOOK_ATTEMPTS, last_error)
+}
+
+fn post_webhook(url: &str, payload: &[u8]) -> Result<(), String> {
+    ureq::post(url)
+        .set("Content-Type", "application/json")
+        .send_bytes(payload)
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+}
+
+/// Spawn `command` via the shell and pipe `payload` to its stdin, matching
+/// [`crate::commands::transition_hooks`]'s stdin-piping convention.
+fn deliver_command(command: &str, payload: &[u8]) -> Result<(), String> {
+    let mut child = Command::new("sh")
+        .arg("-c")
+        .arg(command)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| e.to_string())?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(payload).map_err(|e| e.to_string())?;
+    }
+
+    let output = child.wait_with_output().map_err(|e| e.to_string())?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).to_string())
+    }
+}
+
+/// Append `payload` as a line to `.jit/watch.jsonl`, the feed `jit watch`
+/// tails.
+fn deliver_watch(jit_root: &Path, payload: &[u8]) -> Result<(), String> {
+    let path = jit_root.join("watch.jsonl");
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .map_err(|e| e.to_string())?;
+    file.write_all(payload).map_err(|e| e.to_string())?;
+    file.write_all(b"\n").map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::Issue;
+    use tempfile::TempDir;
+
+    fn sink(kind: SinkKind, event_types: Vec<&str>) -> SinkRule {
+        SinkRule {
+            name: "test-sink".to_string(),
+            event_types: event_types.into_iter().map(String::from).collect(),
+            assignee: None,
+            min_priority: None,
+            to_state: None,
+            label_pattern: None,
+            kind,
+        }
+    }
+
+    #[test]
+    fn test_empty_config_has_no_sinks() {
+        let config = NotifierConfig::from_toml(None).unwrap();
+        assert!(config.sinks.is_empty());
+    }
+
+    #[test]
+    fn test_sink_from_toml_requires_url_for_webhook() {
+        let toml = SinkToml {
+            name: "hook".to_string(),
+            event_types: vec![],
+            assignee: None,
+            min_priority: None,
+            to_state: None,
+            label_pattern: None,
+            kind: "webhook".to_string(),
+            url: None,
+            command: None,
+        };
+        assert!(SinkRule::from_toml(&toml).is_err());
+    }
+
+    #[test]
+    fn test_matches_filters_by_event_type() {
+        let issue = Issue::new("Test".to_string(), String::new());
+        let event = Event::new_issue_created(&issue);
+
+        let matching = sink(SinkKind::Watch, vec!["issue_created"]);
+        assert!(matching.matches(&event, None, None, &[]));
+
+        let non_matching = sink(SinkKind::Watch, vec!["gate_failed"]);
+        assert!(!non_matching.matches(&event, None, None, &[]));
+    }
+
+    #[test]
+    fn test_matches_filters_by_min_priority() {
+        let mut rule = sink(SinkKind::Watch, vec![]);
+        rule.min_priority = Some(Priority::Critical);
+
+        let issue = Issue::new("Test".to_string(), String::new());
+        let event = Event::new_issue_created(&issue);
+
+        assert!(!rule.matches(&event, None, Some(Priority::Normal), &[]));
+        assert!(rule.matches(&event, None, Some(Priority::Critical), &[]));
+    }
+
+    #[test]
+    fn test_matches_filters_by_assignee() {
+        let mut rule = sink(SinkKind::Watch, vec![]);
+        rule.assignee = Some("human:alice".to_string());
+
+        let issue = Issue::new("Test".to_string(), String::new());
+        let event = Event::new_issue_created(&issue);
+
+        assert!(!rule.matches(&event, Some("human:bob"), None, &[]));
+        assert!(rule.matches(&event, Some("human:alice"), None, &[]));
+    }
+
+    #[test]
+    fn test_matches_filters_by_to_state() {
+        let mut rule = sink(SinkKind::Watch, vec![]);
+        rule.to_state = Some(State::Rejected);
+
+        let to_rejected =
+            Event::new_issue_state_changed("issue-1".to_string(), State::InProgress, State::Rejected);
+        let to_done =
+            Event::new_issue_state_changed("issue-1".to_string(), State::InProgress, State::Done);
+
+        assert!(rule.matches(&to_rejected, None, None, &[]));
+        assert!(!rule.matches(&to_done, None, None, &[]));
+
+        // An event with no target state (e.g. a stall-recovery release)
+        // never matches a to_state filter.
+        let released = Event::new_issue_released(
+            "issue-1".to_string(),
+            "agent:bot".to_string(),
+            "stalled: no heartbeat".to_string(),
+        );
+        assert!(!rule.matches(&released, None, None, &[]));
+    }
+
+    #[test]
+    fn test_matches_filters_by_label_pattern() {
+        let mut rule = sink(SinkKind::Watch, vec![]);
+        rule.label_pattern = Some("epic:*".to_string());
+
+        let issue = Issue::new("Test".to_string(), String::new());
+        let event = Event::new_issue_created(&issue);
+
+        assert!(!rule.matches(&event, None, None, &["milestone:v1.0".to_string()]));
+        assert!(rule.matches(&event, None, None, &["epic:auth".to_string()]));
+    }
+
+    #[test]
+    fn test_deliver_watch_appends_event_json() {
+        let temp = TempDir::new().unwrap();
+        let issue = Issue::new("Test".to_string(), String::new());
+        let event = Event::new_issue_created(&issue);
+        let rule = sink(SinkKind::Watch, vec![]);
+
+        let outcome = deliver(&rule, &event, Some(&issue), temp.path());
+        assert!(outcome.delivered);
+
+        let contents = std::fs::read_to_string(temp.path().join("watch.jsonl")).unwrap();
+        assert!(contents.contains(&event.get_id().to_string()));
+        assert!(contents.contains("Test"));
+    }
+
+    #[test]
+    fn test_deliver_command_runs_and_receives_payload() {
+        let temp = TempDir::new().unwrap();
+        let out_path = temp.path().join("out.json");
+        let issue = Issue::new("Test".to_string(), String::new());
+        let event = Event::new_issue_created(&issue);
+        let rule = sink(
+            SinkKind::Command {
+                command: format!("cat > {}", out_path.display()),
+            },
+            vec![],
+        );
+
+        let outcome = deliver(&rule, &event, Some(&issue), temp.path());
+        assert!(outcome.delivered);
+
+        let contents = std::fs::read_to_string(&out_path).unwrap();
+        assert!(contents.contains(&event.get_id().to_string()));
+    }
+
+    #[test]
+    fn test_second_delivery_within_window_is_debounced() {
+        let temp = TempDir::new().unwrap();
+        let issue = Issue::new("Test".to_string(), String::new());
+        let event = Event::new_issue_created(&issue);
+        let rule = sink(SinkKind::Watch, vec![]);
+        // Use a sink name unique to this test so other tests' timestamps
+        // in the shared process-wide debounce map can't interfere.
+        let rule = SinkRule {
+            name: format!("debounce-test-{}", event.get_id()),
+            ..rule
+        };
+
+        let first = deliver(&rule, &event, Some(&issue), temp.path());
+        assert!(first.delivered);
+        let second = deliver(&rule, &event, Some(&issue), temp.path());
+        assert!(second.debounced);
+    }
+}
@@ -220,17 +220,36 @@ impl ErrorCode {
     pub const BLOCKED: &'static str = "BLOCKED";
     pub const IO_ERROR: &'static str = "IO_ERROR";
     pub const PARSE_ERROR: &'static str = "PARSE_ERROR";
+    pub const QUERY_PARSE_ERROR: &'static str = "QUERY_PARSE_ERROR";
+    pub const INVALID_LABEL_PATTERN: &'static str = "INVALID_LABEL_PATTERN";
+    pub const MISSING_ID_OR_FILTER: &'static str = "MISSING_ID_OR_FILTER";
+    pub const ID_AND_FILTER_CONFLICT: &'static str = "ID_AND_FILTER_CONFLICT";
+    pub const GATE_NOT_SATISFIED: &'static str = "GATE_NOT_SATISFIED";
+    pub const INVALID_STATE_TRANSITION: &'static str = "INVALID_STATE_TRANSITION";
+    pub const JOB_NOT_FOUND: &'static str = "JOB_NOT_FOUND";
+    pub const MALFORMED_JOB: &'static str = "MALFORMED_JOB";
 }
 
 impl ErrorCode {
     /// Map error code string to exit code
     pub fn to_exit_code(code: &str) -> ExitCode {
         match code {
-            Self::ISSUE_NOT_FOUND | Self::GATE_NOT_FOUND => ExitCode::NotFound,
-            Self::CYCLE_DETECTED | Self::VALIDATION_FAILED => ExitCode::ValidationFailed,
-            Self::INVALID_ARGUMENT | Self::INVALID_STATE => ExitCode::InvalidArgument,
+            Self::ISSUE_NOT_FOUND | Self::GATE_NOT_FOUND | Self::JOB_NOT_FOUND => {
+                ExitCode::NotFound
+            }
+            Self::CYCLE_DETECTED | Self::VALIDATION_FAILED | Self::GATE_NOT_SATISFIED => {
+                ExitCode::ValidationFailed
+            }
+            Self::INVALID_ARGUMENT
+            | Self::INVALID_STATE
+            | Self::INVALID_LABEL_PATTERN
+            | Self::MISSING_ID_OR_FILTER
+            | Self::ID_AND_FILTER_CONFLICT
+            | Self::INVALID_STATE_TRANSITION
+            | Self::MALFORMED_JOB => ExitCode::InvalidArgument,
             Self::ALREADY_EXISTS => ExitCode::AlreadyExists,
             Self::IO_ERROR => ExitCode::ExternalError,
+            Self::QUERY_PARSE_ERROR => ExitCode::InvalidArgument,
             _ => ExitCode::GenericError,
         }
     }
@@ -286,6 +305,21 @@ impl JsonError {
         .with_details(serde_json::json!({"invalid_priority": priority}))
         .with_suggestion("Valid priorities are: low, normal, high, critical")
     }
+
+    /// Build an error for a query filter that failed to parse.
+    ///
+    /// `position`/`length` locate the offending span within `query` so
+    /// automation can underline it the way human-mode output does with a
+    /// caret (see `QueryParseError::render`).
+    pub fn query_parse_error(query: &str, message: &str, position: usize, length: usize) -> Self {
+        Self::new(ErrorCode::QUERY_PARSE_ERROR, message)
+            .with_details(serde_json::json!({
+                "query": query,
+                "position": position,
+                "length": length,
+            }))
+            .with_suggestion("Run 'jit query' with the corrected filter expression")
+    }
 }
 
 /// Metadata included in all responses
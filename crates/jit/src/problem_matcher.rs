@@ -0,0 +1,347 @@
+//! Problem-matcher subsystem for turning checker command output into
+//! structured diagnostics.
+//!
+//! Mirrors the pattern editors use to parse rustfmt/clippy output: a
+//! [`ProblemMatcher`] names an `owner`, a default severity, and an ordered
+//! list of [`MatcherPattern`] entries. A single-pattern matcher emits one
+//! [`Diagnostic`] per matching line; a multi-line matcher applies its
+//! patterns in sequence against consecutive lines -- the classic "message
+//! line, then a `--> file:line:col` line" shape -- and only emits a
+//! diagnostic once every pattern in the sequence has matched.
+
+use anyhow::{Context, Result};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+/// Severity of a parsed [`Diagnostic`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiagnosticSeverity {
+    Error,
+    Warning,
+    Info,
+}
+
+impl DiagnosticSeverity {
+    /// Parse a severity captured from checker output (e.g. `"error"`,
+    /// `"warning: unused variable"`). Returns `None` if the text doesn't
+    /// start with a recognized severity word, so callers can fall back to
+    /// the matcher's default.
+    fn parse(text: &str) -> Option<Self> {
+        let word = text.trim().split(|c: char| !c.is_alphabetic()).next()?;
+        match word.to_lowercase().as_str() {
+            "error" => Some(Self::Error),
+            "warning" | "warn" => Some(Self::Warning),
+            "info" | "note" => Some(Self::Info),
+            _ => None,
+        }
+    }
+}
+
+/// One line of a (possibly multi-line) [`ProblemMatcher`] pattern.
+///
+/// Each numbered field is a 1-based capture-group index into `regexp`;
+/// `None` means this line doesn't carry that piece of information.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MatcherPattern {
+    pub regexp: String,
+    #[serde(default)]
+    pub file: Option<usize>,
+    #[serde(default)]
+    pub line: Option<usize>,
+    #[serde(default)]
+    pub column: Option<usize>,
+    #[serde(default)]
+    pub severity: Option<usize>,
+    #[serde(default)]
+    pub code: Option<usize>,
+    #[serde(default)]
+    pub message: Option<usize>,
+}
+
+/// A named problem matcher: how to turn a checker's stdout/stderr into
+/// structured [`Diagnostic`]s. Stored in preset JSON alongside gates so
+/// custom presets can describe how to read cargo, clippy, eslint, etc.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProblemMatcher {
+    pub owner: String,
+    #[serde(default = "default_severity")]
+    pub severity: DiagnosticSeverity,
+    pub pattern: Vec<MatcherPattern>,
+}
+
+fn default_severity() -> DiagnosticSeverity {
+    DiagnosticSeverity::Error
+}
+
+/// A single structured diagnostic extracted from checker output.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Diagnostic {
+    pub owner: String,
+    pub file: Option<String>,
+    pub line: Option<u32>,
+    pub column: Option<u32>,
+    pub severity: DiagnosticSeverity,
+    pub code: Option<String>,
+    pub message: Option<String>,
+}
+
+/// Strip ANSI escape sequences (e.g. the color codes in `cargo clippy`'s
+/// terminal output) before matching, so patterns only have to deal with
+/// plain text.
+pub fn strip_ansi(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    let mut chars = input.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\u{1b}' && chars.peek() == Some(&'[') {
+            chars.next();
+            for next in chars.by_ref() {
+                if next.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+fn capture_str(caps: &regex::Captures, group: Option<usize>) -> Option<String> {
+    let text = caps.get(group?)?.as_str().trim();
+    if text.is_empty() {
+        None
+    } else {
+        Some(text.to_string())
+    }
+}
+
+fn capture_num(caps: &regex::Captures, group: Option<usize>) -> Option<u32> {
+    capture_str(caps, group)?.parse().ok()
+}
+
+impl ProblemMatcher {
+    /// Compile this matcher's patterns, failing fast with the owner name if
+    /// one of them isn't a valid regex.
+    fn compile(&self) -> Result<Vec<Regex>> {
+        self.pattern
+            .iter()
+            .map(|p| {
+                Regex::new(&p.regexp).with_context(|| {
+                    format!(
+                        "invalid problem-matcher regexp for owner '{}': {}",
+                        self.owner, p.regexp
+                    )
+                })
+            })
+            .collect()
+    }
+
+    /// Run this matcher over `output`, returning every diagnostic it can
+    /// extract. `output` is stripped of ANSI escapes before matching.
+    ///
+    /// A multi-line matcher (`pattern.len() > 1`) requires its patterns to
+    /// match on consecutive lines starting from wherever the first pattern
+    /// matched; a partial match (the sequence breaks before the last
+    /// pattern) yields no diagnostic and scanning resumes one line later.
+    pub fn run(&self, output: &str) -> Result<Vec<Diagnostic>> {
+        if self.pattern.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let compiled = self.compile()?;
+        let text = strip_ansi(output);
+        let lines: Vec<&str> = text.lines().collect();
+
+        let mut diagnostics = Vec::new();
+        let mut i = 0;
+        while i < lines.len() {
+            if let Some(first_caps) = compiled[0].captures(lines[i]) {
+                let mut file = capture_str(&first_caps, self.pattern[0].file);
+                let mut line = capture_num(&first_caps, self.pattern[0].line);
+                let mut column = capture_num(&first_caps, self.pattern[0].column);
+                let mut severity = capture_str(&first_caps, self.pattern[0].severity)
+                    .as_deref()
+                    .and_then(DiagnosticSeverity::parse)
+                    .unwrap_or(self.severity);
+                let mut code = capture_str(&first_caps, self.pattern[0].code);
+                let mut message = capture_str(&first_caps, self.pattern[0].message);
+
+                let mut consumed = 1;
+                let mut complete = true;
+                for (pattern, regex) in self.pattern.iter().zip(compiled.iter()).skip(1) {
+                    match lines.get(i + consumed).and_then(|line| regex.captures(line)) {
+                        Some(caps) => {
+                            file = file.or_else(|| capture_str(&caps, pattern.file));
+                            line = line.or_else(|| capture_num(&caps, pattern.line));
+                            column = column.or_else(|| capture_num(&caps, pattern.column));
+                            if let Some(s) = capture_str(&caps, pattern.severity)
+                                .as_deref()
+                                .and_then(DiagnosticSeverity::parse)
+                            {
+                                severity = s;
+                            }
+                            code = code.or_else(|| capture_str(&caps, pattern.code));
+                            message = message.or_else(|| capture_str(&caps, pattern.message));
+                            consumed += 1;
+                        }
+                        None => {
+                            complete = false;
+                            break;
+                        }
+                    }
+                }
+
+                if complete {
+                    diagnostics.push(Diagnostic {
+                        owner: self.owner.clone(),
+                        file,
+                        line,
+                        column,
+                        severity,
+                        code,
+                        message,
+                    });
+                    i += consumed;
+                    continue;
+                }
+            }
+            i += 1;
+        }
+
+        Ok(diagnostics)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn clippy_matcher() -> ProblemMatcher {
+        ProblemMatcher {
+            owner: "clippy".to_string(),
+            severity: DiagnosticSeverity::Error,
+            pattern: vec![
+                MatcherPattern {
+                    regexp: r"^(error|warning)(?:\[(\w+)\])?: (.+)$".to_string(),
+                    severity: Some(1),
+                    code: Some(2),
+                    message: Some(3),
+                    file: None,
+                    line: None,
+                    column: None,
+                },
+                MatcherPattern {
+                    regexp: r"^\s*--> (.+):(\d+):(\d+)$".to_string(),
+                    file: Some(1),
+                    line: Some(2),
+                    column: Some(3),
+                    severity: None,
+                    code: None,
+                    message: None,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_strip_ansi_removes_color_codes() {
+        let colored = "\u{1b}[0m\u{1b}[1m\u{1b}[38;5;9merror\u{1b}[0m: mismatched types";
+        assert_eq!(strip_ansi(colored), "error: mismatched types");
+    }
+
+    #[test]
+    fn test_multiline_clippy_output_extracts_file_line_column_code_message() {
+        let output = "\
+warning: unused variable: `x`
+ --> src/main.rs:3:9
+  |
+3 |     let x = 5;
+  |         ^ help: if this is intentional, prefix it with an underscore
+
+error[E0308]: mismatched types
+ --> src/lib.rs:42:5
+  |
+";
+        let diagnostics = clippy_matcher().run(output).unwrap();
+
+        assert_eq!(diagnostics.len(), 2);
+
+        assert_eq!(diagnostics[0].severity, DiagnosticSeverity::Warning);
+        assert_eq!(diagnostics[0].file.as_deref(), Some("src/main.rs"));
+        assert_eq!(diagnostics[0].line, Some(3));
+        assert_eq!(diagnostics[0].column, Some(9));
+        assert_eq!(diagnostics[0].message.as_deref(), Some("unused variable: `x`"));
+        assert_eq!(diagnostics[0].code, None);
+
+        assert_eq!(diagnostics[1].severity, DiagnosticSeverity::Error);
+        assert_eq!(diagnostics[1].file.as_deref(), Some("src/lib.rs"));
+        assert_eq!(diagnostics[1].line, Some(42));
+        assert_eq!(diagnostics[1].column, Some(5));
+        assert_eq!(diagnostics[1].code.as_deref(), Some("E0308"));
+        assert_eq!(diagnostics[1].message.as_deref(), Some("mismatched types"));
+    }
+
+    #[test]
+    fn test_colored_clippy_output_strips_ansi_before_matching() {
+        let output = "\u{1b}[0m\u{1b}[1m\u{1b}[38;5;9merror\u{1b}[0m\u{1b}[1m: mismatched types\u{1b}[0m\n\u{1b}[0m \u{1b}[0m\u{1b}[1m\u{1b}[38;5;12m--> \u{1b}[0msrc/lib.rs:42:5\n";
+        let diagnostics = clippy_matcher().run(output).unwrap();
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].file.as_deref(), Some("src/lib.rs"));
+        assert_eq!(diagnostics[0].line, Some(42));
+    }
+
+    #[test]
+    fn test_incomplete_sequence_yields_no_diagnostic() {
+        let output = "error: something broke\nno location line here\n";
+        let diagnostics = clippy_matcher().run(output).unwrap();
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_invalid_regexp_is_rejected() {
+        let matcher = ProblemMatcher {
+            owner: "broken".to_string(),
+            severity: DiagnosticSeverity::Error,
+            pattern: vec![MatcherPattern {
+                regexp: "(unclosed".to_string(),
+                file: None,
+                line: None,
+                column: None,
+                severity: None,
+                code: None,
+                message: None,
+            }],
+        };
+
+        let result = matcher.run("anything");
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("broken"));
+    }
+
+    #[test]
+    fn test_single_pattern_matcher_emits_one_diagnostic_per_line() {
+        let matcher = ProblemMatcher {
+            owner: "eslint".to_string(),
+            severity: DiagnosticSeverity::Error,
+            pattern: vec![MatcherPattern {
+                regexp: r"^(.+):(\d+):(\d+): (.+)$".to_string(),
+                file: Some(1),
+                line: Some(2),
+                column: Some(3),
+                message: Some(4),
+                severity: None,
+                code: None,
+            }],
+        };
+
+        let output = "src/app.js:10:2: missing semicolon\nsrc/app.js:20:4: unused import\n";
+        let diagnostics = matcher.run(output).unwrap();
+
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(diagnostics[0].line, Some(10));
+        assert_eq!(diagnostics[1].line, Some(20));
+        assert!(diagnostics.iter().all(|d| d.severity == DiagnosticSeverity::Error));
+    }
+}
@@ -2,24 +2,77 @@
 //!
 //! This layer contains all domain knowledge and reuses existing Issue methods.
 
+use super::lexer::ComparisonOp;
 use super::parser::{QueryCondition, QueryExpr};
+use crate::document::{AdapterRegistry, DocFormatAdapter};
 use crate::domain::{Issue, Priority, State};
 use crate::labels;
+use chrono::{DateTime, Utc};
 use std::collections::HashMap;
+use std::path::PathBuf;
 use std::str::FromStr;
 
 /// Context needed for evaluating queries
 ///
-/// Contains all issues for dependency graph evaluation (blocking checks).
+/// Contains all issues for dependency graph evaluation (blocking checks),
+/// plus the optional document adapter registry and filesystem access
+/// `docs:*` conditions (see [`QueryCondition::DocHealth`]) need to scan
+/// attached documents for dangling asset/link references.
 pub struct QueryContext<'a> {
     pub all_issues: HashMap<String, &'a Issue>,
+    pub doc_adapters: Option<&'a AdapterRegistry>,
+    pub doc_fs: Option<&'a dyn DocFilesystem>,
 }
 
 impl<'a> QueryContext<'a> {
     /// Create context from issue collection
     pub fn from_issues(issues: &'a [Issue]) -> Self {
         let all_issues = issues.iter().map(|i| (i.id.clone(), i)).collect();
-        QueryContext { all_issues }
+        QueryContext {
+            all_issues,
+            doc_adapters: None,
+            doc_fs: None,
+        }
+    }
+
+    /// Attach a document adapter registry and filesystem so `docs:*`
+    /// conditions can resolve. Without this, `docs:*` conditions never
+    /// match -- the same "missing context means no match" behavior as
+    /// [`QueryCondition::Blocked`] would have against an empty `all_issues`.
+    pub fn with_doc_health(
+        mut self,
+        adapters: &'a AdapterRegistry,
+        fs: &'a dyn DocFilesystem,
+    ) -> Self {
+        self.doc_adapters = Some(adapters);
+        self.doc_fs = Some(fs);
+        self
+    }
+}
+
+/// Filesystem access needed to evaluate `docs:*` conditions, abstracted so
+/// evaluation can be tested without touching the real filesystem.
+pub trait DocFilesystem {
+    /// Read a repo-relative document's content, or `None` if it doesn't
+    /// exist or can't be read.
+    fn read_to_string(&self, repo_relative_path: &str) -> Option<String>;
+
+    /// Whether a repo-relative path exists on disk.
+    fn exists(&self, repo_relative_path: &str) -> bool;
+}
+
+/// [`DocFilesystem`] backed by the real filesystem, rooted at `repo_root`.
+pub struct RealDocFilesystem {
+    pub repo_root: PathBuf,
+}
+
+impl DocFilesystem for RealDocFilesystem {
+    fn read_to_string(&self, repo_relative_path: &str) -> Option<String> {
+        std::fs::read_to_string(self.repo_root.join(repo_relative_path)).ok()
+    }
+
+    fn exists(&self, repo_relative_path: &str) -> bool {
+        self.repo_root.join(repo_relative_path).exists()
     }
 }
 
@@ -83,10 +136,165 @@ impl QueryEvaluator {
                 // Reuse Issue::is_blocked method
                 issue.is_blocked(&ctx.all_issues)
             }
+
+            QueryCondition::Compare { field, op, value } => eval_compare(field, op, value, issue),
+
+            QueryCondition::InList { field, values } => values.iter().any(|value| {
+                let Some(sub) = single_value_condition(field, value) else {
+                    return false;
+                };
+                Self::eval_condition(&sub, issue, ctx)
+            }),
+
+            QueryCondition::Field { name, value } => {
+                issue.context.get(name).map(|actual| actual == value).unwrap_or(false)
+            }
+
+            QueryCondition::DocHealth(kind) => eval_doc_health(kind, issue, ctx),
+
+            QueryCondition::Text(needle) => {
+                let needle = needle.to_lowercase();
+                issue.title.to_lowercase().contains(&needle)
+                    || issue.description.to_lowercase().contains(&needle)
+            }
         }
     }
 }
 
+/// `docs:broken-links` and `docs:missing-assets` are the same check under
+/// two names: does any local asset one of the issue's attached documents
+/// references fail to resolve on disk? Unrecognized health names, and
+/// conditions evaluated without a [`QueryContext::with_doc_health`] context,
+/// never match -- same permissive-parse-strict-eval shape as
+/// [`QueryCondition::State`].
+fn eval_doc_health(kind: &str, issue: &Issue, ctx: &QueryContext) -> bool {
+    if kind != "broken-links" && kind != "missing-assets" {
+        return false;
+    }
+
+    let (Some(adapters), Some(fs)) = (ctx.doc_adapters, ctx.doc_fs) else {
+        return false;
+    };
+
+    issue.documents.iter().any(|doc| {
+        let Some(content) = fs.read_to_string(&doc.path) else {
+            return false;
+        };
+        let Some(adapter) = adapters.resolve(&doc.path, &content) else {
+            return false;
+        };
+
+        let base_dir = crate::document::doc_dir_segments(&doc.path);
+        adapter.scan_assets(&content).into_iter().any(|asset| {
+            if is_external_url(&asset) {
+                return false;
+            }
+            let resolved = crate::document::resolve_repo_relative(&asset, &base_dir);
+            !fs.exists(&resolved)
+        })
+    })
+}
+
+/// Whether `target` is an absolute URL or a `mailto:` link rather than a
+/// repo-relative path -- those can never be "missing" from the repo.
+fn is_external_url(target: &str) -> bool {
+    target.contains("://") || target.starts_with("mailto:")
+}
+
+/// Build the single-value `QueryCondition` that `field:value` would parse
+/// to, for expanding a [`QueryCondition::InList`] back into the OR chain it's
+/// equivalent to. `field` is always one of the known list-eligible fields --
+/// the parser rejects anything else before an `InList` is ever constructed.
+fn single_value_condition(field: &str, value: &str) -> Option<QueryCondition> {
+    match field {
+        "state" => Some(QueryCondition::State(value.to_string())),
+        "label" => Some(QueryCondition::Label(value.to_string())),
+        "priority" => Some(QueryCondition::Priority(value.to_string())),
+        "assignee" => Some(QueryCondition::Assignee(value.to_string())),
+        _ => None,
+    }
+}
+
+/// Resolve `field`'s value on `issue` and compare it against `value` with
+/// `op`.
+///
+/// `priority` ranks by severity (`low < normal < high < critical`) rather
+/// than lexically; every other field is read from `issue.context` (there's
+/// nowhere else user-defined fields like `points` could live) and compared
+/// numerically, then chronologically, then lexically -- whichever the
+/// stored value actually parses as.
+fn eval_compare(field: &str, op: &ComparisonOp, value: &str, issue: &Issue) -> bool {
+    if field == "priority" {
+        return match priority_rank(value) {
+            Some(target) => compare_ord(&priority_rank_of(issue.priority), op, &target),
+            None => false,
+        };
+    }
+
+    let Some(actual) = issue.context.get(field) else {
+        return false;
+    };
+
+    if let (Ok(actual_num), Ok(target_num)) = (actual.parse::<f64>(), value.parse::<f64>()) {
+        return compare_ord(&actual_num, op, &target_num);
+    }
+
+    if let (Some(actual_date), Some(target_date)) = (parse_date(actual), parse_date(value)) {
+        return compare_ord(&actual_date, op, &target_date);
+    }
+
+    if *op == ComparisonOp::Contains {
+        return actual.contains(value);
+    }
+
+    compare_ord(&actual.as_str(), op, &value)
+}
+
+fn compare_ord<T: PartialOrd>(actual: &T, op: &ComparisonOp, target: &T) -> bool {
+    match op {
+        ComparisonOp::Eq => actual == target,
+        ComparisonOp::Ne => actual != target,
+        ComparisonOp::Gt => actual > target,
+        ComparisonOp::Lt => actual < target,
+        ComparisonOp::Ge => actual >= target,
+        ComparisonOp::Le => actual <= target,
+        // Contains is a substring check, not an ordering -- handled by the
+        // caller before falling back here.
+        ComparisonOp::Contains => false,
+    }
+}
+
+fn priority_rank(name: &str) -> Option<u8> {
+    match name.to_lowercase().as_str() {
+        "low" => Some(0),
+        "normal" => Some(1),
+        "high" => Some(2),
+        "critical" => Some(3),
+        _ => None,
+    }
+}
+
+fn priority_rank_of(priority: Priority) -> u8 {
+    match priority {
+        Priority::Low => 0,
+        Priority::Normal => 1,
+        Priority::High => 2,
+        Priority::Critical => 3,
+    }
+}
+
+/// Parse an RFC3339 timestamp or a bare `YYYY-MM-DD` date (midnight UTC).
+fn parse_date(value: &str) -> Option<DateTime<Utc>> {
+    if let Ok(dt) = DateTime::parse_from_rfc3339(value) {
+        return Some(dt.with_timezone(&Utc));
+    }
+
+    chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|naive| DateTime::from_naive_utc_and_offset(naive, Utc))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -257,6 +465,295 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_eval_compare_priority_rank() {
+        let high = create_issue("1", State::Ready, Priority::High, None, vec![], vec![]);
+        let normal = create_issue("2", State::Ready, Priority::Normal, None, vec![], vec![]);
+        let issues = [high.clone(), normal.clone()];
+        let context = QueryContext::from_issues(&issues);
+
+        let cond = QueryCondition::Compare {
+            field: "priority".to_string(),
+            op: ComparisonOp::Gt,
+            value: "normal".to_string(),
+        };
+
+        assert!(QueryEvaluator::eval_condition(&cond, &high, &context));
+        assert!(!QueryEvaluator::eval_condition(&cond, &normal, &context));
+    }
+
+    #[test]
+    fn test_eval_compare_numeric_context_field() {
+        let mut issue = create_issue("1", State::Ready, Priority::Normal, None, vec![], vec![]);
+        issue.context.insert("points".to_string(), "8".to_string());
+        let issues = [issue.clone()];
+        let context = QueryContext::from_issues(&issues);
+
+        let cond = QueryCondition::Compare {
+            field: "points".to_string(),
+            op: ComparisonOp::Ge,
+            value: "5".to_string(),
+        };
+
+        assert!(QueryEvaluator::eval_condition(&cond, &issue, &context));
+    }
+
+    #[test]
+    fn test_eval_compare_date_context_field() {
+        let mut issue = create_issue("1", State::Ready, Priority::Normal, None, vec![], vec![]);
+        issue
+            .context
+            .insert("created".to_string(), "2023-06-01".to_string());
+        let issues = [issue.clone()];
+        let context = QueryContext::from_issues(&issues);
+
+        let cond = QueryCondition::Compare {
+            field: "created".to_string(),
+            op: ComparisonOp::Lt,
+            value: "2024-01-01".to_string(),
+        };
+
+        assert!(QueryEvaluator::eval_condition(&cond, &issue, &context));
+    }
+
+    #[test]
+    fn test_eval_compare_contains() {
+        let mut issue = create_issue("1", State::Ready, Priority::Normal, None, vec![], vec![]);
+        issue
+            .context
+            .insert("title".to_string(), "fix login bug".to_string());
+        let issues = [issue.clone()];
+        let context = QueryContext::from_issues(&issues);
+
+        let cond = QueryCondition::Compare {
+            field: "title".to_string(),
+            op: ComparisonOp::Contains,
+            value: "bug".to_string(),
+        };
+
+        assert!(QueryEvaluator::eval_condition(&cond, &issue, &context));
+    }
+
+    #[test]
+    fn test_eval_compare_missing_context_field() {
+        let issue = create_issue("1", State::Ready, Priority::Normal, None, vec![], vec![]);
+        let issues = [issue.clone()];
+        let context = QueryContext::from_issues(&issues);
+
+        let cond = QueryCondition::Compare {
+            field: "points".to_string(),
+            op: ComparisonOp::Ge,
+            value: "5".to_string(),
+        };
+
+        assert!(!QueryEvaluator::eval_condition(&cond, &issue, &context));
+    }
+
+    #[test]
+    fn test_eval_custom_field_matches_context_value() {
+        let mut issue = create_issue("1", State::Ready, Priority::Normal, None, vec![], vec![]);
+        issue.context.insert("milestone".to_string(), "v2".to_string());
+        let issues = [issue.clone()];
+        let context = QueryContext::from_issues(&issues);
+
+        let cond = QueryCondition::Field {
+            name: "milestone".to_string(),
+            value: "v2".to_string(),
+        };
+
+        assert!(QueryEvaluator::eval_condition(&cond, &issue, &context));
+    }
+
+    #[test]
+    fn test_eval_custom_field_missing_does_not_match() {
+        let issue = create_issue("1", State::Ready, Priority::Normal, None, vec![], vec![]);
+        let issues = [issue.clone()];
+        let context = QueryContext::from_issues(&issues);
+
+        let cond = QueryCondition::Field {
+            name: "milestone".to_string(),
+            value: "v2".to_string(),
+        };
+
+        assert!(!QueryEvaluator::eval_condition(&cond, &issue, &context));
+    }
+
+    struct FakeFs {
+        files: HashMap<String, String>,
+    }
+
+    impl DocFilesystem for FakeFs {
+        fn read_to_string(&self, path: &str) -> Option<String> {
+            self.files.get(path).cloned()
+        }
+
+        fn exists(&self, path: &str) -> bool {
+            self.files.contains_key(path)
+        }
+    }
+
+    #[test]
+    fn test_eval_doc_health_detects_broken_link() {
+        let mut issue = create_issue("1", State::Ready, Priority::Normal, None, vec![], vec![]);
+        issue
+            .documents
+            .push(crate::domain::DocumentReference::new("docs/readme.md".to_string()));
+        let issues = [issue.clone()];
+
+        let mut files = HashMap::new();
+        files.insert(
+            "docs/readme.md".to_string(),
+            "See the [Guide](./missing.md)".to_string(),
+        );
+        let fs = FakeFs { files };
+        let adapters = AdapterRegistry::with_builtins();
+        let context = QueryContext::from_issues(&issues).with_doc_health(&adapters, &fs);
+
+        let cond = QueryCondition::DocHealth("broken-links".to_string());
+        assert!(QueryEvaluator::eval_condition(&cond, &issue, &context));
+    }
+
+    #[test]
+    fn test_eval_doc_health_all_assets_present() {
+        let mut issue = create_issue("1", State::Ready, Priority::Normal, None, vec![], vec![]);
+        issue
+            .documents
+            .push(crate::domain::DocumentReference::new("docs/readme.md".to_string()));
+        let issues = [issue.clone()];
+
+        let mut files = HashMap::new();
+        files.insert(
+            "docs/readme.md".to_string(),
+            "See the [Guide](./guide.md)".to_string(),
+        );
+        files.insert("docs/guide.md".to_string(), "content".to_string());
+        let fs = FakeFs { files };
+        let adapters = AdapterRegistry::with_builtins();
+        let context = QueryContext::from_issues(&issues).with_doc_health(&adapters, &fs);
+
+        let cond = QueryCondition::DocHealth("missing-assets".to_string());
+        assert!(!QueryEvaluator::eval_condition(&cond, &issue, &context));
+    }
+
+    #[test]
+    fn test_eval_doc_health_ignores_external_urls() {
+        let mut issue = create_issue("1", State::Ready, Priority::Normal, None, vec![], vec![]);
+        issue
+            .documents
+            .push(crate::domain::DocumentReference::new("docs/readme.md".to_string()));
+        let issues = [issue.clone()];
+
+        let mut files = HashMap::new();
+        files.insert(
+            "docs/readme.md".to_string(),
+            "See [External](https://example.com/guide.md)".to_string(),
+        );
+        let fs = FakeFs { files };
+        let adapters = AdapterRegistry::with_builtins();
+        let context = QueryContext::from_issues(&issues).with_doc_health(&adapters, &fs);
+
+        let cond = QueryCondition::DocHealth("broken-links".to_string());
+        assert!(!QueryEvaluator::eval_condition(&cond, &issue, &context));
+    }
+
+    #[test]
+    fn test_eval_doc_health_without_context_never_matches() {
+        let mut issue = create_issue("1", State::Ready, Priority::Normal, None, vec![], vec![]);
+        issue
+            .documents
+            .push(crate::domain::DocumentReference::new("docs/readme.md".to_string()));
+        let issues = [issue.clone()];
+        let context = QueryContext::from_issues(&issues);
+
+        let cond = QueryCondition::DocHealth("broken-links".to_string());
+        assert!(!QueryEvaluator::eval_condition(&cond, &issue, &context));
+    }
+
+    #[test]
+    fn test_eval_text_condition_matches_title_and_description() {
+        let mut issue = create_issue("1", State::Ready, Priority::Normal, None, vec![], vec![]);
+        issue.title = "Fix login bug".to_string();
+        issue.description = "Needs review before release".to_string();
+        let issues = [issue.clone()];
+        let context = QueryContext::from_issues(&issues);
+
+        let cond = QueryCondition::Text("LOGIN".to_string());
+        assert!(QueryEvaluator::eval_condition(&cond, &issue, &context));
+
+        let cond = QueryCondition::Text("needs review".to_string());
+        assert!(QueryEvaluator::eval_condition(&cond, &issue, &context));
+
+        let cond = QueryCondition::Text("nonexistent".to_string());
+        assert!(!QueryEvaluator::eval_condition(&cond, &issue, &context));
+    }
+
+    #[test]
+    fn test_eval_in_list_equivalent_to_or_chain() {
+        let ready = create_issue("1", State::Ready, Priority::Normal, None, vec![], vec![]);
+        let done = create_issue("2", State::Done, Priority::Normal, None, vec![], vec![]);
+        let in_progress = create_issue(
+            "3",
+            State::InProgress,
+            Priority::Normal,
+            None,
+            vec![],
+            vec![],
+        );
+        let issues = [ready.clone(), done.clone(), in_progress.clone()];
+        let context = QueryContext::from_issues(&issues);
+
+        let list_cond = QueryCondition::InList {
+            field: "state".to_string(),
+            values: vec!["ready".to_string(), "done".to_string()],
+        };
+        let or_chain = QueryExpr::Or(
+            Box::new(QueryExpr::Condition(QueryCondition::State(
+                "ready".to_string(),
+            ))),
+            Box::new(QueryExpr::Condition(QueryCondition::State(
+                "done".to_string(),
+            ))),
+        );
+
+        for issue in [&ready, &done, &in_progress] {
+            assert_eq!(
+                QueryEvaluator::eval_condition(&list_cond, issue, &context),
+                QueryEvaluator::matches(&or_chain, issue, &context),
+            );
+        }
+        assert!(QueryEvaluator::eval_condition(&list_cond, &ready, &context));
+        assert!(QueryEvaluator::eval_condition(&list_cond, &done, &context));
+        assert!(!QueryEvaluator::eval_condition(
+            &list_cond,
+            &in_progress,
+            &context
+        ));
+    }
+
+    #[test]
+    fn test_eval_in_list_with_outer_and_not() {
+        let high_ready = create_issue("1", State::Ready, Priority::High, None, vec![], vec![]);
+        let normal_ready = create_issue("2", State::Ready, Priority::Normal, None, vec![], vec![]);
+        let issues = [high_ready.clone(), normal_ready.clone()];
+        let context = QueryContext::from_issues(&issues);
+
+        // priority:high AND NOT state:backlog,archived
+        let expr = QueryExpr::And(
+            Box::new(QueryExpr::Condition(QueryCondition::Priority(
+                "high".to_string(),
+            ))),
+            Box::new(QueryExpr::Not(Box::new(QueryExpr::Condition(
+                QueryCondition::InList {
+                    field: "state".to_string(),
+                    values: vec!["backlog".to_string(), "archived".to_string()],
+                },
+            )))),
+        );
+
+        assert!(QueryEvaluator::matches(&expr, &high_ready, &context));
+        assert!(!QueryEvaluator::matches(&expr, &normal_ready, &context));
+    }
+
     #[test]
     fn test_eval_and_expression() {
         let issue = create_issue("1", State::Ready, Priority::High, None, vec![], vec![]);
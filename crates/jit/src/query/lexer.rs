@@ -4,11 +4,52 @@
 
 use anyhow::{anyhow, Result};
 
+/// Byte-offset span of a token within the original query string.
+///
+/// Lets callers (e.g. a CLI) print the original query with a caret under
+/// the location a parse error was reported at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Relational operator carried by a [`Token::Filter`].
+///
+/// `Eq` is produced by the plain `field:value` syntax; the others by
+/// `field>value`, `field<value`, `field>=value`, `field<=value`,
+/// `field!=value` and `field~value` (substring match) respectively.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComparisonOp {
+    Eq,
+    Ne,
+    Gt,
+    Lt,
+    Ge,
+    Le,
+    Contains,
+}
+
+/// A lexed [`Token`] paired with the span of source text it came from.
+///
+/// `Parser` keeps the span of whatever token it's currently looking at so
+/// parse errors can point back at the offending text instead of just
+/// describing it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpannedToken {
+    pub token: Token,
+    pub span: Span,
+}
+
 /// Token types in the query language
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Token {
-    /// Filter condition: field:value (e.g., "state:ready", "label:epic:auth")
-    Filter { field: String, value: String },
+    /// Filter condition: field\<op\>value (e.g., "state:ready", "priority>high")
+    Filter {
+        field: String,
+        op: ComparisonOp,
+        value: String,
+    },
     /// Boolean AND operator
     And,
     /// Boolean OR operator
@@ -23,6 +64,28 @@ pub enum Token {
     Unassigned,
     /// Special: blocked issues
     Blocked,
+    /// Free-text search term, from a quoted phrase (`"needs review"`) or a
+    /// bare word that isn't a keyword and doesn't parse as `field:value`.
+    Text(String),
+}
+
+/// Relational operators a filter word may use, checked before the plain
+/// colon so that e.g. "points>=5" splits on ">=" rather than failing to
+/// find a colon at all. Longer operators are listed first so ">=" isn't
+/// mistaken for ">" followed by a literal "=".
+const RELATIONAL_OPS: &[(&str, ComparisonOp)] = &[
+    (">=", ComparisonOp::Ge),
+    ("<=", ComparisonOp::Le),
+    ("!=", ComparisonOp::Ne),
+    (">", ComparisonOp::Gt),
+    ("<", ComparisonOp::Lt),
+    ("~", ComparisonOp::Contains),
+];
+
+/// Whether `word` contains a `:` or one of [`RELATIONAL_OPS`], i.e. looks
+/// like an attempted `field<op>value` filter rather than a free-text term.
+fn looks_like_filter(word: &str) -> bool {
+    word.contains(':') || RELATIONAL_OPS.iter().any(|(op, _)| word.contains(op))
 }
 
 /// Lexer for tokenizing query strings
@@ -38,7 +101,7 @@ impl<'a> Lexer<'a> {
     }
 
     /// Tokenize the entire input string
-    pub fn tokenize(input: &str) -> Result<Vec<Token>> {
+    pub fn tokenize(input: &str) -> Result<Vec<SpannedToken>> {
         let mut lexer = Lexer::new(input);
         let mut tokens = Vec::new();
 
@@ -49,44 +112,63 @@ impl<'a> Lexer<'a> {
         Ok(tokens)
     }
 
-    fn next_token(&mut self) -> Result<Option<Token>> {
+    fn next_token(&mut self) -> Result<Option<SpannedToken>> {
         self.skip_whitespace();
 
         if self.is_at_end() {
             return Ok(None);
         }
 
+        let start = self.position;
         let ch = self.current_char();
 
-        match ch {
+        let token = match ch {
             '(' => {
                 self.advance();
-                Ok(Some(Token::LParen))
+                Token::LParen
             }
             ')' => {
                 self.advance();
-                Ok(Some(Token::RParen))
+                Token::RParen
             }
+            '"' => Token::Text(self.read_quoted_text()?),
             _ => {
                 // Try to read a word
                 let word = self.read_word()?;
 
                 match word.as_str() {
-                    "AND" => Ok(Some(Token::And)),
-                    "OR" => Ok(Some(Token::Or)),
-                    "NOT" => Ok(Some(Token::Not)),
-                    "unassigned" => Ok(Some(Token::Unassigned)),
-                    "blocked" => Ok(Some(Token::Blocked)),
-                    _ => {
-                        // Parse as filter (field:value)
-                        self.parse_filter(&word)
-                    }
+                    "AND" => Token::And,
+                    "OR" => Token::Or,
+                    "NOT" => Token::Not,
+                    "unassigned" => Token::Unassigned,
+                    "blocked" => Token::Blocked,
+                    // A word with no `:` or relational operator isn't a
+                    // filter attempt at all -- it's a free-text search term
+                    // (e.g. `jit query "needs review"` without quotes).
+                    _ if !looks_like_filter(&word) => Token::Text(word),
+                    _ => self.parse_filter(&word)?,
                 }
             }
-        }
+        };
+
+        Ok(Some(SpannedToken {
+            token,
+            span: Span {
+                start,
+                end: self.position,
+            },
+        }))
     }
 
-    fn parse_filter(&self, word: &str) -> Result<Option<Token>> {
+    fn parse_filter(&self, word: &str) -> Result<Token> {
+        for (op_str, op) in RELATIONAL_OPS {
+            if let Some(pos) = word.find(op_str) {
+                let field = &word[..pos];
+                let value = &word[pos + op_str.len()..];
+                return self.build_filter_token(word, field, op.clone(), value);
+            }
+        }
+
         if !word.contains(':') {
             return Err(anyhow!(
                 "Invalid filter '{}': expected format 'field:value'",
@@ -99,6 +181,16 @@ impl<'a> Lexer<'a> {
         let field = &word[..colon_pos];
         let value = &word[colon_pos + 1..];
 
+        self.build_filter_token(word, field, ComparisonOp::Eq, value)
+    }
+
+    fn build_filter_token(
+        &self,
+        word: &str,
+        field: &str,
+        op: ComparisonOp,
+        value: &str,
+    ) -> Result<Token> {
         if field.is_empty() {
             return Err(anyhow!("Filter field cannot be empty: '{}'", word));
         }
@@ -106,10 +198,31 @@ impl<'a> Lexer<'a> {
             return Err(anyhow!("Filter value cannot be empty: '{}'", word));
         }
 
-        Ok(Some(Token::Filter {
+        Ok(Token::Filter {
             field: field.to_string(),
+            op,
             value: value.to_string(),
-        }))
+        })
+    }
+
+    /// Read the contents of a double-quoted free-text term, consuming both
+    /// quotes. Lets a multi-word phrase (`"needs review"`) become a single
+    /// [`Token::Text`] instead of lexing as several AND-ed words.
+    fn read_quoted_text(&mut self) -> Result<String> {
+        self.advance(); // opening quote
+        let start = self.position;
+
+        while !self.is_at_end() && self.current_char() != '"' {
+            self.advance();
+        }
+
+        if self.is_at_end() {
+            return Err(anyhow!("Unterminated quoted text starting at position {}", start - 1));
+        }
+
+        let text = self.input[start..self.position].to_string();
+        self.advance(); // closing quote
+        Ok(text)
     }
 
     fn read_word(&mut self) -> Result<String> {
@@ -161,9 +274,10 @@ mod tests {
         let tokens = Lexer::tokenize("state:ready").unwrap();
         assert_eq!(tokens.len(), 1);
         assert_eq!(
-            tokens[0],
+            tokens[0].token,
             Token::Filter {
                 field: "state".to_string(),
+                op: ComparisonOp::Eq,
                 value: "ready".to_string()
             }
         );
@@ -174,9 +288,10 @@ mod tests {
         let tokens = Lexer::tokenize("label:epic:auth").unwrap();
         assert_eq!(tokens.len(), 1);
         assert_eq!(
-            tokens[0],
+            tokens[0].token,
             Token::Filter {
                 field: "label".to_string(),
+                op: ComparisonOp::Eq,
                 value: "epic:auth".to_string()
             }
         );
@@ -187,17 +302,19 @@ mod tests {
         let tokens = Lexer::tokenize("state:ready AND priority:high").unwrap();
         assert_eq!(tokens.len(), 3);
         assert_eq!(
-            tokens[0],
+            tokens[0].token,
             Token::Filter {
                 field: "state".to_string(),
+                op: ComparisonOp::Eq,
                 value: "ready".to_string()
             }
         );
-        assert_eq!(tokens[1], Token::And);
+        assert_eq!(tokens[1].token, Token::And);
         assert_eq!(
-            tokens[2],
+            tokens[2].token,
             Token::Filter {
                 field: "priority".to_string(),
+                op: ComparisonOp::Eq,
                 value: "high".to_string()
             }
         );
@@ -207,37 +324,57 @@ mod tests {
     fn test_tokenize_or_operator() {
         let tokens = Lexer::tokenize("state:ready OR state:in_progress").unwrap();
         assert_eq!(tokens.len(), 3);
-        assert_eq!(tokens[1], Token::Or);
+        assert_eq!(tokens[1].token, Token::Or);
     }
 
     #[test]
     fn test_tokenize_not_operator() {
         let tokens = Lexer::tokenize("NOT blocked").unwrap();
         assert_eq!(tokens.len(), 2);
-        assert_eq!(tokens[0], Token::Not);
-        assert_eq!(tokens[1], Token::Blocked);
+        assert_eq!(tokens[0].token, Token::Not);
+        assert_eq!(tokens[1].token, Token::Blocked);
     }
 
     #[test]
     fn test_tokenize_parentheses() {
         let tokens = Lexer::tokenize("(state:ready OR state:done)").unwrap();
         assert_eq!(tokens.len(), 5);
-        assert_eq!(tokens[0], Token::LParen);
-        assert_eq!(tokens[4], Token::RParen);
+        assert_eq!(tokens[0].token, Token::LParen);
+        assert_eq!(tokens[4].token, Token::RParen);
     }
 
     #[test]
     fn test_tokenize_unassigned() {
         let tokens = Lexer::tokenize("unassigned").unwrap();
         assert_eq!(tokens.len(), 1);
-        assert_eq!(tokens[0], Token::Unassigned);
+        assert_eq!(tokens[0].token, Token::Unassigned);
     }
 
     #[test]
     fn test_tokenize_blocked() {
         let tokens = Lexer::tokenize("blocked").unwrap();
         assert_eq!(tokens.len(), 1);
-        assert_eq!(tokens[0], Token::Blocked);
+        assert_eq!(tokens[0].token, Token::Blocked);
+    }
+
+    #[test]
+    fn test_tokenize_quoted_text() {
+        let tokens = Lexer::tokenize("\"needs review\"").unwrap();
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(tokens[0].token, Token::Text("needs review".to_string()));
+    }
+
+    #[test]
+    fn test_tokenize_bare_word_as_text() {
+        let tokens = Lexer::tokenize("urgent").unwrap();
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(tokens[0].token, Token::Text("urgent".to_string()));
+    }
+
+    #[test]
+    fn test_tokenize_unterminated_quote_errors() {
+        let result = Lexer::tokenize("\"needs review");
+        assert!(result.is_err());
     }
 
     #[test]
@@ -248,12 +385,12 @@ mod tests {
         .unwrap();
 
         assert_eq!(tokens.len(), 9);
-        assert_eq!(tokens[0], Token::LParen);
-        assert_eq!(tokens[2], Token::Or);
-        assert_eq!(tokens[4], Token::RParen);
-        assert_eq!(tokens[5], Token::And);
-        assert_eq!(tokens[7], Token::Not);
-        assert_eq!(tokens[8], Token::Blocked);
+        assert_eq!(tokens[0].token, Token::LParen);
+        assert_eq!(tokens[2].token, Token::Or);
+        assert_eq!(tokens[4].token, Token::RParen);
+        assert_eq!(tokens[5].token, Token::And);
+        assert_eq!(tokens[7].token, Token::Not);
+        assert_eq!(tokens[8].token, Token::Blocked);
     }
 
     #[test]
@@ -265,13 +402,14 @@ mod tests {
     }
 
     #[test]
-    fn test_tokenize_error_no_colon() {
-        let result = Lexer::tokenize("invalidfilter");
-        assert!(result.is_err());
-        assert!(result
-            .unwrap_err()
-            .to_string()
-            .contains("expected format 'field:value'"));
+    fn test_tokenize_word_without_colon_is_free_text() {
+        // A bare word with no `:` or relational operator used to be a hard
+        // lexer error -- it's now a free-text search term instead (see
+        // `Token::Text`), so `jit query urgent` finds issues mentioning
+        // "urgent" rather than rejecting the query outright.
+        let tokens = Lexer::tokenize("invalidfilter").unwrap();
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(tokens[0].token, Token::Text("invalidfilter".to_string()));
     }
 
     #[test]
@@ -300,4 +438,96 @@ mod tests {
         assert_eq!(tokens.len(), 3);
         // Whitespace should be ignored
     }
+
+    #[test]
+    fn test_tokenize_greater_than() {
+        let tokens = Lexer::tokenize("priority>high").unwrap();
+        assert_eq!(
+            tokens[0].token,
+            Token::Filter {
+                field: "priority".to_string(),
+                op: ComparisonOp::Gt,
+                value: "high".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_tokenize_greater_than_or_equal() {
+        let tokens = Lexer::tokenize("points>=5").unwrap();
+        assert_eq!(
+            tokens[0].token,
+            Token::Filter {
+                field: "points".to_string(),
+                op: ComparisonOp::Ge,
+                value: "5".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_tokenize_less_than() {
+        let tokens = Lexer::tokenize("created<2024-01-01").unwrap();
+        assert_eq!(
+            tokens[0].token,
+            Token::Filter {
+                field: "created".to_string(),
+                op: ComparisonOp::Lt,
+                value: "2024-01-01".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_tokenize_less_than_or_equal() {
+        let tokens = Lexer::tokenize("points<=5").unwrap();
+        assert_eq!(
+            tokens[0].token,
+            Token::Filter {
+                field: "points".to_string(),
+                op: ComparisonOp::Le,
+                value: "5".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_tokenize_not_equal() {
+        let tokens = Lexer::tokenize("priority!=low").unwrap();
+        assert_eq!(
+            tokens[0].token,
+            Token::Filter {
+                field: "priority".to_string(),
+                op: ComparisonOp::Ne,
+                value: "low".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_tokenize_contains() {
+        let tokens = Lexer::tokenize("title~bug").unwrap();
+        assert_eq!(
+            tokens[0].token,
+            Token::Filter {
+                field: "title".to_string(),
+                op: ComparisonOp::Contains,
+                value: "bug".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_tokenize_spans() {
+        let tokens = Lexer::tokenize("state:ready AND priority:high").unwrap();
+        assert_eq!(tokens[0].span, Span { start: 0, end: 11 });
+        assert_eq!(tokens[1].span, Span { start: 12, end: 15 });
+        assert_eq!(tokens[2].span, Span { start: 16, end: 29 });
+    }
+
+    #[test]
+    fn test_tokenize_span_skips_leading_whitespace() {
+        let tokens = Lexer::tokenize("   blocked").unwrap();
+        assert_eq!(tokens[0].span, Span { start: 3, end: 10 });
+    }
 }
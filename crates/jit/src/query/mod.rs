@@ -4,7 +4,8 @@
 //! - Simple filters: `state:ready`, `label:epic:auth`, `priority:high`
 //! - Boolean operators: `AND`, `OR`, `NOT`
 //! - Parentheses for grouping: `(state:ready OR state:done) AND priority:high`
-//! - Special conditions: `unassigned`, `blocked`
+//! - Special conditions: `unassigned`, `blocked`, `docs:broken-links`
+//! - Free-text search: bare words or `"quoted phrases"` match title/description
 //!
 //! # Architecture
 //!
@@ -30,8 +31,8 @@ mod lexer;
 mod parser;
 
 pub use evaluator::{QueryContext, QueryEvaluator};
-pub use lexer::{Lexer, Token};
-pub use parser::{Parser, QueryCondition, QueryExpr};
+pub use lexer::{ComparisonOp, Lexer, Span, SpannedToken, Token};
+pub use parser::{FieldRegistry, Parser, QueryCondition, QueryExpr, QueryParseError};
 
 use crate::domain::Issue;
 use anyhow::Result;
@@ -62,6 +63,27 @@ impl QueryFilter {
         Ok(QueryFilter { expr })
     }
 
+    /// Parse a query string, additionally recognizing `fields` as valid
+    /// custom filter field names (see [`FieldRegistry`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jit::query::{FieldRegistry, QueryFilter};
+    ///
+    /// # fn example() -> anyhow::Result<()> {
+    /// let mut fields = FieldRegistry::new();
+    /// fields.register("milestone");
+    /// let filter = QueryFilter::parse_with_fields("milestone:v2", fields)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_with_fields(query: &str, fields: FieldRegistry) -> Result<Self> {
+        let tokens = Lexer::tokenize(query)?;
+        let expr = Parser::parse_with_fields(tokens, fields)?;
+        Ok(QueryFilter { expr })
+    }
+
     /// Check if an issue matches this filter
     ///
     /// Requires a QueryContext containing all issues for dependency checks.
@@ -131,6 +153,39 @@ mod tests {
         assert_eq!(matched[1].id, "3");
     }
 
+    #[test]
+    fn test_filter_by_state_list() {
+        let issues = vec![
+            create_test_issue("1", State::Ready, Priority::Normal, vec![]),
+            create_test_issue("2", State::Done, Priority::Normal, vec![]),
+            create_test_issue("3", State::InProgress, Priority::Normal, vec![]),
+        ];
+
+        let filter = QueryFilter::parse("state:ready,done").unwrap();
+        let matched = filter.filter_issues(&issues).unwrap();
+
+        assert_eq!(matched.len(), 2);
+        assert_eq!(matched[0].id, "1");
+        assert_eq!(matched[1].id, "2");
+    }
+
+    #[test]
+    fn test_filter_by_custom_field() {
+        let mut ready = create_test_issue("1", State::Ready, Priority::Normal, vec![]);
+        ready.context.insert("milestone".to_string(), "v2".to_string());
+        let mut done = create_test_issue("2", State::Done, Priority::Normal, vec![]);
+        done.context.insert("milestone".to_string(), "v1".to_string());
+        let issues = [ready, done];
+
+        let mut fields = FieldRegistry::new();
+        fields.register("milestone");
+        let filter = QueryFilter::parse_with_fields("milestone:v2", fields).unwrap();
+        let matched = filter.filter_issues(&issues).unwrap();
+
+        assert_eq!(matched.len(), 1);
+        assert_eq!(matched[0].id, "1");
+    }
+
     #[test]
     fn test_filter_by_priority() {
         let issues = vec![
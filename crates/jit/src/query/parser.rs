@@ -0,0 +1,842 @@
+//! Parser for query filter language
+//!
+//! Builds an abstract syntax tree (AST) from tokens.
+
+use super::lexer::{ComparisonOp, Span, SpannedToken, Token};
+use std::collections::HashSet;
+use std::fmt;
+
+/// A parse error together with the span of source text that caused it.
+///
+/// Carrying the span (rather than just a message) lets a caller render the
+/// original query with a caret under the offending text -- see
+/// [`QueryParseError::render`] -- or report `position`/`length` as
+/// structured fields in `--json` mode.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryParseError {
+    pub message: String,
+    pub span: Span,
+}
+
+impl QueryParseError {
+    fn new(message: impl Into<String>, span: Span) -> Self {
+        QueryParseError {
+            message: message.into(),
+            span,
+        }
+    }
+
+    /// Render the original query with the error message and a caret
+    /// underlining the offending span, e.g.:
+    ///
+    /// ```text
+    /// state:ready AND (
+    ///                  ^
+    /// error: unexpected end of query
+    /// ```
+    pub fn render(&self, query: &str) -> String {
+        let width = (self.span.end - self.span.start).max(1);
+        let caret_line = format!("{}{}", " ".repeat(self.span.start), "^".repeat(width));
+        format!("{}\n{}\nerror: {}", query, caret_line, self.message)
+    }
+}
+
+impl fmt::Display for QueryParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at position {}", self.message, self.span.start)
+    }
+}
+
+impl std::error::Error for QueryParseError {}
+
+type Result<T> = std::result::Result<T, QueryParseError>;
+
+/// Abstract syntax tree node for query expressions
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QueryExpr {
+    /// Single condition
+    Condition(QueryCondition),
+    /// Logical AND
+    And(Box<QueryExpr>, Box<QueryExpr>),
+    /// Logical OR
+    Or(Box<QueryExpr>, Box<QueryExpr>),
+    /// Logical NOT
+    Not(Box<QueryExpr>),
+}
+
+/// Individual query conditions
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QueryCondition {
+    /// Filter by state (value unparsed, e.g., "ready")
+    State(String),
+    /// Filter by label (supports wildcards, e.g., "epic:*")
+    Label(String),
+    /// Filter by priority (value unparsed, e.g., "high")
+    Priority(String),
+    /// Filter by assignee
+    Assignee(String),
+    /// Issues with no assignee
+    Unassigned,
+    /// Issues blocked by dependencies or gates
+    Blocked,
+    /// Relational comparison on an arbitrary field (e.g. `priority>high`,
+    /// `points>=5`, `created<2024-01-01`). `state`/`label`/`priority`/
+    /// `assignee` only reach this variant for non-`Eq` operators -- plain
+    /// `field:value` keeps producing the dedicated variants above so
+    /// existing wildcard label matching etc. is unaffected.
+    Compare {
+        field: String,
+        op: ComparisonOp,
+        value: String,
+    },
+    /// Disjunction over a comma-separated value list for one of the known
+    /// fields, e.g. `state:ready,in_progress` or `assignee:alice,bob`.
+    /// Semantically identical to ORing a same-field `Eq` condition per
+    /// value, just terser to write -- the evaluator expands it back out.
+    InList { field: String, values: Vec<String> },
+    /// Equality on a caller-registered custom field (see [`FieldRegistry`]),
+    /// e.g. `milestone:v2` once `milestone` has been registered. Resolved
+    /// against `Issue::context` at evaluation time, the same place
+    /// [`QueryCondition::Compare`] reads arbitrary fields from.
+    Field { name: String, value: String },
+    /// Document health check, e.g. `docs:broken-links` or
+    /// `docs:missing-assets`. The value is kept as the raw string rather
+    /// than a closed enum -- same permissive-parse-strict-eval split as
+    /// [`QueryCondition::State`] -- and is resolved against the issue's
+    /// attached documents via the adapter registry threaded into
+    /// `QueryContext`, not parsed further here.
+    DocHealth(String),
+    /// Free-text search term (a quoted phrase or bare non-filter word),
+    /// matched case-insensitively as a substring of `issue.title` or
+    /// `issue.description`.
+    Text(String),
+}
+
+/// Caller-registered filter field names, recognized by [`Parser`] alongside
+/// the built-in `state`/`label`/`priority`/`assignee` fields.
+///
+/// Lets a downstream binary declare its own filterable `Issue::context`
+/// attributes (e.g. `milestone`, `component`, `sprint`) at startup instead of
+/// patching `parse_condition` -- the same "extend without touching the
+/// parser" shape as [`crate::type_hierarchy::HierarchyConfig::with_custom_rules`].
+#[derive(Debug, Clone, Default)]
+pub struct FieldRegistry {
+    fields: HashSet<String>,
+}
+
+impl FieldRegistry {
+    /// Create an empty registry recognizing only the built-in fields.
+    pub fn new() -> Self {
+        FieldRegistry::default()
+    }
+
+    /// Register a custom field name as a valid `field:value` filter target.
+    pub fn register(&mut self, name: impl Into<String>) -> &mut Self {
+        self.fields.insert(name.into());
+        self
+    }
+
+    /// Whether `name` has been registered as a custom filter field.
+    pub fn contains(&self, name: &str) -> bool {
+        self.fields.contains(name)
+    }
+}
+
+/// Maximum nesting depth for parenthesized groups and `NOT` chains.
+///
+/// Guards against stack overflow on pathological input like
+/// `((((...))))` or `NOT NOT NOT ...` -- mirrors how robust SQL parsers
+/// bound recursion with a depth limit rather than letting the process crash.
+const MAX_DEPTH: usize = 256;
+
+/// Parser for building AST from tokens
+pub struct Parser {
+    tokens: Vec<SpannedToken>,
+    position: usize,
+    depth: usize,
+    fields: FieldRegistry,
+}
+
+impl Parser {
+    /// Create a new parser recognizing only the built-in fields. Chain
+    /// [`Parser::with_fields`] to also accept caller-registered custom ones.
+    pub fn new(tokens: Vec<SpannedToken>) -> Self {
+        Parser {
+            tokens,
+            position: 0,
+            depth: 0,
+            fields: FieldRegistry::default(),
+        }
+    }
+
+    /// Attach a [`FieldRegistry`] of custom field names for `parse_condition`
+    /// to accept alongside the built-ins.
+    pub fn with_fields(mut self, fields: FieldRegistry) -> Self {
+        self.fields = fields;
+        self
+    }
+
+    /// Parse tokens into an expression tree
+    pub fn parse(tokens: Vec<SpannedToken>) -> Result<QueryExpr> {
+        let mut parser = Parser::new(tokens);
+        parser.parse_bp(0)
+    }
+
+    /// Parse tokens into an expression tree, additionally recognizing
+    /// `fields` as valid custom filter field names.
+    pub fn parse_with_fields(
+        tokens: Vec<SpannedToken>,
+        fields: FieldRegistry,
+    ) -> Result<QueryExpr> {
+        let mut parser = Parser::new(tokens).with_fields(fields);
+        parser.parse_bp(0)
+    }
+
+    /// Binding power (left, right) for a binary operator token.
+    ///
+    /// Higher numbers bind tighter. Adding a new binary operator (e.g. an
+    /// `XOR` or `NEAR`) is a single new row here -- no new recursion level
+    /// needed. `NOT_BP` below plays the same role for the one prefix operator.
+    fn binary_binding_power(token: &Token) -> Option<(u8, u8)> {
+        match token {
+            Token::Or => Some((1, 2)),
+            Token::And => Some((3, 4)),
+            _ => None,
+        }
+    }
+
+    /// Precedence-climbing parse: parses a prefix atom, then repeatedly
+    /// consumes binary operators whose left binding power is at least
+    /// `min_bp`, recursing with the operator's right binding power for the
+    /// right-hand side. Two conditions with no operator between them are
+    /// treated as an implicit AND.
+    fn parse_bp(&mut self, min_bp: u8) -> Result<QueryExpr> {
+        let mut left = self.parse_prefix()?;
+
+        loop {
+            if self.is_at_end() || self.match_token(&Token::RParen) {
+                break;
+            }
+
+            let (is_or, l_bp, r_bp, consumes_token) =
+                if let Some((l_bp, r_bp)) = Self::binary_binding_power(self.current_token()) {
+                    (self.match_token(&Token::Or), l_bp, r_bp, true)
+                } else if self.is_condition_start() {
+                    // Implicit AND: no operator token to consume.
+                    let (l_bp, r_bp) = Self::binary_binding_power(&Token::And).unwrap();
+                    (false, l_bp, r_bp, false)
+                } else {
+                    break;
+                };
+
+            if l_bp < min_bp {
+                break;
+            }
+
+            if consumes_token {
+                self.advance();
+            }
+
+            let right = self.parse_bp(r_bp)?;
+            left = if is_or {
+                QueryExpr::Or(Box::new(left), Box::new(right))
+            } else {
+                QueryExpr::And(Box::new(left), Box::new(right))
+            };
+        }
+
+        Ok(left)
+    }
+
+    /// `NOT`'s binding power as a prefix operator: tighter than AND/OR so it
+    /// only grabs the next atom, not a whole AND/OR chain.
+    const NOT_BP: u8 = 5;
+
+    /// Parse a prefix operator (`NOT`) or a primary expression (`(...)` or a
+    /// single condition atom).
+    fn parse_prefix(&mut self) -> Result<QueryExpr> {
+        if self.match_token(&Token::Not) {
+            self.advance();
+            self.enter_nested()?;
+            let inner = self.parse_bp(Self::NOT_BP)?;
+            self.depth -= 1;
+            return Ok(QueryExpr::Not(Box::new(inner)));
+        }
+
+        if self.match_token(&Token::LParen) {
+            self.advance();
+            self.enter_nested()?;
+            let expr = self.parse_bp(0)?;
+            self.depth -= 1;
+
+            if !self.match_token(&Token::RParen) {
+                return Err(QueryParseError::new(
+                    "Expected closing parenthesis",
+                    self.current_span(),
+                ));
+            }
+            self.advance();
+            return Ok(expr);
+        }
+
+        self.parse_condition()
+    }
+
+    /// Increment the nesting depth, erroring if it would exceed `MAX_DEPTH`.
+    fn enter_nested(&mut self) -> Result<()> {
+        self.depth += 1;
+        if self.depth > MAX_DEPTH {
+            return Err(QueryParseError::new(
+                "query nesting too deep",
+                self.current_span(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Parse a single condition (atom)
+    fn parse_condition(&mut self) -> Result<QueryExpr> {
+        if self.is_at_end() {
+            return Err(QueryParseError::new(
+                "Unexpected end of query",
+                self.current_span(),
+            ));
+        }
+
+        let span = self.current_span();
+        let token = self.current_token().clone();
+        self.advance();
+
+        match token {
+            Token::Filter { field, op, value } => {
+                // "any of" shorthand: `state:ready,done` means
+                // `(state:ready OR state:done)`. Only plain equality on the
+                // closed set of known fields takes this path -- same
+                // restriction as the single-value case below.
+                if op == ComparisonOp::Eq && value.contains(',') {
+                    if !matches!(field.as_str(), "state" | "label" | "priority" | "assignee") {
+                        return Err(QueryParseError::new(
+                            format!("Unknown filter field: '{}'", field),
+                            span,
+                        ));
+                    }
+
+                    let values: Vec<String> = value.split(',').map(str::to_string).collect();
+                    if values.iter().any(|v| v.is_empty()) {
+                        return Err(QueryParseError::new(
+                            format!("Empty value in list filter: '{}:{}'", field, value),
+                            span,
+                        ));
+                    }
+
+                    return Ok(QueryExpr::Condition(QueryCondition::InList { field, values }));
+                }
+
+                let condition = match (field.as_str(), &op) {
+                    ("state", ComparisonOp::Eq) => QueryCondition::State(value),
+                    ("label", ComparisonOp::Eq) => QueryCondition::Label(value),
+                    ("priority", ComparisonOp::Eq) => QueryCondition::Priority(value),
+                    ("assignee", ComparisonOp::Eq) => QueryCondition::Assignee(value),
+                    ("docs", ComparisonOp::Eq) => QueryCondition::DocHealth(value),
+                    // Plain `field:value` equality keeps the closed set of
+                    // built-in fields above, unless the field was registered
+                    // as a custom one; relational operators open up any
+                    // field name, since those are the ones that need to
+                    // reach arbitrary numeric/date `context` entries.
+                    (_, ComparisonOp::Eq) if self.fields.contains(&field) => {
+                        QueryCondition::Field { name: field, value }
+                    }
+                    (_, ComparisonOp::Eq) => {
+                        return Err(QueryParseError::new(
+                            format!("Unknown filter field: '{}'", field),
+                            span,
+                        ))
+                    }
+                    _ => QueryCondition::Compare { field, op, value },
+                };
+                Ok(QueryExpr::Condition(condition))
+            }
+            Token::Unassigned => Ok(QueryExpr::Condition(QueryCondition::Unassigned)),
+            Token::Blocked => Ok(QueryExpr::Condition(QueryCondition::Blocked)),
+            Token::Text(text) => Ok(QueryExpr::Condition(QueryCondition::Text(text))),
+            _ => Err(QueryParseError::new(
+                format!("Expected condition, found {:?}", token),
+                span,
+            )),
+        }
+    }
+
+    fn is_condition_start(&self) -> bool {
+        if self.is_at_end() {
+            return false;
+        }
+        matches!(
+            self.current_token(),
+            Token::Filter { .. }
+                | Token::Unassigned
+                | Token::Blocked
+                | Token::Text(_)
+                | Token::Not
+                | Token::LParen
+        )
+    }
+
+    fn match_token(&self, expected: &Token) -> bool {
+        if self.is_at_end() {
+            return false;
+        }
+        std::mem::discriminant(self.current_token()) == std::mem::discriminant(expected)
+    }
+
+    fn current_token(&self) -> &Token {
+        &self.tokens[self.position].token
+    }
+
+    /// Span of the token at the current position, or a zero-width span just
+    /// past the last token (or at the very start for an empty query) once
+    /// input is exhausted -- good enough to put a caret at "end of input".
+    fn current_span(&self) -> Span {
+        if self.is_at_end() {
+            self.tokens
+                .last()
+                .map(|t| Span {
+                    start: t.span.end,
+                    end: t.span.end,
+                })
+                .unwrap_or(Span { start: 0, end: 0 })
+        } else {
+            self.tokens[self.position].span
+        }
+    }
+
+    fn advance(&mut self) {
+        if !self.is_at_end() {
+            self.position += 1;
+        }
+    }
+
+    fn is_at_end(&self) -> bool {
+        self.position >= self.tokens.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::lexer::Lexer;
+
+    #[test]
+    fn test_parse_single_condition() {
+        let tokens = Lexer::tokenize("state:ready").unwrap();
+        let expr = Parser::parse(tokens).unwrap();
+
+        assert_eq!(
+            expr,
+            QueryExpr::Condition(QueryCondition::State("ready".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parse_and_operator() {
+        let tokens = Lexer::tokenize("state:ready AND priority:high").unwrap();
+        let expr = Parser::parse(tokens).unwrap();
+
+        match expr {
+            QueryExpr::And(left, right) => {
+                assert_eq!(
+                    *left,
+                    QueryExpr::Condition(QueryCondition::State("ready".to_string()))
+                );
+                assert_eq!(
+                    *right,
+                    QueryExpr::Condition(QueryCondition::Priority("high".to_string()))
+                );
+            }
+            _ => panic!("Expected And expression"),
+        }
+    }
+
+    #[test]
+    fn test_parse_or_operator() {
+        let tokens = Lexer::tokenize("state:ready OR state:done").unwrap();
+        let expr = Parser::parse(tokens).unwrap();
+
+        match expr {
+            QueryExpr::Or(left, right) => {
+                assert_eq!(
+                    *left,
+                    QueryExpr::Condition(QueryCondition::State("ready".to_string()))
+                );
+                assert_eq!(
+                    *right,
+                    QueryExpr::Condition(QueryCondition::State("done".to_string()))
+                );
+            }
+            _ => panic!("Expected Or expression"),
+        }
+    }
+
+    #[test]
+    fn test_parse_not_operator() {
+        let tokens = Lexer::tokenize("NOT blocked").unwrap();
+        let expr = Parser::parse(tokens).unwrap();
+
+        match expr {
+            QueryExpr::Not(inner) => {
+                assert_eq!(*inner, QueryExpr::Condition(QueryCondition::Blocked));
+            }
+            _ => panic!("Expected Not expression"),
+        }
+    }
+
+    #[test]
+    fn test_parse_parentheses() {
+        let tokens = Lexer::tokenize("(state:ready OR state:done)").unwrap();
+        let expr = Parser::parse(tokens).unwrap();
+
+        match expr {
+            QueryExpr::Or(_, _) => {} // Correct
+            _ => panic!("Expected Or expression"),
+        }
+    }
+
+    #[test]
+    fn test_parse_implicit_and() {
+        let tokens = Lexer::tokenize("state:ready priority:high").unwrap();
+        let expr = Parser::parse(tokens).unwrap();
+
+        match expr {
+            QueryExpr::And(left, right) => {
+                assert_eq!(
+                    *left,
+                    QueryExpr::Condition(QueryCondition::State("ready".to_string()))
+                );
+                assert_eq!(
+                    *right,
+                    QueryExpr::Condition(QueryCondition::Priority("high".to_string()))
+                );
+            }
+            _ => panic!("Expected And expression for implicit AND"),
+        }
+    }
+
+    #[test]
+    fn test_parse_complex_expression() {
+        let tokens =
+            Lexer::tokenize("(state:ready OR state:in_progress) AND priority:high NOT blocked")
+                .unwrap();
+        let expr = Parser::parse(tokens).unwrap();
+
+        // Should parse as: ((state:ready OR state:in_progress) AND priority:high) AND (NOT blocked)
+        match expr {
+            QueryExpr::And(_, _) => {} // At least an AND at top level
+            _ => panic!("Expected And at top level"),
+        }
+    }
+
+    #[test]
+    fn test_parse_label_condition() {
+        let tokens = Lexer::tokenize("label:epic:auth").unwrap();
+        let expr = Parser::parse(tokens).unwrap();
+
+        assert_eq!(
+            expr,
+            QueryExpr::Condition(QueryCondition::Label("epic:auth".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parse_unassigned() {
+        let tokens = Lexer::tokenize("unassigned").unwrap();
+        let expr = Parser::parse(tokens).unwrap();
+
+        assert_eq!(expr, QueryExpr::Condition(QueryCondition::Unassigned));
+    }
+
+    #[test]
+    fn test_parse_blocked() {
+        let tokens = Lexer::tokenize("blocked").unwrap();
+        let expr = Parser::parse(tokens).unwrap();
+
+        assert_eq!(expr, QueryExpr::Condition(QueryCondition::Blocked));
+    }
+
+    #[test]
+    fn test_parse_error_unknown_field() {
+        let tokens = vec![SpannedToken {
+            token: Token::Filter {
+                field: "unknown".to_string(),
+                op: ComparisonOp::Eq,
+                value: "value".to_string(),
+            },
+            span: Span { start: 0, end: 13 },
+        }];
+        let result = Parser::parse(tokens);
+
+        let err = result.unwrap_err();
+        assert!(err.message.contains("Unknown filter field"));
+        assert_eq!(err.span, Span { start: 0, end: 13 });
+    }
+
+    #[test]
+    fn test_parse_comparison_on_priority() {
+        let tokens = Lexer::tokenize("priority>high").unwrap();
+        let expr = Parser::parse(tokens).unwrap();
+
+        assert_eq!(
+            expr,
+            QueryExpr::Condition(QueryCondition::Compare {
+                field: "priority".to_string(),
+                op: ComparisonOp::Gt,
+                value: "high".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_comparison_on_arbitrary_field() {
+        let tokens = Lexer::tokenize("points>=5").unwrap();
+        let expr = Parser::parse(tokens).unwrap();
+
+        assert_eq!(
+            expr,
+            QueryExpr::Condition(QueryCondition::Compare {
+                field: "points".to_string(),
+                op: ComparisonOp::Ge,
+                value: "5".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_date_comparison() {
+        let tokens = Lexer::tokenize("created<2024-01-01").unwrap();
+        let expr = Parser::parse(tokens).unwrap();
+
+        assert_eq!(
+            expr,
+            QueryExpr::Condition(QueryCondition::Compare {
+                field: "created".to_string(),
+                op: ComparisonOp::Lt,
+                value: "2024-01-01".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_state_list_condition() {
+        let tokens = Lexer::tokenize("state:ready,done").unwrap();
+        let expr = Parser::parse(tokens).unwrap();
+
+        assert_eq!(
+            expr,
+            QueryExpr::Condition(QueryCondition::InList {
+                field: "state".to_string(),
+                values: vec!["ready".to_string(), "done".to_string()],
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_assignee_list_condition() {
+        let tokens = Lexer::tokenize("assignee:alice,bob").unwrap();
+        let expr = Parser::parse(tokens).unwrap();
+
+        assert_eq!(
+            expr,
+            QueryExpr::Condition(QueryCondition::InList {
+                field: "assignee".to_string(),
+                values: vec!["alice".to_string(), "bob".to_string()],
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_list_condition_respects_and_precedence() {
+        // `priority:high AND state:ready,done` must parse as
+        // `priority:high AND (state:ready,done)`, not split the list across
+        // the AND.
+        let tokens = Lexer::tokenize("priority:high AND state:ready,done").unwrap();
+        let expr = Parser::parse(tokens).unwrap();
+
+        match expr {
+            QueryExpr::And(left, right) => {
+                assert_eq!(
+                    *left,
+                    QueryExpr::Condition(QueryCondition::Priority("high".to_string()))
+                );
+                assert_eq!(
+                    *right,
+                    QueryExpr::Condition(QueryCondition::InList {
+                        field: "state".to_string(),
+                        values: vec!["ready".to_string(), "done".to_string()],
+                    })
+                );
+            }
+            _ => panic!("Expected And expression"),
+        }
+    }
+
+    #[test]
+    fn test_parse_list_condition_unknown_field_errors() {
+        let tokens = Lexer::tokenize("sprint:1,2").unwrap();
+        let result = Parser::parse(tokens);
+
+        assert!(result.unwrap_err().message.contains("Unknown filter field"));
+    }
+
+    #[test]
+    fn test_parse_list_condition_trailing_comma_errors() {
+        let tokens = Lexer::tokenize("state:ready,").unwrap();
+        let result = Parser::parse(tokens);
+
+        assert!(result.unwrap_err().message.contains("Empty value"));
+    }
+
+    #[test]
+    fn test_parse_custom_field_requires_registration() {
+        let tokens = Lexer::tokenize("milestone:v2").unwrap();
+        let result = Parser::parse(tokens);
+
+        assert!(result.unwrap_err().message.contains("Unknown filter field"));
+    }
+
+    #[test]
+    fn test_parse_custom_field_with_registry() {
+        let tokens = Lexer::tokenize("milestone:v2").unwrap();
+        let mut fields = FieldRegistry::new();
+        fields.register("milestone");
+        let expr = Parser::parse_with_fields(tokens, fields).unwrap();
+
+        assert_eq!(
+            expr,
+            QueryExpr::Condition(QueryCondition::Field {
+                name: "milestone".to_string(),
+                value: "v2".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_custom_field_unregistered_name_still_errors() {
+        let tokens = Lexer::tokenize("milestone:v2").unwrap();
+        let mut fields = FieldRegistry::new();
+        fields.register("component");
+        let result = Parser::parse_with_fields(tokens, fields);
+
+        assert!(result.unwrap_err().message.contains("Unknown filter field"));
+    }
+
+    #[test]
+    fn test_parse_doc_health_condition() {
+        let tokens = Lexer::tokenize("docs:broken-links").unwrap();
+        let expr = Parser::parse(tokens).unwrap();
+
+        assert_eq!(
+            expr,
+            QueryExpr::Condition(QueryCondition::DocHealth("broken-links".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parse_free_text_condition() {
+        let tokens = Lexer::tokenize("\"needs review\" AND state:ready").unwrap();
+        let expr = Parser::parse(tokens).unwrap();
+
+        assert_eq!(
+            expr,
+            QueryExpr::And(
+                Box::new(QueryExpr::Condition(QueryCondition::Text(
+                    "needs review".to_string()
+                ))),
+                Box::new(QueryExpr::Condition(QueryCondition::State(
+                    "ready".to_string()
+                ))),
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_error_unclosed_paren() {
+        let tokens = Lexer::tokenize("(state:ready").unwrap();
+        let result = Parser::parse(tokens);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_deeply_nested_parens_errors_cleanly() {
+        let query = format!("{}state:ready{}", "(".repeat(1000), ")".repeat(1000));
+        let tokens = Lexer::tokenize(&query).unwrap();
+        let result = Parser::parse(tokens);
+
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("nesting too deep"));
+    }
+
+    #[test]
+    fn test_parse_deeply_nested_not_errors_cleanly() {
+        let query = format!("{} blocked", "NOT ".repeat(1000));
+        let tokens = Lexer::tokenize(&query).unwrap();
+        let result = Parser::parse(tokens);
+
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("nesting too deep"));
+    }
+
+    #[test]
+    fn test_parse_nested_parens_within_limit_succeeds() {
+        let query = format!("{}state:ready{}", "(".repeat(10), ")".repeat(10));
+        let tokens = Lexer::tokenize(&query).unwrap();
+        let result = Parser::parse(tokens);
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_parse_error_unexpected_end_has_span_at_input_length() {
+        let query = "state:ready AND";
+        let tokens = Lexer::tokenize(query).unwrap();
+        let err = Parser::parse(tokens).unwrap_err();
+
+        assert_eq!(
+            err.span,
+            Span {
+                start: query.len(),
+                end: query.len(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_error_render_underlines_span() {
+        let query = "state:ready AND (";
+        let tokens = Lexer::tokenize(query).unwrap();
+        let err = Parser::parse(tokens).unwrap_err();
+
+        let rendered = err.render(query);
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(lines[0], query);
+        assert_eq!(lines[1], format!("{}^", " ".repeat(query.len())));
+        assert!(lines[2].contains("Unexpected end of query"));
+    }
+
+    #[test]
+    fn test_operator_precedence() {
+        // OR has lower precedence than AND
+        // "a AND b OR c" should parse as "(a AND b) OR c"
+        let tokens = Lexer::tokenize("state:ready AND priority:high OR state:done").unwrap();
+        let expr = Parser::parse(tokens).unwrap();
+
+        match expr {
+            QueryExpr::Or(left, _right) => {
+                // Left side should be AND
+                match *left {
+                    QueryExpr::And(_, _) => {} // Correct
+                    _ => panic!("Expected AND on left side of OR"),
+                }
+            }
+            _ => panic!("Expected OR at top level"),
+        }
+    }
+}
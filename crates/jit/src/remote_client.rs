@@ -0,0 +1,101 @@
+//! Blocking HTTP client for `--remote` mode.
+//!
+//! Lets the CLI talk to a `jit-server` instance instead of a local
+//! `.jit` directory, for the query/claim/report surface the server
+//! exposes under `/api/query/*` and `/api/issues/:id/{claim,report}`.
+//! Every request carries a bearer token, same trust model as
+//! [`crate::notifier`]'s webhook sinks -- a single shared secret, not
+//! per-user auth.
+//!
+//! Uses [`ureq`] rather than an async HTTP client since the CLI itself
+//! has no async runtime; this mirrors how `notifier::deliver` talks to
+//! webhook sinks.
+
+use crate::domain::{Issue, ReportStatus, State};
+use anyhow::{anyhow, Result};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+/// A handle to a remote `jit-server`, authenticated with a bearer token.
+pub struct RemoteClient {
+    base_url: String,
+    token: String,
+}
+
+impl RemoteClient {
+    pub fn new(base_url: String, token: String) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            token,
+        }
+    }
+
+    fn get<T: DeserializeOwned>(&self, path: &str, query: &[(&str, &str)]) -> Result<T> {
+        let url = format!("{}{}", self.base_url, path);
+        let mut request = ureq::get(&url).set("Authorization", &format!("Bearer {}", self.token));
+        for (key, value) in query {
+            request = request.query(key, value);
+        }
+        request
+            .call()
+            .map_err(|e| anyhow!("request to {} failed: {}", url, e))?
+            .into_json()
+            .map_err(|e| anyhow!("invalid response from {}: {}", url, e))
+    }
+
+    fn post<B: Serialize, T: DeserializeOwned>(&self, path: &str, body: &B) -> Result<T> {
+        let url = format!("{}{}", self.base_url, path);
+        ureq::post(&url)
+            .set("Authorization", &format!("Bearer {}", self.token))
+            .send_json(serde_json::to_value(body)?)
+            .map_err(|e| anyhow!("request to {} failed: {}", url, e))?
+            .into_json()
+            .map_err(|e| anyhow!("invalid response from {}: {}", url, e))
+    }
+
+    pub fn query_ready(&self) -> Result<Vec<Issue>> {
+        self.get("/api/query/ready", &[])
+    }
+
+    pub fn query_blocked(&self) -> Result<Vec<(Issue, Vec<String>)>> {
+        #[derive(Deserialize)]
+        struct BlockedEntry {
+            issue: Issue,
+            reasons: Vec<String>,
+        }
+        let entries: Vec<BlockedEntry> = self.get("/api/query/blocked", &[])?;
+        Ok(entries.into_iter().map(|e| (e.issue, e.reasons)).collect())
+    }
+
+    pub fn query_by_assignee(&self, assignee: &str) -> Result<Vec<Issue>> {
+        self.get("/api/query/assignee", &[("assignee", assignee)])
+    }
+
+    pub fn query_by_state(&self, state: State) -> Result<Vec<Issue>> {
+        self.get("/api/query/state", &[("state", &format!("{:?}", state))])
+    }
+
+    pub fn query_by_label(&self, pattern: &str) -> Result<Vec<Issue>> {
+        self.get("/api/query/label", &[("pattern", pattern)])
+    }
+
+    pub fn claim_issue(&self, id: &str, assignee: &str) -> Result<Issue> {
+        #[derive(Serialize)]
+        struct ClaimRequest<'a> {
+            assignee: &'a str,
+        }
+        self.post(&format!("/api/issues/{}/claim", id), &ClaimRequest { assignee })
+    }
+
+    pub fn report_issue(&self, id: &str, status: ReportStatus, message: Option<String>) -> Result<()> {
+        #[derive(Serialize)]
+        struct ReportRequest {
+            status: ReportStatus,
+            message: Option<String>,
+        }
+        let _: serde_json::Value = self.post(
+            &format!("/api/issues/{}/report", id),
+            &ReportRequest { status, message },
+        )?;
+        Ok(())
+    }
+}
@@ -36,6 +36,7 @@ mod tests {
             command: "cargo test".to_string(),
             by: Some("auto:executor".to_string()),
             message: None,
+            diagnostics: Vec::new(),
         };
 
         // Save result
@@ -78,6 +79,7 @@ mod tests {
                 command: "cargo test".to_string(),
                 by: Some("auto:executor".to_string()),
                 message: None,
+                diagnostics: Vec::new(),
             };
             storage.save_gate_run_result(&result).unwrap();
         }
@@ -134,6 +136,7 @@ mod tests {
             command: "cargo clippy".to_string(),
             by: Some("auto:executor".to_string()),
             message: None,
+            diagnostics: Vec::new(),
         };
 
         storage.save_gate_run_result(&result).unwrap();
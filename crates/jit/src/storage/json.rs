@@ -129,6 +129,10 @@ impl JsonFileStorage {
 }
 
 impl IssueStore for JsonFileStorage {
+    fn root(&self) -> &Path {
+        &self.root
+    }
+
     fn init(&self) -> Result<()> {
         let issues_dir = self.root.join(ISSUES_DIR);
 
@@ -457,6 +461,7 @@ mod tests {
             description: "Manual code review".to_string(),
             auto: false,
             example_integration: None,
+            command: None,
         };
 
         registry.gates.insert(gate.key.clone(), gate.clone());
@@ -11,6 +11,7 @@ use std::collections::HashMap;
 
 pub mod json;
 pub mod memory;
+pub mod worktree_identity;
 
 // Re-export for convenience
 pub use json::JsonFileStorage;
@@ -105,6 +106,11 @@ pub trait IssueStore: Clone {
     ///
     /// Returns an error if events cannot be read.
     fn read_events(&self) -> Result<Vec<Event>>;
+
+    /// The backend's root directory, for callers (e.g. [`crate::commands::dump`],
+    /// the job queue) that need to read or write files alongside the
+    /// structured issue/gate/event storage.
+    fn root(&self) -> &std::path::Path;
 }
 
 #[cfg(test)]
@@ -211,6 +217,7 @@ mod tests {
                 description: "A test gate".to_string(),
                 auto: false,
                 example_integration: None,
+                command: None,
             };
             new_registry.gates.insert("test-gate".to_string(), gate);
 
@@ -0,0 +1,424 @@
+//! Stable worktree identity, backed by git worktree discovery.
+//!
+//! Every worktree gets a `.jit/worktree.json` recording a `worktree_id` that
+//! downstream consumers (`ClaimCoordinator`, `HeartbeatDaemon`) use to tell
+//! "this worktree" apart from any other checkout of the same repo. The ID
+//! used to be derived from hashing the filesystem path plus a creation
+//! timestamp, with relocation detected by comparing the stored `root` string
+//! against the current path. That breaks the two cases it needs to tell
+//! apart: a worktree that was legitimately `mv`'d (same worktree, new path)
+//! looks identical to `git worktree add` copying a sibling's
+//! `.jit/worktree.json` (a different worktree, same stale path recorded).
+//!
+//! Git already has a stable identity for "this worktree": the linked gitdir
+//! under `$GIT_COMMON_DIR/worktrees/<name>` (or the common `.git` itself for
+//! the main worktree). That path doesn't move when the worktree is relocated
+//! and differs for every `git worktree add`, so [`generate_worktree_id`] now
+//! hashes the gitdir instead of the working-tree root. [`GitWorktreeInfo`]
+//! resolves root/gitdir/branch straight from `git`, the same way
+//! `commands::claim::get_current_branch` does; outside a git repository (or
+//! in the many unit tests that operate on a bare `TempDir`) discovery simply
+//! fails and [`load_or_create_worktree_identity`] falls back to hashing the
+//! worktree root directly, preserving the old behavior.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Current schema version for `worktree.json`.
+const SCHEMA_VERSION: u32 = 1;
+
+/// Persisted identity for a single worktree, stored at `.jit/worktree.json`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct WorktreeIdentity {
+    pub schema_version: u32,
+    pub worktree_id: String,
+    pub branch: String,
+    pub root: String,
+    pub created_at: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relocated_at: Option<DateTime<Utc>>,
+}
+
+/// Git-reported facts about the worktree that contains a given path.
+///
+/// `gitdir` is the stable identity: it lives under `$GIT_COMMON_DIR`, not
+/// the worktree itself, so it doesn't move when the worktree is relocated
+/// and is unique per `git worktree add`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct GitWorktreeInfo {
+    root: PathBuf,
+    gitdir: PathBuf,
+    branch: String,
+}
+
+impl GitWorktreeInfo {
+    /// Resolve worktree root, linked gitdir, and current branch from git
+    /// metadata. Fails (rather than guessing) outside a git repository.
+    fn discover(cwd: &Path) -> Result<Self> {
+        let root = git_output(cwd, &["rev-parse", "--show-toplevel"])?;
+        let gitdir = git_output(cwd, &["rev-parse", "--git-dir"])?;
+        let branch = git_output(cwd, &["rev-parse", "--abbrev-ref", "HEAD"])?;
+
+        let gitdir_path = PathBuf::from(&gitdir);
+        let gitdir_abs = if gitdir_path.is_absolute() {
+            gitdir_path
+        } else {
+            cwd.join(gitdir_path)
+        };
+        let gitdir_canonical = fs::canonicalize(&gitdir_abs)
+            .with_context(|| format!("Failed to canonicalize gitdir {}", gitdir_abs.display()))?;
+
+        Ok(Self {
+            root: PathBuf::from(root),
+            gitdir: gitdir_canonical,
+            branch,
+        })
+    }
+}
+
+/// Run `git <args>` in `cwd` and return trimmed stdout, or an error if git
+/// is missing, not a repository, or the command otherwise fails.
+fn git_output(cwd: &Path, args: &[&str]) -> Result<String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(cwd)
+        .output()
+        .with_context(|| format!("Failed to run git {}", args.join(" ")))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        anyhow::bail!(
+            "git {} failed. Are you in a git repository?\n\
+             Git error: {}",
+            args.join(" "),
+            stderr.trim()
+        );
+    }
+
+    Ok(String::from_utf8(output.stdout)?.trim().to_string())
+}
+
+/// Derive a stable, human-skimmable worktree ID from `key_path`.
+///
+/// `key_path` should be the most stable identity available: the linked
+/// gitdir when git discovery succeeds, or the worktree root as a fallback
+/// outside a git repository. The ID is a SHA-256 hash of the path string,
+/// truncated to its first 4 bytes (8 hex chars), prefixed `"wt:"`.
+pub fn generate_worktree_id(key_path: &Path) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(key_path.to_string_lossy().as_bytes());
+    let hash = hasher.finalize();
+    let hex: String = hash[..4].iter().map(|b| format!("{b:02x}")).collect();
+    format!("wt:{hex}")
+}
+
+/// Build a fresh identity for `worktree_root`/`branch`, hashing the git
+/// gitdir when `git_info` is available and falling back to the worktree
+/// root otherwise.
+fn create_identity(
+    git_info: Option<&GitWorktreeInfo>,
+    worktree_root: &Path,
+    branch: &str,
+    now: DateTime<Utc>,
+) -> WorktreeIdentity {
+    let key_path = git_info.map_or(worktree_root, |info| info.gitdir.as_path());
+    let root = git_info.map_or_else(
+        || worktree_root.to_string_lossy().to_string(),
+        |info| info.root.to_string_lossy().to_string(),
+    );
+
+    WorktreeIdentity {
+        schema_version: SCHEMA_VERSION,
+        worktree_id: generate_worktree_id(key_path),
+        branch: branch.to_string(),
+        root,
+        created_at: now,
+        relocated_at: None,
+    }
+}
+
+/// Load the worktree identity from `jit_dir/worktree.json`, creating it if
+/// absent, and reconciling it against git's view of the current worktree.
+///
+/// When git discovery succeeds, the stored identity is kept only if it was
+/// derived from the *same* gitdir; a mismatch means `.jit/worktree.json` was
+/// copied from another worktree (e.g. by `git worktree add`), so it is
+/// discarded and a fresh identity is created in its place. A matching gitdir
+/// with a different canonical root means this worktree was relocated: `root`
+/// is updated and `relocated_at` is stamped, but `worktree_id` and
+/// `created_at` are preserved.
+///
+/// Outside a git repository, falls back to the legacy behavior: the stored
+/// `root` string is compared against `worktree_root` directly to detect
+/// relocation, and the ID is derived from `worktree_root` itself.
+///
+/// # Errors
+///
+/// Returns an error if the existing file cannot be parsed or the identity
+/// cannot be written.
+pub fn load_or_create_worktree_identity(
+    jit_dir: &Path,
+    worktree_root: &Path,
+    branch: &str,
+) -> Result<WorktreeIdentity> {
+    let git_info = GitWorktreeInfo::discover(worktree_root).ok();
+    let path = jit_dir.join("worktree.json");
+    let now = Utc::now();
+
+    let existing = if path.exists() {
+        let content = fs::read_to_string(&path).context("Failed to read worktree.json")?;
+        Some(
+            serde_json::from_str::<WorktreeIdentity>(&content)
+                .context("Failed to parse worktree.json")?,
+        )
+    } else {
+        None
+    };
+
+    let identity = match existing {
+        None => create_identity(git_info.as_ref(), worktree_root, branch, now),
+        Some(mut identity) => {
+            let expected_id = git_info.as_ref().map_or_else(
+                || generate_worktree_id(worktree_root),
+                |info| generate_worktree_id(&info.gitdir),
+            );
+
+            if identity.worktree_id != expected_id {
+                // Different gitdir (or, outside git, a different root hash)
+                // than what created this file: it was copied, not moved.
+                create_identity(git_info.as_ref(), worktree_root, branch, now)
+            } else {
+                let current_root = git_info.as_ref().map_or_else(
+                    || worktree_root.to_string_lossy().to_string(),
+                    |info| info.root.to_string_lossy().to_string(),
+                );
+
+                if identity.root != current_root {
+                    identity.root = current_root;
+                    identity.relocated_at = Some(now);
+                }
+                identity
+            }
+        }
+    };
+
+    write_identity_atomic(jit_dir, &path, &identity)?;
+    Ok(identity)
+}
+
+/// Write `identity` to `path` atomically, using a write-temp-rename-fsync
+/// pattern for crash safety (mirrors `storage::heartbeat::write_heartbeat`).
+fn write_identity_atomic(jit_dir: &Path, path: &Path, identity: &WorktreeIdentity) -> Result<()> {
+    fs::create_dir_all(jit_dir).context("Failed to create .jit directory")?;
+
+    let temp_path = path.with_extension("json.tmp");
+
+    let json =
+        serde_json::to_string_pretty(identity).context("Failed to serialize worktree identity")?;
+    fs::write(&temp_path, json).context("Failed to write worktree.json temp file")?;
+
+    let file = File::open(&temp_path).context("Failed to open temp file for fsync")?;
+    file.sync_all()
+        .context("Failed to fsync worktree.json temp file")?;
+    drop(file);
+
+    fs::rename(&temp_path, path).context("Failed to rename worktree.json")?;
+
+    let parent_dir = File::open(jit_dir).context("Failed to open .jit directory for fsync")?;
+    parent_dir
+        .sync_all()
+        .context("Failed to fsync .jit directory")?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_generate_worktree_id_is_deterministic() {
+        let path = PathBuf::from("/home/user/project/.git");
+
+        let id1 = generate_worktree_id(&path);
+        let id2 = generate_worktree_id(&path);
+
+        assert_eq!(id1, id2, "Same input should produce same ID");
+    }
+
+    #[test]
+    fn test_generate_worktree_id_format() {
+        let path = PathBuf::from("/test/path");
+
+        let id = generate_worktree_id(&path);
+
+        assert!(id.starts_with("wt:"), "ID should start with 'wt:'");
+        assert_eq!(id.len(), 11, "ID should be 11 chars (wt: + 8 hex)");
+
+        let hex_part = &id[3..];
+        assert!(
+            hex_part.chars().all(|c| c.is_ascii_hexdigit()),
+            "ID suffix should be hex: {}",
+            hex_part
+        );
+    }
+
+    #[test]
+    fn test_generate_worktree_id_different_paths_different_ids() {
+        let id1 = generate_worktree_id(&PathBuf::from("/path/one"));
+        let id2 = generate_worktree_id(&PathBuf::from("/path/two"));
+
+        assert_ne!(id1, id2, "Different paths should produce different IDs");
+    }
+
+    #[test]
+    fn test_worktree_identity_serialization() {
+        let identity = WorktreeIdentity {
+            schema_version: 1,
+            worktree_id: "wt:abc123ef".to_string(),
+            branch: "main".to_string(),
+            root: "/path/to/worktree".to_string(),
+            created_at: chrono::Utc::now(),
+            relocated_at: None,
+        };
+
+        let json = serde_json::to_string_pretty(&identity).unwrap();
+        let deserialized: WorktreeIdentity = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(deserialized.worktree_id, identity.worktree_id);
+        assert_eq!(deserialized.branch, identity.branch);
+        assert_eq!(deserialized.root, identity.root);
+    }
+
+    #[test]
+    fn test_load_or_create_creates_new_identity() {
+        let temp_dir = TempDir::new().unwrap();
+        let jit_dir = temp_dir.path().join(".jit");
+        fs::create_dir_all(&jit_dir).unwrap();
+
+        let branch = "test-branch".to_string();
+        let identity = load_or_create_worktree_identity(&jit_dir, temp_dir.path(), &branch).unwrap();
+
+        assert!(identity.worktree_id.starts_with("wt:"));
+        assert_eq!(identity.branch, branch);
+        assert_eq!(identity.root, temp_dir.path().to_string_lossy().to_string());
+        assert_eq!(identity.schema_version, 1);
+        assert!(identity.relocated_at.is_none());
+
+        let wt_file = jit_dir.join("worktree.json");
+        assert!(wt_file.exists(), "worktree.json should be created");
+    }
+
+    #[test]
+    fn test_load_or_create_loads_existing_identity() {
+        let temp_dir = TempDir::new().unwrap();
+        let jit_dir = temp_dir.path().join(".jit");
+        fs::create_dir_all(&jit_dir).unwrap();
+
+        let branch = "test-branch".to_string();
+
+        let identity1 = load_or_create_worktree_identity(&jit_dir, temp_dir.path(), &branch).unwrap();
+        let identity2 = load_or_create_worktree_identity(&jit_dir, temp_dir.path(), &branch).unwrap();
+
+        assert_eq!(identity1.worktree_id, identity2.worktree_id);
+        assert_eq!(identity1.created_at, identity2.created_at);
+    }
+
+    #[test]
+    fn test_relocation_detection_updates_path() {
+        let temp_dir = TempDir::new().unwrap();
+        let jit_dir = temp_dir.path().join(".jit");
+        fs::create_dir_all(&jit_dir).unwrap();
+
+        let branch = "test-branch".to_string();
+
+        let mut identity =
+            load_or_create_worktree_identity(&jit_dir, temp_dir.path(), &branch).unwrap();
+        let original_id = identity.worktree_id.clone();
+
+        // Simulate relocation by rewriting the stored root to a stale path;
+        // outside a git repo, the ID hash is keyed on worktree_root and is
+        // unaffected since only `root` is being overwritten here.
+        identity.root = "/old/path".to_string();
+        let wt_file = jit_dir.join("worktree.json");
+        let json = serde_json::to_string_pretty(&identity).unwrap();
+        fs::write(&wt_file, json).unwrap();
+
+        let relocated = load_or_create_worktree_identity(&jit_dir, temp_dir.path(), &branch).unwrap();
+
+        assert_eq!(
+            relocated.worktree_id, original_id,
+            "ID should remain stable"
+        );
+        assert_eq!(
+            relocated.root,
+            temp_dir.path().to_string_lossy().to_string(),
+            "Root should be updated"
+        );
+        assert!(
+            relocated.relocated_at.is_some(),
+            "relocated_at should be set"
+        );
+    }
+
+    #[test]
+    fn test_copied_identity_with_wrong_id_is_replaced() {
+        let temp_dir = TempDir::new().unwrap();
+        let jit_dir = temp_dir.path().join(".jit");
+        fs::create_dir_all(&jit_dir).unwrap();
+
+        // Simulate `git worktree add` copying a sibling's worktree.json: the
+        // stored ID doesn't match what this root/gitdir would hash to.
+        let copied = WorktreeIdentity {
+            schema_version: 1,
+            worktree_id: "wt:wrongid1".to_string(),
+            branch: "main".to_string(),
+            root: "/some/other/worktree".to_string(),
+            created_at: chrono::Utc::now(),
+            relocated_at: None,
+        };
+        let wt_file = jit_dir.join("worktree.json");
+        fs::write(&wt_file, serde_json::to_string_pretty(&copied).unwrap()).unwrap();
+
+        let identity =
+            load_or_create_worktree_identity(&jit_dir, temp_dir.path(), "main").unwrap();
+
+        assert_ne!(
+            identity.worktree_id, "wt:wrongid1",
+            "copied identity with a mismatched ID should be replaced"
+        );
+        assert_eq!(identity.root, temp_dir.path().to_string_lossy().to_string());
+        assert!(identity.relocated_at.is_none());
+    }
+
+    #[test]
+    fn test_atomic_write_on_relocation() {
+        let temp_dir = TempDir::new().unwrap();
+        let jit_dir = temp_dir.path().join(".jit");
+        fs::create_dir_all(&jit_dir).unwrap();
+
+        let branch = "test-branch".to_string();
+
+        let mut identity =
+            load_or_create_worktree_identity(&jit_dir, temp_dir.path(), &branch).unwrap();
+        identity.root = "/old/path".to_string();
+
+        let wt_file = jit_dir.join("worktree.json");
+        let json = serde_json::to_string_pretty(&identity).unwrap();
+        fs::write(&wt_file, json).unwrap();
+
+        let _relocated = load_or_create_worktree_identity(&jit_dir, temp_dir.path(), &branch).unwrap();
+
+        let tmp_file = jit_dir.join("worktree.json.tmp");
+        assert!(!tmp_file.exists(), "Temp file should be cleaned up");
+
+        let content = fs::read_to_string(&wt_file).unwrap();
+        let _parsed: WorktreeIdentity = serde_json::from_str(&content).unwrap();
+    }
+}
@@ -99,6 +99,12 @@ pub enum ValidationWarning {
     },
     /// A leaf-level issue (task) has no parent association labels
     OrphanedLeaf { issue_id: String, type_name: String },
+    /// A user-defined rule (see [`CustomRule`]) flagged this issue.
+    Custom {
+        issue_id: String,
+        code: String,
+        message: String,
+    },
 }
 
 /// Errors that can occur during configuration validation.
@@ -142,6 +148,12 @@ pub struct HierarchyConfig {
     /// e.g., "epic" -> "epic" means type:epic uses epic:* labels
     /// e.g., "release" -> "milestone" means type:release uses milestone:* labels
     label_associations: HashMap<String, String>,
+
+    /// User-defined rules run by [`run_custom_rules`] alongside the
+    /// built-in strategic-label/orphan checks. Empty by default; populated
+    /// via [`HierarchyConfig::with_custom_rules`] (see `--rules <path>` on
+    /// `jit validate` and `jit issue create`).
+    custom_rules: Vec<CustomRule>,
 }
 
 impl Default for HierarchyConfig {
@@ -165,6 +177,7 @@ impl Default for HierarchyConfig {
         Self {
             types,
             label_associations,
+            custom_rules: Vec::new(),
         }
     }
 }
@@ -198,9 +211,22 @@ impl HierarchyConfig {
         Ok(Self {
             types,
             label_associations,
+            custom_rules: Vec::new(),
         })
     }
 
+    /// Attaches user-defined rules (e.g. loaded via `--rules <path>` from a
+    /// [`load_custom_rules`] file) to run alongside the built-in checks.
+    pub fn with_custom_rules(mut self, rules: Vec<CustomRule>) -> Self {
+        self.custom_rules = rules;
+        self
+    }
+
+    /// The custom rules currently attached to this config.
+    pub fn custom_rules(&self) -> &[CustomRule] {
+        &self.custom_rules
+    }
+
     /// Returns the level of a type, or None if the type is not in the hierarchy.
     pub fn get_level(&self, type_name: &str) -> Option<u8> {
         self.types.get(type_name).copied()
@@ -527,6 +553,153 @@ pub fn validate_orphans(
     warnings
 }
 
+/// A user-defined validation rule: an external command that receives a
+/// read-only JSON view of an issue on stdin and emits zero or more
+/// warnings as a JSON array on stdout.
+///
+/// This lets repositories express policies like "every `type:task` under a
+/// `milestone:*` must also carry an `epic:*` label" or "critical issues
+/// must declare an assignee" without recompiling `jit`, matching the
+/// stdin-piping convention used by `jit gate run` and transition hooks.
+/// See [`run_custom_rules`] for the execution contract and [`load_custom_rules`]
+/// for the `--rules <path>` file format.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+pub struct CustomRule {
+    /// Identifies this rule in [`ValidationWarning::Custom`] when the
+    /// command's own output doesn't supply one.
+    pub code: String,
+    /// Shell command run via `sh -c`. Receives the issue view (see
+    /// [`RuleIssueView`]) as JSON on stdin and must print a JSON array of
+    /// `{"code": "...", "message": "..."}` objects (both fields optional;
+    /// `code` falls back to this rule's `code`) to stdout. A non-zero exit
+    /// or unparseable stdout is treated as "no warnings" -- a broken rule
+    /// must never abort validation.
+    pub command: String,
+}
+
+/// The read-only view of an issue handed to a [`CustomRule`] command on
+/// stdin: labels, state, priority, dependencies, and context, but no
+/// mutation capability.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct RuleIssueView<'a> {
+    pub issue_id: &'a str,
+    pub labels: &'a [String],
+    pub state: crate::domain::State,
+    pub priority: crate::domain::Priority,
+    /// (dependency issue id, dependency labels) pairs, for rules that need
+    /// to reason about what this issue depends on.
+    pub dependencies: Vec<(&'a str, &'a [String])>,
+    pub context: &'a HashMap<String, String>,
+}
+
+/// One warning emitted by a custom rule's command on stdout.
+#[derive(Debug, Clone, serde::Deserialize)]
+struct RuleWarningOutput {
+    code: Option<String>,
+    message: String,
+}
+
+/// Loads custom rules from a `--rules <path>` file.
+///
+/// # Format
+///
+/// ```toml
+/// [[rule]]
+/// code = "task-needs-epic"
+/// command = "my-hierarchy-rules task-needs-epic"
+/// ```
+pub fn load_custom_rules(path: &std::path::Path) -> anyhow::Result<Vec<CustomRule>> {
+    #[derive(serde::Deserialize)]
+    struct RulesFile {
+        #[serde(default)]
+        rule: Vec<CustomRule>,
+    }
+
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("Failed to read rules file '{}': {}", path.display(), e))?;
+    let parsed: RulesFile = toml::from_str(&content)
+        .map_err(|e| anyhow::anyhow!("Failed to parse rules file '{}': {}", path.display(), e))?;
+    Ok(parsed.rule)
+}
+
+/// Runs every rule in `config` against `issue` and collects the warnings
+/// they emit. Never fails: a rule whose command can't be spawned, exits
+/// non-zero, or prints output that isn't the expected JSON array is
+/// skipped rather than surfaced as an error, matching the "a sink being
+/// unreachable must not abort the command" contract `jit` uses elsewhere
+/// for optional external integrations (see [`crate::notifier::deliver`]).
+pub fn run_custom_rules(
+    config: &HierarchyConfig,
+    issue: &crate::domain::Issue,
+    deps: &[(String, Vec<String>)],
+) -> Vec<ValidationWarning> {
+    if config.custom_rules.is_empty() {
+        return Vec::new();
+    }
+
+    let view = RuleIssueView {
+        issue_id: &issue.id,
+        labels: &issue.labels,
+        state: issue.state,
+        priority: issue.priority,
+        dependencies: deps
+            .iter()
+            .map(|(id, labels)| (id.as_str(), labels.as_slice()))
+            .collect(),
+        context: &issue.context,
+    };
+    let Ok(payload) = serde_json::to_vec(&view) else {
+        return Vec::new();
+    };
+
+    let mut warnings = Vec::new();
+    for rule in &config.custom_rules {
+        for output in run_rule_command(&rule.command, &payload) {
+            warnings.push(ValidationWarning::Custom {
+                issue_id: issue.id.clone(),
+                code: output.code.unwrap_or_else(|| rule.code.clone()),
+                message: output.message,
+            });
+        }
+    }
+    warnings
+}
+
+/// Spawns `command` via the shell, pipes `payload` to its stdin, and parses
+/// its stdout as a JSON array of [`RuleWarningOutput`]. Returns an empty
+/// vec on any failure.
+fn run_rule_command(command: &str, payload: &[u8]) -> Vec<RuleWarningOutput> {
+    use std::io::Write;
+    use std::process::{Command, Stdio};
+
+    let mut child = match Command::new("sh")
+        .arg("-c")
+        .arg(command)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+    {
+        Ok(child) => child,
+        Err(_) => return Vec::new(),
+    };
+
+    if let Some(mut stdin) = child.stdin.take() {
+        if stdin.write_all(payload).is_err() {
+            return Vec::new();
+        }
+    }
+
+    let Ok(output) = child.wait_with_output() else {
+        return Vec::new();
+    };
+    if !output.status.success() {
+        return Vec::new();
+    }
+
+    serde_json::from_slice(&output.stdout).unwrap_or_default()
+}
+
 /// Detects membership validation issues for an issue.
 ///
 /// Checks that membership labels (epic:*, milestone:*, etc.) reference actual issues
@@ -898,6 +1071,74 @@ mod tests {
         let fixes = generate_fixes(&issues);
         assert!(fixes.is_empty()); // No fix generated without suggestion
     }
+
+    #[test]
+    fn test_run_custom_rules_collects_rule_output() {
+        let mut issue = crate::domain::Issue::new("Fix bug".to_string(), String::new());
+        issue.labels = vec!["type:task".to_string()];
+
+        let config = HierarchyConfig::default().with_custom_rules(vec![CustomRule {
+            code: "default-code".to_string(),
+            command: "cat > /dev/null; echo '[{\"message\": \"no assignee\"}]'".to_string(),
+        }]);
+
+        let warnings = run_custom_rules(&config, &issue, &[]);
+
+        assert_eq!(warnings.len(), 1);
+        match &warnings[0] {
+            ValidationWarning::Custom {
+                issue_id,
+                code,
+                message,
+            } => {
+                assert_eq!(issue_id, &issue.id);
+                assert_eq!(code, "default-code"); // falls back to rule's code
+                assert_eq!(message, "no assignee");
+            }
+            other => panic!("Expected Custom warning, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_run_custom_rules_no_rules_is_empty() {
+        let issue = crate::domain::Issue::new("Fix bug".to_string(), String::new());
+        let config = HierarchyConfig::default();
+
+        assert!(run_custom_rules(&config, &issue, &[]).is_empty());
+    }
+
+    #[test]
+    fn test_run_custom_rules_failing_command_is_skipped() {
+        let issue = crate::domain::Issue::new("Fix bug".to_string(), String::new());
+        let config = HierarchyConfig::default().with_custom_rules(vec![CustomRule {
+            code: "broken".to_string(),
+            command: "exit 1".to_string(),
+        }]);
+
+        assert!(run_custom_rules(&config, &issue, &[]).is_empty());
+    }
+
+    #[test]
+    fn test_load_custom_rules_parses_toml() {
+        use std::io::Write;
+
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        write!(
+            file,
+            r#"
+[[rule]]
+code = "task-needs-epic"
+command = "my-rules task-needs-epic"
+"#
+        )
+        .unwrap();
+
+        let rules = load_custom_rules(file.path()).unwrap();
+
+        assert_eq!(rules.len(), 1);
+        assert_eq!(rules[0].code, "task-needs-epic");
+        assert_eq!(rules[0].command, "my-rules task-needs-epic");
+    }
 }
 
 #[cfg(test)]
@@ -50,6 +50,8 @@ pub fn export_dot(graph: &DependencyGraph<Issue>) -> String {
             State::Gated => "orange",
             State::Done => "lightgreen",
             State::Archived => "gray",
+            State::Overdue => "red",
+            State::Rejected => "firebrick",
         };
         output.push_str(&format!(
             "  \"{}\" [label=\"{}\", fillcolor={}, style=\"rounded,filled\"];\n",
@@ -108,6 +110,8 @@ pub fn export_mermaid(graph: &DependencyGraph<Issue>) -> String {
             State::Gated => "gated",
             State::Done => "done",
             State::Archived => "archived",
+            State::Overdue => "overdue",
+            State::Rejected => "rejected",
         };
         output.push_str(&format!(
             "  {}[\"{}\"]:::{}\n",
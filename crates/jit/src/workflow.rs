@@ -0,0 +1,279 @@
+//! User-definable workflow states and transition rules.
+//!
+//! The built-in lifecycle (`Backlog -> Ready -> InProgress -> Gated -> Done`,
+//! plus `Archived`) is a sensible default, but repositories can override the
+//! allowed transition table, which states count as "open" vs "closed", and
+//! which state a backlog issue auto-transitions into once all of its
+//! dependencies close. Configuration lives in the `[workflow]` section of
+//! `.jit/config.toml`; see [`crate::config::WorkflowConfigToml`].
+
+use crate::config::WorkflowConfigToml;
+use crate::domain::State;
+use anyhow::{anyhow, Result};
+
+/// Resolved, validated workflow configuration used by the transition and
+/// auto-transition machinery.
+#[derive(Debug, Clone)]
+pub struct WorkflowConfig {
+    /// Ordered list of states (for display / progress purposes).
+    pub states: Vec<State>,
+    /// Allowed `(from, to)` transitions.
+    pub transitions: Vec<(State, State)>,
+    /// States considered open/actionable.
+    pub open_states: Vec<State>,
+    /// States considered closed/terminal.
+    pub closed_states: Vec<State>,
+    /// Target state for the "all dependencies closed" auto-transition.
+    pub auto_ready_target: State,
+    /// External command hooks to run on specific transitions.
+    pub hooks: Vec<TransitionHook>,
+}
+
+/// A shell command to run when an issue crosses a specific `from -> to`
+/// transition. See [`crate::config::TransitionHookToml`].
+#[derive(Debug, Clone)]
+pub struct TransitionHook {
+    pub from: State,
+    pub to: State,
+    pub command: String,
+    pub blocking: bool,
+}
+
+impl Default for WorkflowConfig {
+    fn default() -> Self {
+        use State::*;
+        Self {
+            states: vec![
+                Backlog, Ready, InProgress, Gated, Done, Archived, Overdue, Rejected,
+            ],
+            transitions: vec![
+                (Backlog, Ready),
+                (Ready, InProgress),
+                (InProgress, Gated),
+                (Gated, Done),
+                (InProgress, Done),
+                (Backlog, Archived),
+                (Ready, Archived),
+                (InProgress, Archived),
+                (Backlog, Overdue),
+                (Ready, Overdue),
+                (Overdue, Ready),
+                (Overdue, InProgress),
+                (Overdue, Archived),
+                (Backlog, Rejected),
+                (Ready, Rejected),
+                (InProgress, Rejected),
+                (Overdue, Rejected),
+            ],
+            open_states: vec![Backlog, Ready, InProgress, Gated, Overdue],
+            closed_states: vec![Done, Archived, Rejected],
+            auto_ready_target: Ready,
+            hooks: vec![],
+        }
+    }
+}
+
+fn parse_state_name(name: &str) -> Result<State> {
+    match name.to_lowercase().replace('_', "-").as_str() {
+        "backlog" => Ok(State::Backlog),
+        "ready" => Ok(State::Ready),
+        "in-progress" | "inprogress" => Ok(State::InProgress),
+        "gated" => Ok(State::Gated),
+        "done" => Ok(State::Done),
+        "archived" => Ok(State::Archived),
+        "overdue" => Ok(State::Overdue),
+        "rejected" => Ok(State::Rejected),
+        other => Err(anyhow!(
+            "Unknown workflow state '{}': must be one of backlog, ready, in-progress, gated, done, archived, overdue, rejected",
+            other
+        )),
+    }
+}
+
+impl WorkflowConfig {
+    /// Resolve a [`WorkflowConfigToml`] (as loaded from `.jit/config.toml`)
+    /// into a validated `WorkflowConfig`, falling back to [`Self::default`]
+    /// for any field the user didn't override.
+    pub fn from_toml(toml: Option<&WorkflowConfigToml>) -> Result<Self> {
+        let defaults = Self::default();
+        let Some(toml) = toml else {
+            return Ok(defaults);
+        };
+
+        let states = match &toml.states {
+            Some(names) => names
+                .iter()
+                .map(|n| parse_state_name(n))
+                .collect::<Result<Vec<_>>>()?,
+            None => defaults.states.clone(),
+        };
+
+        let transitions = match &toml.transitions {
+            Some(rows) => rows
+                .iter()
+                .map(|t| Ok((parse_state_name(&t.from)?, parse_state_name(&t.to)?)))
+                .collect::<Result<Vec<_>>>()?,
+            None => defaults.transitions.clone(),
+        };
+
+        let open_states = match &toml.open_states {
+            Some(names) => names
+                .iter()
+                .map(|n| parse_state_name(n))
+                .collect::<Result<Vec<_>>>()?,
+            None => defaults.open_states.clone(),
+        };
+
+        let closed_states = match &toml.closed_states {
+            Some(names) => names
+                .iter()
+                .map(|n| parse_state_name(n))
+                .collect::<Result<Vec<_>>>()?,
+            None => defaults.closed_states.clone(),
+        };
+
+        let auto_ready_target = match &toml.auto_ready_transition {
+            Some(name) => parse_state_name(name)?,
+            None => defaults.auto_ready_target,
+        };
+
+        let hooks = match &toml.hooks {
+            Some(rows) => rows
+                .iter()
+                .map(|h| {
+                    Ok(TransitionHook {
+                        from: parse_state_name(&h.from)?,
+                        to: parse_state_name(&h.to)?,
+                        command: h.command.clone(),
+                        blocking: h.blocking,
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?,
+            None => defaults.hooks.clone(),
+        };
+
+        Ok(Self {
+            states,
+            transitions,
+            open_states,
+            closed_states,
+            auto_ready_target,
+            hooks,
+        })
+    }
+
+    /// Whether `from -> to` is a legal transition under this table.
+    pub fn is_allowed(&self, from: State, to: State) -> bool {
+        from == to || self.transitions.iter().any(|(f, t)| *f == from && *t == to)
+    }
+
+    /// Whether `state` is classified as open.
+    pub fn is_open(&self, state: State) -> bool {
+        self.open_states.contains(&state)
+    }
+
+    /// Whether `state` is classified as closed.
+    pub fn is_closed(&self, state: State) -> bool {
+        self.closed_states.contains(&state)
+    }
+
+    /// The hooks configured for a specific `from -> to` transition, in
+    /// configuration order.
+    pub fn hooks_for(&self, from: State, to: State) -> Vec<&TransitionHook> {
+        self.hooks
+            .iter()
+            .filter(|h| h.from == from && h.to == to)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_allows_backlog_to_ready() {
+        let config = WorkflowConfig::default();
+        assert!(config.is_allowed(State::Backlog, State::Ready));
+        assert!(!config.is_allowed(State::Done, State::Backlog));
+    }
+
+    #[test]
+    fn test_default_open_closed_classification() {
+        let config = WorkflowConfig::default();
+        assert!(config.is_open(State::InProgress));
+        assert!(config.is_closed(State::Done));
+        assert!(!config.is_closed(State::Ready));
+    }
+
+    #[test]
+    fn test_custom_transition_table_rejects_unlisted_move() {
+        let toml = WorkflowConfigToml {
+            states: None,
+            transitions: Some(vec![crate::config::TransitionToml {
+                from: "backlog".to_string(),
+                to: "ready".to_string(),
+            }]),
+            open_states: None,
+            closed_states: None,
+            auto_ready_transition: None,
+            hooks: None,
+        };
+
+        let config = WorkflowConfig::from_toml(Some(&toml)).unwrap();
+        assert!(config.is_allowed(State::Backlog, State::Ready));
+        assert!(!config.is_allowed(State::Ready, State::InProgress));
+    }
+
+    #[test]
+    fn test_custom_auto_ready_target() {
+        let toml = WorkflowConfigToml {
+            states: None,
+            transitions: None,
+            open_states: None,
+            closed_states: None,
+            auto_ready_transition: Some("in-progress".to_string()),
+            hooks: None,
+        };
+
+        let config = WorkflowConfig::from_toml(Some(&toml)).unwrap();
+        assert_eq!(config.auto_ready_target, State::InProgress);
+    }
+
+    #[test]
+    fn test_unknown_state_name_errors() {
+        let toml = WorkflowConfigToml {
+            states: Some(vec!["not-a-state".to_string()]),
+            transitions: None,
+            open_states: None,
+            closed_states: None,
+            auto_ready_transition: None,
+            hooks: None,
+        };
+
+        assert!(WorkflowConfig::from_toml(Some(&toml)).is_err());
+    }
+
+    #[test]
+    fn test_hooks_for_matches_only_configured_transition() {
+        let toml = WorkflowConfigToml {
+            states: None,
+            transitions: None,
+            open_states: None,
+            closed_states: None,
+            auto_ready_transition: None,
+            hooks: Some(vec![crate::config::TransitionHookToml {
+                from: "in-progress".to_string(),
+                to: "gated".to_string(),
+                command: "echo hi".to_string(),
+                blocking: true,
+            }]),
+        };
+
+        let config = WorkflowConfig::from_toml(Some(&toml)).unwrap();
+        let matched = config.hooks_for(State::InProgress, State::Gated);
+        assert_eq!(matched.len(), 1);
+        assert!(matched[0].blocking);
+        assert!(config.hooks_for(State::Backlog, State::Ready).is_empty());
+    }
+}
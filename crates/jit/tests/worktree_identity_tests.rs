@@ -20,23 +20,19 @@ fn jit_binary() -> String {
 
 #[test]
 fn test_generate_worktree_id_is_deterministic() {
-    let path = PathBuf::from("/home/user/project");
-    let timestamp = chrono::DateTime::parse_from_rfc3339("2026-01-06T20:00:00Z")
-        .unwrap()
-        .with_timezone(&chrono::Utc);
+    let path = PathBuf::from("/home/user/project/.git");
 
-    let id1 = generate_worktree_id(&path, timestamp);
-    let id2 = generate_worktree_id(&path, timestamp);
+    let id1 = generate_worktree_id(&path);
+    let id2 = generate_worktree_id(&path);
 
-    assert_eq!(id1, id2, "Same inputs should produce same ID");
+    assert_eq!(id1, id2, "Same input should produce same ID");
 }
 
 #[test]
 fn test_generate_worktree_id_format() {
     let path = PathBuf::from("/test/path");
-    let timestamp = chrono::Utc::now();
 
-    let id = generate_worktree_id(&path, timestamp);
+    let id = generate_worktree_id(&path);
 
     // Should start with "wt:"
     assert!(id.starts_with("wt:"), "ID should start with 'wt:'");
@@ -55,32 +51,24 @@ fn test_generate_worktree_id_format() {
 
 #[test]
 fn test_generate_worktree_id_different_paths_different_ids() {
-    let timestamp = chrono::Utc::now();
-
-    let id1 = generate_worktree_id(&PathBuf::from("/path/one"), timestamp);
-    let id2 = generate_worktree_id(&PathBuf::from("/path/two"), timestamp);
+    let id1 = generate_worktree_id(&PathBuf::from("/path/one"));
+    let id2 = generate_worktree_id(&PathBuf::from("/path/two"));
 
     assert_ne!(id1, id2, "Different paths should produce different IDs");
 }
 
 #[test]
-fn test_generate_worktree_id_different_timestamps_different_ids() {
+fn test_generate_worktree_id_is_stable_across_time() {
+    // The ID no longer depends on a creation timestamp, only the gitdir (or
+    // worktree root outside git) -- it must stay identical across calls
+    // made at different moments.
     let path = PathBuf::from("/same/path");
 
-    let time1 = chrono::DateTime::parse_from_rfc3339("2026-01-06T20:00:00Z")
-        .unwrap()
-        .with_timezone(&chrono::Utc);
-    let time2 = chrono::DateTime::parse_from_rfc3339("2026-01-06T20:01:00Z")
-        .unwrap()
-        .with_timezone(&chrono::Utc);
-
-    let id1 = generate_worktree_id(&path, time1);
-    let id2 = generate_worktree_id(&path, time2);
+    let id1 = generate_worktree_id(&path);
+    std::thread::sleep(std::time::Duration::from_millis(5));
+    let id2 = generate_worktree_id(&path);
 
-    assert_ne!(
-        id1, id2,
-        "Different timestamps should produce different IDs"
-    );
+    assert_eq!(id1, id2, "ID should be stable regardless of when it's generated");
 }
 
 #[test]
@@ -0,0 +1,69 @@
+//! Bearer-token authentication for the remote API.
+//!
+//! There's no per-user identity here -- every request must carry
+//! `Authorization: Bearer <token>` matching the server's single configured
+//! secret (`--token` / `ADMIN_AUTH_TOKEN`), same shared-secret trust model
+//! as `jit`'s notifier webhook sinks.
+
+use axum::{
+    extract::{Request, State},
+    http::{header, StatusCode},
+    middleware::Next,
+    response::Response,
+};
+use std::sync::Arc;
+
+/// Reject any request whose `Authorization: Bearer <token>` header doesn't
+/// match `expected_token`.
+pub async fn require_bearer_token(
+    State(expected_token): State<Arc<String>>,
+    request: Request,
+    next: Next,
+) -> Result<Response, StatusCode> {
+    let provided = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+
+    match provided {
+        Some(token) if constant_time_eq(token.as_bytes(), expected_token.as_bytes()) => {
+            Ok(next.run(request).await)
+        }
+        _ => Err(StatusCode::UNAUTHORIZED),
+    }
+}
+
+/// Compare two byte strings in time independent of where they first differ,
+/// so a mismatching request can't be used to probe the shared bearer secret
+/// one byte at a time. `&str`/`Vec<u8>` equality short-circuits on the first
+/// differing byte and leaks exactly that kind of timing signal.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter()
+        .zip(b.iter())
+        .fold(0u8, |acc, (x, y)| acc | (x ^ y))
+        == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::constant_time_eq;
+
+    #[test]
+    fn test_constant_time_eq_matches_identical_bytes() {
+        assert!(constant_time_eq(b"secret-token", b"secret-token"));
+    }
+
+    #[test]
+    fn test_constant_time_eq_rejects_mismatched_bytes() {
+        assert!(!constant_time_eq(b"secret-token", b"secret-tokeN"));
+    }
+
+    #[test]
+    fn test_constant_time_eq_rejects_different_lengths() {
+        assert!(!constant_time_eq(b"short", b"much-longer-token"));
+    }
+}
@@ -3,10 +3,11 @@
 //! Provides a web API for the Just-In-Time issue tracker, enabling web UI
 //! and external integrations to query and visualize issues.
 
+mod auth;
 mod routes;
 
 use anyhow::Result;
-use axum::Router;
+use axum::{middleware, Router};
 use clap::Parser;
 use std::sync::Arc;
 use tower_http::cors::{Any, CorsLayer};
@@ -29,6 +30,20 @@ struct Args {
     /// Address to bind the server to
     #[arg(short, long, default_value = "0.0.0.0:3000")]
     bind: String,
+
+    /// Bearer token every request must present via `Authorization: Bearer <token>`.
+    ///
+    /// Can also be set via ADMIN_AUTH_TOKEN.
+    #[arg(long, env = "ADMIN_AUTH_TOKEN")]
+    token: String,
+
+    /// TLS certificate (PEM). Requires --tls-key. Omit both to serve plain HTTP.
+    #[arg(long, requires = "tls_key")]
+    tls_cert: Option<String>,
+
+    /// TLS private key (PEM). Requires --tls-cert.
+    #[arg(long, requires = "tls_cert")]
+    tls_key: Option<String>,
 }
 
 #[tokio::main]
@@ -65,17 +80,31 @@ async fn main() -> Result<()> {
         .allow_methods(Any)
         .allow_headers(Any);
 
+    // Every route, including /api/health, requires the shared bearer token.
+    let token = Arc::new(args.token.clone());
+
     // Build router
     let app = Router::new()
         .nest("/api", routes::create_routes(executor))
+        .layer(middleware::from_fn_with_state(token, auth::require_bearer_token))
         .layer(cors)
         .layer(tower_http::trace::TraceLayer::new_for_http());
 
-    // Start server
-    let listener = tokio::net::TcpListener::bind(&args.bind).await?;
-    info!("Server listening on http://{}", args.bind);
-
-    axum::serve(listener, app).await?;
+    // Start server, optionally over rustls TLS when a cert/key pair is configured.
+    match (&args.tls_cert, &args.tls_key) {
+        (Some(cert), Some(key)) => {
+            let tls_config = axum_server::tls_rustls::RustlsConfig::from_pem_file(cert, key).await?;
+            info!("Server listening on https://{}", args.bind);
+            axum_server::bind_rustls(args.bind.parse()?, tls_config)
+                .serve(app.into_make_service())
+                .await?;
+        }
+        _ => {
+            let listener = tokio::net::TcpListener::bind(&args.bind).await?;
+            info!("Server listening on http://{}", args.bind);
+            axum::serve(listener, app).await?;
+        }
+    }
 
     Ok(())
 }
@@ -4,14 +4,16 @@ use axum::{
     extract::{Path, Query, State},
     http::StatusCode,
     response::IntoResponse,
-    routing::get,
+    routing::{get, post},
     Json, Router,
 };
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
-use jit::commands::CommandExecutor;
-use jit::domain::{Issue, Priority, State as IssueState};
+use jit::commands::{parse_priority, parse_state, CommandExecutor, UpdateOperations};
+use jit::domain::{Issue, Priority, ReportStatus, State as IssueState};
+use jit::output::{JsonError, JsonOutput};
+use jit::query::QueryFilter;
 use jit::search::{SearchOptions, SearchResult};
 use jit::storage::IssueStore;
 
@@ -24,7 +26,7 @@ pub fn create_routes<S: IssueStore + Send + Sync + 'static>(
 ) -> Router {
     Router::new()
         .route("/health", get(health_check))
-        .route("/issues", get(list_issues))
+        .route("/issues", get(list_issues).patch(bulk_update_issues))
         .route("/issues/:id", get(get_issue))
         .route("/graph", get(get_graph))
         .route("/status", get(get_status))
@@ -42,6 +44,13 @@ pub fn create_routes<S: IssueStore + Send + Sync + 'static>(
         .route("/api/config/strategic-types", get(get_strategic_types))
         .route("/api/config/hierarchy", get(get_hierarchy))
         .route("/api/config/namespaces", get(get_namespaces))
+        .route("/query/ready", get(query_ready))
+        .route("/query/blocked", get(query_blocked))
+        .route("/query/assignee", get(query_by_assignee))
+        .route("/query/state", get(query_by_state))
+        .route("/query/label", get(query_by_label))
+        .route("/issues/:id/claim", post(claim_issue))
+        .route("/issues/:id/report", post(report_issue))
         .with_state(executor)
 }
 
@@ -54,17 +63,139 @@ async fn health_check() -> impl IntoResponse {
     }))
 }
 
-/// List all issues
+/// Query parameters for listing issues
+#[derive(Debug, Deserialize)]
+struct ListIssuesQuery {
+    /// Label pattern ('namespace:value' or 'namespace:*'). When present,
+    /// the plain issue list is replaced by the same `{success, data:
+    /// {filters, count, issues}}` envelope `jit query all --label --json`
+    /// produces.
+    label: Option<String>,
+}
+
+/// `data.filters` echo for a label-filtered `/issues` response
+#[derive(Debug, Serialize)]
+struct IssuesFilters {
+    label: String,
+}
+
+/// `data` body for a label-filtered `/issues` response
+#[derive(Debug, Serialize)]
+struct IssuesQueryResponse {
+    filters: IssuesFilters,
+    count: usize,
+    issues: Vec<Issue>,
+}
+
+/// List all issues, or issues matching a label pattern
 async fn list_issues<S: IssueStore>(
+    Query(params): Query<ListIssuesQuery>,
     State(executor): State<AppState<S>>,
-) -> Result<Json<Vec<Issue>>, StatusCode> {
-    executor
-        .list_issues(None, None, None)
-        .map(Json)
-        .map_err(|e| {
-            tracing::error!("Failed to list issues: {:?}", e);
-            StatusCode::INTERNAL_SERVER_ERROR
-        })
+) -> impl IntoResponse {
+    let Some(pattern) = params.label else {
+        return match executor.list_issues(None, None, None) {
+            Ok(issues) => Json(issues).into_response(),
+            Err(e) => {
+                tracing::error!("Failed to list issues: {:?}", e);
+                StatusCode::INTERNAL_SERVER_ERROR.into_response()
+            }
+        };
+    };
+
+    match executor.query_by_label(&pattern) {
+        Ok(issues) => {
+            let response = IssuesQueryResponse {
+                filters: IssuesFilters { label: pattern },
+                count: issues.len(),
+                issues,
+            };
+            Json(JsonOutput::success(response)).into_response()
+        }
+        Err(e) => {
+            tracing::error!("Failed to query issues for label {}: {:?}", pattern, e);
+            let error = JsonError::new("INVALID_LABEL_PATTERN", e.to_string()).with_suggestion(
+                "Use 'namespace:value' for exact match or 'namespace:*' for wildcard",
+            );
+            (StatusCode::BAD_REQUEST, Json(error)).into_response()
+        }
+    }
+}
+
+/// Query parameter for the bulk-update filter expression
+#[derive(Debug, Deserialize)]
+struct BulkUpdateQuery {
+    /// Query filter expression, e.g. `label:type:task AND label:priority:high`
+    filter: String,
+}
+
+/// Request body for a bulk update: same operations `jit issue update` supports
+#[derive(Debug, Deserialize, Default)]
+struct BulkUpdateRequest {
+    state: Option<String>,
+    #[serde(default)]
+    add_labels: Vec<String>,
+    #[serde(default)]
+    remove_labels: Vec<String>,
+    assignee: Option<String>,
+    #[serde(default)]
+    unassign: bool,
+    priority: Option<String>,
+}
+
+/// Apply an update to every issue matching `filter`
+async fn bulk_update_issues<S: IssueStore>(
+    Query(params): Query<BulkUpdateQuery>,
+    State(executor): State<AppState<S>>,
+    Json(body): Json<BulkUpdateRequest>,
+) -> impl IntoResponse {
+    let filter = match QueryFilter::parse(&params.filter) {
+        Ok(filter) => filter,
+        Err(e) => {
+            let error = match e.downcast_ref::<jit::query::QueryParseError>() {
+                Some(parse_err) => JsonError::query_parse_error(
+                    &params.filter,
+                    &parse_err.message,
+                    parse_err.span.start,
+                    parse_err.span.end - parse_err.span.start,
+                ),
+                None => JsonError::new("INVALID_QUERY_FILTER", e.to_string()),
+            };
+            return (StatusCode::BAD_REQUEST, Json(error)).into_response();
+        }
+    };
+
+    let state = match body.state.as_deref().map(parse_state).transpose() {
+        Ok(state) => state,
+        Err(_) => {
+            let error = JsonError::invalid_state(body.state.as_deref().unwrap_or_default());
+            return (StatusCode::BAD_REQUEST, Json(error)).into_response();
+        }
+    };
+
+    let priority = match body.priority.as_deref().map(parse_priority).transpose() {
+        Ok(priority) => priority,
+        Err(_) => {
+            let error = JsonError::invalid_priority(body.priority.as_deref().unwrap_or_default());
+            return (StatusCode::BAD_REQUEST, Json(error)).into_response();
+        }
+    };
+
+    let operations = UpdateOperations {
+        state,
+        add_labels: body.add_labels,
+        remove_labels: body.remove_labels,
+        assignee: body.assignee,
+        unassign: body.unassign,
+        priority,
+    };
+
+    match executor.apply_bulk_update(&filter, &operations) {
+        Ok(result) => Json(JsonOutput::success(result)).into_response(),
+        Err(e) => {
+            tracing::error!("Bulk update failed: {:?}", e);
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
 }
 
 /// Get single issue by ID
@@ -553,6 +684,143 @@ async fn get_namespaces<S: IssueStore>(
     Ok(Json(NamespacesResponse { namespaces }))
 }
 
+/// Ready issues: unassigned, state=ready, unblocked
+async fn query_ready<S: IssueStore>(
+    State(executor): State<AppState<S>>,
+) -> Result<Json<Vec<Issue>>, StatusCode> {
+    executor.query_ready().map(Json).map_err(|e| {
+        tracing::error!("Failed to query ready issues: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+/// A blocked issue paired with the human-readable reasons it's blocked.
+#[derive(Debug, Serialize, Deserialize)]
+struct BlockedEntry {
+    issue: Issue,
+    reasons: Vec<String>,
+}
+
+/// Blocked issues, each with its blocking reasons
+async fn query_blocked<S: IssueStore>(
+    State(executor): State<AppState<S>>,
+) -> Result<Json<Vec<BlockedEntry>>, StatusCode> {
+    executor
+        .query_blocked()
+        .map(|blocked| {
+            Json(
+                blocked
+                    .into_iter()
+                    .map(|(issue, reasons)| BlockedEntry { issue, reasons })
+                    .collect(),
+            )
+        })
+        .map_err(|e| {
+            tracing::error!("Failed to query blocked issues: {:?}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })
+}
+
+#[derive(Debug, Deserialize)]
+struct AssigneeQuery {
+    assignee: String,
+}
+
+/// Issues assigned to a given assignee
+async fn query_by_assignee<S: IssueStore>(
+    Query(params): Query<AssigneeQuery>,
+    State(executor): State<AppState<S>>,
+) -> Result<Json<Vec<Issue>>, StatusCode> {
+    executor.query_by_assignee(&params.assignee).map(Json).map_err(|e| {
+        tracing::error!(
+            "Failed to query issues for assignee {}: {:?}",
+            params.assignee,
+            e
+        );
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[derive(Debug, Deserialize)]
+struct StateQuery {
+    state: String,
+}
+
+/// Issues in a given state
+async fn query_by_state<S: IssueStore>(
+    Query(params): Query<StateQuery>,
+    State(executor): State<AppState<S>>,
+) -> Result<Json<Vec<Issue>>, StatusCode> {
+    let state = parse_state(&params.state).map_err(|_| StatusCode::BAD_REQUEST)?;
+    executor.query_by_state(state).map(Json).map_err(|e| {
+        tracing::error!("Failed to query issues for state {}: {:?}", params.state, e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[derive(Debug, Deserialize)]
+struct LabelQuery {
+    pattern: String,
+}
+
+/// Issues matching a label pattern ('namespace:value' or 'namespace:*')
+async fn query_by_label<S: IssueStore>(
+    Query(params): Query<LabelQuery>,
+    State(executor): State<AppState<S>>,
+) -> Result<Json<Vec<Issue>>, StatusCode> {
+    executor.query_by_label(&params.pattern).map(Json).map_err(|e| {
+        tracing::error!(
+            "Failed to query issues for label {}: {:?}",
+            params.pattern,
+            e
+        );
+        StatusCode::BAD_REQUEST
+    })
+}
+
+#[derive(Debug, Deserialize)]
+struct ClaimRequest {
+    assignee: String,
+}
+
+/// Atomically claim an unassigned issue
+async fn claim_issue<S: IssueStore>(
+    Path(id): Path<String>,
+    State(executor): State<AppState<S>>,
+    Json(body): Json<ClaimRequest>,
+) -> Result<Json<Issue>, StatusCode> {
+    executor.claim_issue(&id, body.assignee).map_err(|e| {
+        tracing::error!("Failed to claim issue {}: {:?}", id, e);
+        StatusCode::CONFLICT
+    })?;
+
+    executor.show_issue(&id).map(Json).map_err(|e| {
+        tracing::error!("Failed to reload claimed issue {}: {:?}", id, e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[derive(Debug, Deserialize)]
+struct ReportRequest {
+    status: ReportStatus,
+    message: Option<String>,
+}
+
+/// Record an agent's outcome for an issue it was working on
+async fn report_issue<S: IssueStore>(
+    Path(id): Path<String>,
+    State(executor): State<AppState<S>>,
+    Json(body): Json<ReportRequest>,
+) -> Result<StatusCode, StatusCode> {
+    executor
+        .report_issue(&id, body.status, body.message)
+        .map(|()| StatusCode::OK)
+        .map_err(|e| {
+            tracing::error!("Failed to report on issue {}: {:?}", id, e);
+            StatusCode::CONFLICT
+        })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -813,4 +1081,159 @@ mod tests {
         // in async tests. We'll test this manually or with integration tests.
         // For now, we just verify the route exists.
     }
+
+    #[tokio::test]
+    async fn test_query_ready_empty() {
+        let server = create_test_app();
+        let response = server.get("/query/ready").await;
+        response.assert_status_ok();
+        let issues: Vec<Issue> = response.json();
+        assert_eq!(issues.len(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_query_by_assignee_requires_param() {
+        let server = create_test_app();
+        let response = server.get("/query/assignee").await;
+        assert!(response.status_code() != StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_claim_and_report_roundtrip() {
+        let storage = InMemoryStorage::new();
+        let executor = Arc::new(CommandExecutor::new(storage));
+        let id = executor
+            .create_issue(
+                "Issue 1".to_string(),
+                "Description".to_string(),
+                Priority::Normal,
+                vec![],
+                vec![],
+            )
+            .unwrap();
+
+        let app = create_routes(executor);
+        let server = TestServer::new(app).unwrap();
+
+        let claim_response = server
+            .post(&format!("/issues/{}/claim", id))
+            .json(&serde_json::json!({"assignee": "agent:worker-1"}))
+            .await;
+        claim_response.assert_status_ok();
+        let claimed: Issue = claim_response.json();
+        assert_eq!(claimed.assignee.as_deref(), Some("agent:worker-1"));
+
+        let report_response = server
+            .post(&format!("/issues/{}/report", id))
+            .json(&serde_json::json!({"status": "done"}))
+            .await;
+        report_response.assert_status_ok();
+    }
+
+    #[tokio::test]
+    async fn test_list_issues_by_label_wildcard() {
+        let storage = InMemoryStorage::new();
+        let executor = Arc::new(CommandExecutor::new(storage));
+        executor
+            .create_issue(
+                "Task v1".to_string(),
+                "Description".to_string(),
+                Priority::Normal,
+                vec![],
+                vec!["milestone:v1.0".to_string()],
+            )
+            .unwrap();
+        executor
+            .create_issue(
+                "Task v2".to_string(),
+                "Description".to_string(),
+                Priority::Normal,
+                vec![],
+                vec!["milestone:v2.0".to_string()],
+            )
+            .unwrap();
+
+        let app = create_routes(executor);
+        let server = TestServer::new(app).unwrap();
+
+        let response = server.get("/issues?label=milestone:*").await;
+        response.assert_status_ok();
+        let body: serde_json::Value = response.json();
+
+        assert_eq!(body["success"], true);
+        assert_eq!(body["data"]["filters"]["label"], "milestone:*");
+        assert_eq!(body["data"]["count"], 2);
+        assert_eq!(body["data"]["issues"].as_array().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_list_issues_by_label_invalid_pattern() {
+        let server = create_test_app();
+        let response = server.get("/issues?label=invalidlabel").await;
+        assert_eq!(response.status_code(), StatusCode::BAD_REQUEST);
+        let body: serde_json::Value = response.json();
+        assert_eq!(body["success"], false);
+        assert_eq!(body["error"]["code"], "INVALID_LABEL_PATTERN");
+    }
+
+    #[tokio::test]
+    async fn test_bulk_update_add_labels() {
+        let storage = InMemoryStorage::new();
+        let executor = Arc::new(CommandExecutor::new(storage));
+        executor
+            .create_issue(
+                "Task 1".to_string(),
+                "Description".to_string(),
+                Priority::Normal,
+                vec![],
+                vec!["type:task".to_string()],
+            )
+            .unwrap();
+        executor
+            .create_issue(
+                "Task 2".to_string(),
+                "Description".to_string(),
+                Priority::Normal,
+                vec![],
+                vec!["type:task".to_string()],
+            )
+            .unwrap();
+        executor
+            .create_issue(
+                "Epic 1".to_string(),
+                "Description".to_string(),
+                Priority::Normal,
+                vec![],
+                vec!["type:epic".to_string()],
+            )
+            .unwrap();
+
+        let app = create_routes(executor);
+        let server = TestServer::new(app).unwrap();
+
+        let response = server
+            .patch("/issues?filter=label:type:task")
+            .json(&serde_json::json!({"add_labels": ["milestone:v1.0"]}))
+            .await;
+        response.assert_status_ok();
+        let body: serde_json::Value = response.json();
+
+        assert_eq!(body["success"], true);
+        let summary = &body["data"]["summary"];
+        assert_eq!(summary["total_matched"].as_u64().unwrap(), 2);
+        assert_eq!(summary["total_modified"].as_u64().unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_bulk_update_invalid_filter() {
+        let server = create_test_app();
+        let response = server
+            .patch("/issues?filter=label:type:task AND (")
+            .json(&serde_json::json!({}))
+            .await;
+        assert_eq!(response.status_code(), StatusCode::BAD_REQUEST);
+        let body: serde_json::Value = response.json();
+        assert_eq!(body["success"], false);
+        assert_eq!(body["error"]["code"], "QUERY_PARSE_ERROR");
+    }
 }
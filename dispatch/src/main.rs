@@ -1,6 +1,6 @@
 use anyhow::{Context, Result};
 use clap::{Parser, Subcommand};
-use jit_dispatch::{Config, Orchestrator};
+use jit_dispatch::{Config, CycleReport, Orchestrator};
 use std::path::PathBuf;
 use std::thread;
 use std::time::Duration;
@@ -59,12 +59,8 @@ fn main() -> Result<()> {
             let mut orchestrator = Orchestrator::with_config(&repo, config.clone());
 
             loop {
-                match orchestrator.run_dispatch_cycle() {
-                    Ok(assigned) => {
-                        if assigned > 0 {
-                            println!("Assigned {} issue(s)", assigned);
-                        }
-                    }
+                match orchestrator.run_dispatch_cycle_with_sweep() {
+                    Ok(report) => log_cycle_report(&report),
                     Err(e) => {
                         eprintln!("Error during dispatch cycle: {}", e);
                     }
@@ -86,13 +82,23 @@ fn main() -> Result<()> {
 
             let mut orchestrator = Orchestrator::with_config(&repo, config);
 
-            let assigned = orchestrator
-                .run_dispatch_cycle()
+            let report = orchestrator
+                .run_dispatch_cycle_with_sweep()
                 .context("Failed to run dispatch cycle")?;
 
-            println!("Assigned {} issue(s)", assigned);
+            log_cycle_report(&report);
 
             Ok(())
         }
     }
 }
+
+/// Emit one line of structured JSON describing a dispatch cycle, so a
+/// long-running daemon's output can be consumed by log aggregation instead
+/// of scraped as free-form text.
+fn log_cycle_report(report: &CycleReport) {
+    match serde_json::to_string(report) {
+        Ok(line) => println!("{}", line),
+        Err(e) => eprintln!("Error serializing cycle report: {}", e),
+    }
+}